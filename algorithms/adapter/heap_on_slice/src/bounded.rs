@@ -0,0 +1,170 @@
+//! # Bounded heap
+//!
+//! 원소를 하나씩 받으면서, 그 중 비교 기준으로 가장 앞서는 k개만 유지하는 top-k streaming 구조체를 제공합니다.
+//!
+//! [`BoundedHeapBy`]는 유지 중인 k개를 comparator의 역순으로 heap에 저장하므로,
+//! root는 유지 중인 원소 중 가장 뒤쳐지는 원소입니다. 새 원소는 root와 한 번만 비교하여
+//! 받아들일지 결정하고, 받아들이는 경우 root를 밀어냅니다.
+//! 따라서 n개의 원소를 넣는 데 O(n log k) 시간과 O(k) 공간이 필요합니다.
+//!
+//! ```rust
+//! use heap_on_slice::bounded::BoundedHeapBy;
+//!
+//! // 100에 가장 가까운 2개
+//! let mut nearest = BoundedHeapBy::new(2, |a: &i32, b: &i32| a.abs_diff(100).cmp(&b.abs_diff(100)));
+//! assert_eq!(nearest.try_push(90), None);
+//! assert_eq!(nearest.try_push(103), None);
+//! assert_eq!(nearest.try_push(99), Some(90)); // 가장 먼 90을 밀어냄
+//! assert_eq!(nearest.try_push(150), Some(150)); // 더 멀어서 받아들이지 않음
+//! assert_eq!(nearest.into_sorted_vec(), vec![99, 103]);
+//! ```
+
+use crate::heap_implementation as Impl;
+use std::cmp::Ordering;
+
+/// 사용자 정의 comparator 기준으로 가장 앞서는 k개의 원소만 유지하는 bounded heap입니다.
+///
+/// `compare`는 `Ordering::Less`가 앞서는 순서이며, 유지되는 원소는 `compare`로 정렬했을 때의 처음 k개입니다.
+/// `compare`가 `Equal`을 반환하는 원소 사이에서는 먼저 들어온 원소를 유지합니다.
+///
+/// 내부 저장소는 길이 k까지만 자라는 `Vec<T>`이며, heap 연산은 [`min_heap`](crate::min_heap)과 같은 구현을 사용합니다.
+pub struct BoundedHeapBy<T, F>
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    /// `compare`의 역순으로 구성된 heap, root는 유지 중인 원소 중 가장 뒤쳐지는 원소
+    data: Vec<T>,
+    k: usize,
+    compare: F,
+}
+
+impl<T, F> BoundedHeapBy<T, F>
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    /// 최대 `k`개의 원소를 유지하는 빈 bounded heap을 생성합니다.
+    ///
+    /// `k`가 0이면 어떤 원소도 유지하지 않습니다.
+    pub fn new(k: usize, compare: F) -> Self {
+        BoundedHeapBy {
+            data: Vec::with_capacity(k),
+            k,
+            compare,
+        }
+    }
+
+    /// 원소를 추가하고, 유지하지 않게 된 원소를 반환합니다.
+    ///
+    /// - 아직 k개 미만인 경우 `x`를 유지하고 `None`을 반환합니다.
+    /// - `x`가 유지 중인 가장 뒤쳐지는 원소보다 앞서는 경우, 그 원소를 밀어내고 반환합니다.
+    /// - 그렇지 않은 경우 `x`를 그대로 반환합니다.
+    ///
+    /// # 시간 복잡도
+    ///
+    /// O(log k) - 최대 한 번의 sift-up 또는 sift-down이 필요합니다.
+    pub fn try_push(&mut self, x: T) -> Option<T> {
+        if self.k == 0 {
+            return Some(x);
+        }
+        let compare = &mut self.compare;
+        let reversed = |a: &T, b: &T| compare(b, a);
+        if self.data.len() < self.k {
+            Impl::vec_heap_push(&mut self.data, x, reversed);
+            None
+        } else {
+            Some(Impl::heap_pushpop(&mut self.data, x, reversed))
+        }
+    }
+
+    /// 유지 중인 원소 중 가장 뒤쳐지는 원소의 참조를 반환합니다.
+    /// k개가 모두 찬 경우, 새 원소가 유지되려면 이 원소보다 앞서야 합니다.
+    pub fn peek(&self) -> Option<&T> {
+        self.data.first()
+    }
+
+    /// 유지 중인 원소의 개수를 반환합니다.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// 유지 중인 원소가 없는지 확인합니다.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// 유지 중인 원소를 `compare` 순서로 정렬하여 반환합니다.
+    ///
+    /// # 시간 복잡도
+    ///
+    /// O(k log k) - heap sort를 수행합니다.
+    pub fn into_sorted_vec(self) -> Vec<T> {
+        let BoundedHeapBy {
+            mut data,
+            mut compare,
+            ..
+        } = self;
+        // 역순 heap의 heap_reverse_sort는 compare 순서가 된다.
+        Impl::heap_reverse_sort(&mut data, |a: &T, b: &T| compare(b, a));
+        data
+    }
+}
+
+#[cfg(test)]
+mod unit_test {
+    use super::*;
+    use crate::test_util::pseudo_random;
+
+    #[test]
+    fn test_nearest_to_target() {
+        let distance = |a: &i64, b: &i64| a.abs_diff(100).cmp(&b.abs_diff(100));
+
+        // 임의의 데이터
+        let stream: Vec<i64> = pseudo_random(1000, 42)
+            .into_iter()
+            .map(|x| x as i64 % 1000)
+            .collect();
+
+        let mut nearest = BoundedHeapBy::new(5, distance);
+        let mut evicted = 0;
+        for &x in stream.iter() {
+            if nearest.try_push(x).is_some() {
+                evicted += 1;
+            }
+            assert!(nearest.len() <= 5);
+        }
+        assert_eq!(evicted, stream.len() - 5);
+
+        // 거리 순으로 stable하게 정렬한 reference의 처음 5개
+        let mut expected = stream.clone();
+        expected.sort_by(distance);
+        expected.truncate(5);
+        let result = nearest.into_sorted_vec();
+        let distances: Vec<u64> = result.iter().map(|x| x.abs_diff(100)).collect();
+        let expected_distances: Vec<u64> = expected.iter().map(|x| x.abs_diff(100)).collect();
+        assert_eq!(distances, expected_distances);
+    }
+
+    #[test]
+    fn test_try_push_evicts() {
+        let mut heap = BoundedHeapBy::new(3, |a: &i32, b: &i32| a.cmp(b));
+        assert!(heap.is_empty());
+        assert_eq!(heap.try_push(5), None);
+        assert_eq!(heap.try_push(1), None);
+        assert_eq!(heap.try_push(4), None);
+        assert_eq!(heap.peek(), Some(&5));
+
+        // 더 작은 원소는 가장 큰 원소를 밀어냄
+        assert_eq!(heap.try_push(2), Some(5));
+        assert_eq!(heap.peek(), Some(&4));
+        // 같거나 큰 원소는 받아들이지 않음
+        assert_eq!(heap.try_push(4), Some(4));
+        assert_eq!(heap.try_push(9), Some(9));
+        assert_eq!(heap.len(), 3);
+        assert_eq!(heap.into_sorted_vec(), vec![1, 2, 4]);
+
+        // k = 0
+        let mut none = BoundedHeapBy::new(0, |a: &i32, b: &i32| a.cmp(b));
+        assert_eq!(none.try_push(1), Some(1));
+        assert!(none.into_sorted_vec().is_empty());
+    }
+}
@@ -10,6 +10,9 @@ use std::cmp::Ordering;
 
 /// # Note
 /// [0, n/2)가 parent node이고 [n/2, n) 가 leaf node이므로 $[0, len / 2)$에 대해 확인한다.
+/// $n = 2k$이면 마지막 parent $k - 1$은 왼쪽 자식만 가지므로, 오른쪽 자식은 `right < len`으로 확인한 후 비교한다.
+/// $n = 2k + 1$이면 마지막 parent $k - 1$도 양쪽 자식을 모두 가진다.
+/// 처음 발견한 위반에서 바로 false를 반환한다.
 pub fn is_heap<T, F>(arr: &[T], mut compare: F) -> bool
 where
     F: FnMut(&T, &T) -> Ordering,
@@ -133,10 +136,60 @@ where
     }
 }
 
+/// # Note
+/// heapify와 같은 결과의 heap property를 만족하지만, 원소를 하나씩 push하는 것처럼 top-down으로 구성한다.
+/// [0, idx)가 heap인 상태에서 arr[idx]를 upward adjustment하면 [0, idx]가 heap이 된다.
+/// 각 원소가 최대 depth만큼 이동하므로 O(n log n)이며, 대부분의 node가 leaf 근처에 있어 O(n)인 heapify보다 느리다.
+pub fn heapify_by_insertion<T, F>(arr: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    for idx in 1..arr.len() {
+        move_upward(arr, idx, &mut compare);
+    }
+}
+
+/// # Note
+/// `keys[i]`는 `arr[i]`의 key이다. heapify와 같은 bottom-up 구성을 keys에 대해 수행하되,
+/// keys에 적용한 swap을 arr에도 동일하게 적용하여 두 slice를 동기화한다.
+/// 비교는 keys로만 이루어지므로 key를 다시 계산하지 않는다.
+pub fn heapify_cached<T, K, F>(arr: &mut [T], keys: &mut [K], mut compare: F)
+where
+    F: FnMut(&K, &K) -> Ordering,
+{
+    debug_assert_eq!(arr.len(), keys.len());
+    let len = keys.len();
+    for start in (0..len / 2).rev() {
+        let mut idx = start;
+        loop {
+            let (left, right) = (2 * idx + 1, 2 * idx + 2);
+            let mut smallest_idx = idx;
+            if left < len && compare(&keys[left], &keys[smallest_idx]).is_lt() {
+                smallest_idx = left;
+            }
+            if right < len && compare(&keys[right], &keys[smallest_idx]).is_lt() {
+                smallest_idx = right;
+            }
+            if smallest_idx == idx {
+                break;
+            }
+            keys.swap(idx, smallest_idx);
+            arr.swap(idx, smallest_idx);
+            idx = smallest_idx;
+        }
+    }
+}
+
 /// # Note
 /// 메서드 spec은 pop 후 push가 아닌, push 후 pop이다.
 /// 따라서 arr이 비어있거나, x가 arr의 root보다 작은 경우 x를 반환한다.
 /// root의 값과 같은 경우 최적화를 위해 힙을 조정하지 않고 x를 반환한다.
+///
+/// # Panic safety
+/// 원소의 이동은 모두 swap으로만 이루어지므로, compare가 panic하더라도 원소가 복제되거나 유실되지 않는다.
+/// root와 x를 교환한 후 move_downward 중에 panic한 경우,
+/// arr에는 x와 기존 root를 제외한 원소들이 모두 남아있으며(heap property는 보장되지 않는다),
+/// 기존 root는 x에 담긴 채로 unwinding 중에 정확히 한 번 drop된다.
 pub fn heap_pushpop<T, F>(arr: &mut [T], mut x: T, mut compare: F) -> T
 where
     F: FnMut(&T, &T) -> Ordering,
@@ -167,6 +220,40 @@ where
     Some(init)
 }
 
+/// # Note
+/// heap_pop과 같이 root를 slice의 끝으로 옮긴 후, 끝으로 옮겨진 root를 clone하여 반환한다.
+/// slice는 길이를 줄일 수 없으므로 root의 원본은 slice의 끝에 그대로 남는다.
+pub fn heap_pop_value<T, F>(arr: &mut [T], compare: F) -> Option<(T, &mut [T])>
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    heap_pop(arr, compare)?;
+    let (last, init) = arr.split_last_mut()?;
+    Some((last.clone(), init))
+}
+
+/// # Note
+/// Vec의 끝에 x를 추가한 후 move_upward로 heap property를 복구한다.
+pub fn vec_heap_push<T, F>(vec: &mut Vec<T>, x: T, compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    vec.push(x);
+    let last = vec.len() - 1;
+    move_upward(vec, last, compare);
+}
+
+/// # Note
+/// heap_pop은 root를 slice의 끝으로 옮기므로, Vec의 마지막 원소를 꺼내면 root가 된다.
+pub fn vec_heap_pop<T, F>(vec: &mut Vec<T>, compare: F) -> Option<T>
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    heap_pop(vec, compare)?;
+    vec.pop()
+}
+
 pub fn heap_reverse_sort<T, F>(mut arr: &mut [T], mut compare: F)
 where
     F: FnMut(&T, &T) -> Ordering,
@@ -177,6 +264,20 @@ where
     }
 }
 
+/// # Note
+/// heap_reverse_sort의 결과는 compare의 역순이므로, 정렬 확인은 인접한 쌍이 compare 기준 `Less`가 아닌지로 한다.
+/// 두 확인은 heap 연산과 독립적으로 compare만을 사용하므로, 일관되지 않은 compare는 둘 중 하나에서 드러난다.
+/// 정렬 확인이 실패해도 heapify와 heap 확인은 수행하여, arr은 항상 heapify된 상태로 끝난다.
+pub fn heapsort_then_rebuild<T, F>(arr: &mut [T], mut compare: F) -> bool
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    heap_reverse_sort(arr, &mut compare);
+    let sorted = arr.is_sorted_by(|a, b| compare(a, b).is_ge());
+    heapify(arr, &mut compare);
+    sorted && is_heap(arr, &mut compare)
+}
+
 pub fn adjust_heap<T, F>(arr: &mut [T], idx: usize, mut compare: F) -> bool
 where
     F: FnMut(&T, &T) -> Ordering,
@@ -184,10 +285,143 @@ where
     move_upward(arr, idx, &mut compare) || move_downward(arr, idx, &mut compare)
 }
 
+/// # Note
+/// adjust_heap과 같이 위로 이동을 먼저 시도하고, 위로 이동하지 않은 경우에만 아래로 이동한다.
+/// single_upward와 single_downward가 idx를 이동한 위치로 갱신하므로, 마지막 idx가 원소의 최종 위치이다.
+pub fn adjust_heap_tracked<T, F>(arr: &mut [T], mut idx: usize, mut compare: F) -> usize
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let start = idx;
+    while single_upward(arr, &mut idx, &mut compare) {}
+    if idx == start {
+        while single_downward(arr, &mut idx, &mut compare) {}
+    }
+    idx
+}
+
+/// # Note
+/// adjust_heap은 idx 한 곳만 heap property를 깨뜨린 경우에만 heap을 복구할 수 있다.
+/// idx를 제외한 모든 (parent, child) 쌍과, idx의 parent와 idx의 child 쌍이 heap property를 만족하는지 확인한다.
+/// 후자는 idx의 값이 바뀌기 전에 transitivity로 성립하던 관계이다.
+fn is_heap_except<T, F>(arr: &[T], idx: usize, mut compare: F) -> bool
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    for child in 1..arr.len() {
+        if child == idx {
+            continue;
+        }
+        let mut parent = (child - 1) / 2;
+        if parent == idx {
+            // idx의 child는 idx의 parent와 비교한다. idx가 root이면 비교할 대상이 없다.
+            let Some(grand) = idx.checked_sub(1).map(|x| x / 2) else {
+                continue;
+            };
+            parent = grand;
+        }
+        if compare(&arr[parent], &arr[child]).is_gt() {
+            return false;
+        }
+    }
+    true
+}
+
+/// # Note
+/// 전제 조건 확인에 O(n)이 소요되므로, adjust_heap의 O(log n)을 유지하려면 adjust_heap을 사용한다.
+pub fn adjust_heap_checked<T, F>(arr: &mut [T], idx: usize, mut compare: F) -> bool
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    assert!(
+        is_heap_except(arr, idx, &mut compare),
+        "adjust_heap_checked: heap property is broken at positions other than idx"
+    );
+    adjust_heap(arr, idx, compare)
+}
+
+/// # Note
+/// `arr[..=k]`를 compare 기준의 heap으로 유지하면서 나머지 원소를 한 번씩 훑는다.
+/// root보다 "큰"(compare 기준 root 뒤에 와야 하는) 원소를 만나면 root와 교환하고 heap을 복구한다.
+/// 순회가 끝나면 root는 `arr[..=k]` 중 compare 기준으로 가장 앞선 원소이자,
+/// 전체 배열에서 compare의 역순 기준으로 k번째 원소이다. 이를 index k로 옮긴다.
+/// heap의 크기는 항상 k + 1로 고정되므로 O(n log k)에 동작한다.
+pub fn heap_select_nth<T, F>(arr: &mut [T], k: usize, mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let (heap, rest) = arr.split_at_mut(k + 1);
+    heapify(heap, &mut compare);
+    for x in rest.iter_mut() {
+        if compare(&heap[0], x).is_lt() {
+            std::mem::swap(&mut heap[0], x);
+            move_downward(heap, 0, &mut compare);
+        }
+    }
+    heap.swap(0, k);
+}
+
+/// # Note
+/// heap의 원소는 각 run의 `(다음에 꺼낼 index, run의 끝)`이며, 비교는 그 index의 원소로 한다.
+/// run은 slice 상에서 순서대로 놓여있으므로, 서로 다른 run의 index는 run의 순서와 같은 순서이다.
+/// 따라서 원소가 같으면 index로 비교하는 것만으로 앞선 run이 먼저 나오는 stable merge가 된다.
+/// 꺼낸 원소는 out의 원소와 swap하므로, 이미 지나간 index에 out의 원래 원소가 남지만 다시 비교되지 않는다.
+/// heap의 크기는 비어있지 않은 run의 개수 k 이하이므로 O(n log k)에 동작한다.
+pub fn merge_runs<T, F>(slice: &mut [T], run_boundaries: &[usize], out: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let len = slice.len();
+    assert_eq!(
+        len,
+        out.len(),
+        "merge_runs: out must have the same length as slice"
+    );
+    assert!(
+        run_boundaries.is_sorted() && run_boundaries.last().is_none_or(|&b| b <= len),
+        "merge_runs: run_boundaries must be sorted and within the slice"
+    );
+
+    let starts = std::iter::once(0).chain(run_boundaries.iter().copied());
+    let ends = run_boundaries.iter().copied().chain(std::iter::once(len));
+    let mut heap: Vec<(usize, usize)> = starts.zip(ends).filter(|(s, e)| s < e).collect();
+    debug_assert!(
+        heap.iter()
+            .all(|&(s, e)| slice[s..e].is_sorted_by(|a, b| compare(a, b).is_le())),
+        "merge_runs: run is not sorted"
+    );
+
+    heapify(&mut heap, run_order(slice, &mut compare));
+    for dst in out.iter_mut() {
+        let (idx, end) = heap[0];
+        std::mem::swap(dst, &mut slice[idx]);
+        if idx + 1 < end {
+            heap[0].0 = idx + 1;
+            move_downward(&mut heap, 0, run_order(slice, &mut compare));
+        } else {
+            heap_pop(&mut heap, run_order(slice, &mut compare));
+            heap.pop();
+        }
+    }
+}
+
+/// merge_runs의 heap 원소를 다음에 꺼낼 원소로 비교하고, 같으면 앞선 run을 먼저 둔다.
+fn run_order<'a, T, F>(
+    slice: &'a [T],
+    compare: &'a mut F,
+) -> impl FnMut(&(usize, usize), &(usize, usize)) -> Ordering + 'a
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    move |a, b| compare(&slice[a.0], &slice[b.0]).then(a.0.cmp(&b.0))
+}
+
 #[cfg(test)]
 mod unit_test {
     use crate::heap_implementation::*;
+    use std::cell::Cell;
     use std::cmp::Ordering;
+    use test_data::DropCounter;
 
     // Define the comparison functions that will be used in tests
     fn default_compare<T: Ord>(a: &T, b: &T) -> Ordering {
@@ -283,6 +517,39 @@ mod unit_test {
         assert!(is_heap(&arr_adj_down, default_compare::<i32>));
     }
 
+    #[test]
+    fn test_is_heap_parent_boundary() {
+        for k in 1..6usize {
+            // len = 2k, 마지막 parent k - 1은 왼쪽 자식만 가짐
+            let even: Vec<usize> = (0..2 * k).collect();
+            assert!(is_heap(&even, default_compare));
+            let mut broken = even.clone();
+            broken.swap(k - 1, 2 * k - 1);
+            assert!(!is_heap(&broken, default_compare));
+
+            // len = 2k + 1, 마지막 parent k - 1은 양쪽 자식을 모두 가짐
+            let odd: Vec<usize> = (0..2 * k + 1).collect();
+            assert!(is_heap(&odd, default_compare));
+            let mut broken_left = odd.clone();
+            broken_left.swap(k - 1, 2 * k - 1);
+            assert!(!is_heap(&broken_left, default_compare));
+            let mut broken_right = odd.clone();
+            broken_right.swap(k - 1, 2 * k);
+            assert!(!is_heap(&broken_right, default_compare));
+        }
+    }
+
+    #[test]
+    fn test_is_heap_except() {
+        // only idx is broken
+        assert!(is_heap_except(&[2, 3, 4, 5, 1], 4, default_compare::<i32>));
+        assert!(is_heap_except(&[5, 1, 2, 3, 4], 0, default_compare::<i32>));
+        // idx and another position are broken
+        assert!(!is_heap_except(&[2, 3, 0, 5, 1], 4, default_compare::<i32>));
+        // children of idx are smaller than parent of idx
+        assert!(!is_heap_except(&[2, 9, 4, 1, 5], 1, default_compare::<i32>));
+    }
+
     #[test]
     fn test_heap_reverse_sort_sorts_descending() {
         let mut arr = vec![3, 1, 4, 2, 5];
@@ -524,4 +791,37 @@ mod unit_test {
         assert_eq!(result, 3);
         assert_eq!(single, vec![5]);
     }
+
+    #[test]
+    fn heap_pushpop_panic_safety() {
+        let drops = Cell::new(0);
+        let new = |value| DropCounter {
+            value,
+            drops: &drops,
+        };
+        let mut arr: Vec<DropCounter> = (0..15).map(new).collect();
+        let len = arr.len();
+
+        // move_downward 중간에 panic하는 comparator
+        let mut calls = 0;
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            heap_pushpop(&mut arr, new(100), |a: &DropCounter, b: &DropCounter| {
+                calls += 1;
+                if calls > 2 {
+                    panic!("comparator panic");
+                }
+                a.value.cmp(&b.value)
+            })
+        }));
+        assert!(result.is_err());
+
+        // 기존 root(0)만 unwinding 중에 drop되고, 나머지는 모두 arr에 남아있다
+        assert_eq!(drops.get(), 1);
+        let mut values: Vec<i32> = arr.iter().map(|x| x.value).collect();
+        values.sort();
+        assert_eq!(values, (1..15).chain([100]).collect::<Vec<_>>());
+
+        drop(arr);
+        assert_eq!(drops.get(), len + 1);
+    }
 }
@@ -3,15 +3,24 @@
 //! `&mut [T]` slice에 대한 in-place binary heap 연산을 제공하는 라이브러리입니다.
 //!
 //! 이 crate는 별도의 heap 자료구조를 생성하지 않고, 기존 slice의 메모리 영역을
-//! 그대로 활용하여 heap 연산을 수행합니다. 표준 라이브러리의 [`BinaryHeap`]과
+//! 그대로 활용하여 heap 연산을 수행합니다. slice를 받는 heap 연산은 표준 라이브러리의 [`BinaryHeap`]과
 //! 달리 메모리 할당을 하지 않으며, slice의 고정된 크기 내에서만 동작합니다.
 //!
+//! 다만 다음 API는 편의를 위해 `Vec`을 사용하거나 할당합니다.
+//! - `vec_heap_push`, `vec_heap_pop` 계열: `Vec`의 끝에서 원소를 추가, 제거하므로 `Vec`의 재할당이 일어날 수 있습니다.
+//! - `max_heap::heap_drain`: pop된 원소를 담은 `Vec`을 반환합니다.
+//! - `heapify_by_key_cached`, `is_heap_by_key_cached`: 미리 계산한 키를 담을 `Vec`을 할당합니다.
+//! - `merge_runs` 계열: run의 개수만큼의 heap을 `Vec`으로 할당합니다.
+//! - [`BoundedHeapBy`](bounded::BoundedHeapBy): 길이 k까지 자라는 `Vec`을 소유합니다.
+//!
 //! [`BinaryHeap`]: std::collections::BinaryHeap
 //!
 //! ## 모듈 구성
 //!
-//! - [`min_heap`]: minimum heap 연산을 제공합니다. 가장 작은 원소가 root에 위치하며, [`heap_reverse_sort`](min_heap::heap_reverse_sort)로 내림차순 정렬을 지원합니다.
-//! - [`max_heap`]: maximum heap 연산을 제공합니다. 가장 큰 원소가 root에 위치하며, [`heapsort`](max_heap::heapsort)로 오름차순 정렬을 지원합니다.
+//! - [`min_heap`]: minimum heap 연산을 제공합니다. 가장 작은 원소가 root에 위치하며, [`heap_reverse_sort`](min_heap::heap_reverse_sort)와 [`heap_sort_asc`](min_heap::heap_sort_asc)로 내림차순, 오름차순 정렬을 지원합니다.
+//! - [`max_heap`]: maximum heap 연산을 제공합니다. 가장 큰 원소가 root에 위치하며, [`heap_sort`](max_heap::heap_sort)와 [`heap_sort_desc`](max_heap::heap_sort_desc)로 오름차순, 내림차순 정렬을 지원합니다.
+//! - [`bounded`]: 원소를 하나씩 받으면서 사용자 정의 comparator 기준으로 가장 앞서는 k개만 유지하는 [`BoundedHeapBy`](bounded::BoundedHeapBy)를 제공합니다.
+//! - [`orient`]: 두 모듈의 연산을 [`Heap`](orient::Heap) trait으로 추상화하여, heap의 방향에 대해 generic한 코드를 작성할 수 있게 합니다.
 //!
 //! ## 사용법
 //!
@@ -41,12 +50,16 @@
 //!
 //! ## 주요 특징
 //!
-//! - **Zero-allocation**: 추가 메모리 할당 없이 기존 slice에서 동작
+//! - **Zero-allocation**: slice를 받는 heap 연산은 추가 메모리 할당 없이 기존 slice에서 동작 (위의 `Vec` 기반 API 제외)
 //! - **In-place 연산**: 원본 데이터를 직접 수정하여 공간 효율성 극대화
 //! - **Custom comparator 지원**: 사용자 정의 비교 함수 및 key extraction 함수 지원
 //! - **Type-safe**: 컴파일 타임에 타입 안전성 보장
 
 mod heap_implementation;
 
+pub mod bounded;
 pub mod max_heap;
 pub mod min_heap;
+pub mod orient;
+#[cfg(test)]
+mod test_util;
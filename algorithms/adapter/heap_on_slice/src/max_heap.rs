@@ -11,18 +11,34 @@
 //! - [`is_heap`]: slice가 valid max heap인지 확인
 //! - [`is_heap_by`]: 사용자 정의 comparator로 heap 검증
 //! - [`is_heap_by_key`]: key extraction 함수로 heap 검증
+//! - [`is_heap_by_key_cached`]: key를 한 번씩만 계산하여 heap 검증
+//! - [`heapsort_then_rebuild`]: heap sort와 heapify의 round-trip으로 비교 기준을 검증
 //!
 //! ### Heap 구성
 //! - [`heapify`]: 임의의 slice를 valid max heap으로 변환
 //! - [`heapify_by`], [`heapify_by_key`]: 사용자 정의 비교 기준으로 heapify
+//! - [`heapify_by_key_cached`]: key를 한 번씩만 계산하여 heapify
+//! - [`heapify_by_insertion`]: 원소를 하나씩 push하는 방식으로 heap을 구성 (O(n log n), 비교용)
+//! - [`merge_into_heap`]: 두 heap을 이어붙인 slice를 하나의 heap으로 병합
+//! - [`repair_heap`]: heap property가 깨진 경우에만 heapify하고, 복구가 필요했는지 반환
 //!
 //! ### Priority Queue 연산
 //! - [`heap_pushpop`]: 새 원소 추가 후 최대값 제거
 //! - [`heap_pop`]: 최대 원소 제거
+//! - [`heap_pop_value`]: 최대 원소를 제거하고 그 값을 나머지 slice와 함께 반환
+//! - [`heap_drain`]: 모든 원소를 pop 순서(내림차순)대로 꺼내 `Vec`으로 반환
+//! - [`vec_heap_push`], [`vec_heap_pop`]: `Vec`으로 구성된 heap에 원소 추가 및 최대 원소 제거
 //! - [`adjust_heap`]: 특정 위치의 heap property 복구
+//! - [`adjust_heap_tracked`]: 특정 위치의 heap property 복구 후 원소의 최종 위치 반환
+//! - [`adjust_heap_checked`]: 전제 조건을 확인한 후 특정 위치의 heap property 복구
 //!
 //! ### 정렬
-//! - [`heap_sort`]: in-place 오름차순 정렬 (내림차순 정렬은 [`min_heap::heap_reverse_sort`](crate::min_heap::heap_reverse_sort) 사용)
+//! - [`heap_sort`]: in-place 오름차순 정렬
+//! - [`heap_sort_desc`]: in-place 내림차순 정렬
+//! - [`stable_heap_sort_by_key`]: key 기준 stable 오름차순 정렬 (O(n) 추가 공간)
+//!
+//! ### 선택
+//! - [`heap_select_nth`]: k번째로 작은 원소를 index k에 위치시킴 (O(n log k))
 //!
 //! ## 사용 예시
 //!
@@ -178,6 +194,39 @@ where
     Impl::is_heap(arr, key2reversed_compare(key))
 }
 
+/// key extraction 함수로 slice가 valid heap인지 확인하되, 각 원소의 key를 한 번씩만 계산합니다.
+///
+/// [`is_heap_by_key`]는 비교할 때마다 key를 다시 계산하므로 `key`가 최대 약 2n번 호출됩니다.
+/// 이 함수는 key를 `Vec<K>`에 먼저 모은 후 heap property를 검사하므로, `key`는 정확히 n번 호출됩니다.
+/// key 계산 비용이 큰 경우에 사용하세요.
+///
+/// # 공간 복잡도
+///
+/// O(n) - key를 저장하기 위한 추가 메모리를 할당합니다.
+///
+/// # Examples
+///
+/// ```rust
+/// use heap_on_slice::max_heap::is_heap_by_key_cached;
+///
+/// struct Person { age: u32 }
+///
+/// let people = vec![
+///     Person { age: 35 },
+///     Person { age: 30 },
+///     Person { age: 28 },
+/// ];
+/// assert!(is_heap_by_key_cached(&people, |p| p.age));
+/// ```
+pub fn is_heap_by_key_cached<T, K, F>(arr: &[T], key: F) -> bool
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    let keys: Vec<K> = arr.iter().map(key).collect();
+    Impl::is_heap(&keys, reversed_cmp)
+}
+
 /// 임의의 slice를 valid max heap으로 변환합니다.
 ///
 /// Floyd's heap construction algorithm을 사용하여 bottom-up 방식으로 heap을 구성합니다.
@@ -248,12 +297,212 @@ where
     Impl::heapify(arr, key2reversed_compare(key));
 }
 
+/// key extraction 함수로 slice를 heap으로 변환하되, 각 원소의 key를 한 번씩만 계산합니다.
+///
+/// [`heapify_by_key`]는 비교할 때마다 key를 다시 계산하므로 `key`가 O(n)번, 약 4n번 호출됩니다.
+/// 이 함수는 key를 `Vec<K>`에 먼저 모은 후 key에 대해 heap을 구성하고,
+/// key에 적용한 swap을 slice에도 동일하게 적용합니다. 따라서 `key`는 정확히 n번 호출됩니다.
+/// key 계산 비용이 큰 경우에만 이득이 있으며, key가 저렴하다면 [`heapify_by_key`]를 사용하세요.
+///
+/// # 공간 복잡도
+///
+/// O(n) - key를 저장하기 위한 추가 메모리를 할당합니다.
+///
+/// # 시간 복잡도
+///
+/// O(n) - [`heapify`]와 같습니다.
+///
+/// # Examples
+///
+/// ```rust
+/// use heap_on_slice::max_heap::{heapify_by_key_cached, is_heap_by_key};
+///
+/// let mut words = vec!["ccc", "a", "bbbbbbbbb", "dd"];
+/// heapify_by_key_cached(&mut words, |w| w.len());
+/// assert!(is_heap_by_key(&words, |w| w.len()));
+/// ```
+pub fn heapify_by_key_cached<T, K, F>(arr: &mut [T], key: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    let mut keys: Vec<K> = arr.iter().map(key).collect();
+    Impl::heapify_cached(arr, &mut keys, |a: &K, b: &K| b.cmp(a));
+}
+
+/// 원소를 하나씩 push하는 방식으로 slice를 valid max heap으로 변환합니다.
+///
+/// 앞쪽의 heap에 다음 원소를 추가하고 sift-up하는 과정을 반복하여, top-down으로 heap을 구성합니다.
+/// 결과는 [`heapify`]와 마찬가지로 valid heap이지만, 원소의 배치는 다를 수 있습니다.
+///
+/// 원소가 하나씩 도착하는 경우에는 이 방식이 자연스럽지만, 모든 원소가 이미 slice에 있다면
+/// O(n)인 [`heapify`] (Floyd's algorithm)를 사용하세요.
+/// 대부분의 node는 leaf 근처에 있어, sift-down은 짧게 끝나지만 sift-up은 root까지 길어질 수 있습니다.
+///
+/// # 시간 복잡도
+///
+/// O(n log n) - 각 원소의 sift-up이 O(log n)입니다.
+///
+/// # Examples
+///
+/// ```rust
+/// use heap_on_slice::max_heap::{heapify_by_insertion, is_heap};
+///
+/// let mut arr = vec![3, 1, 4, 1, 5, 9, 2];
+/// heapify_by_insertion(&mut arr);
+/// assert!(is_heap(&arr));
+/// assert_eq!(arr[0], 9); // 최대값이 root에 위치
+/// ```
+pub fn heapify_by_insertion<T: Ord>(arr: &mut [T]) {
+    Impl::heapify_by_insertion(arr, reversed_cmp);
+}
+
+/// 사용자 정의 comparator로 원소를 하나씩 push하는 방식의 heap 구성을 수행합니다.
+///
+/// [`heapify_by_insertion`]과 동일한 기능을 하지만 `Ord::cmp` 대신 `compare` 함수를 인자로 받습니다.
+pub fn heapify_by_insertion_by<T, F>(arr: &mut [T], compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    Impl::heapify_by_insertion(arr, reverse_compare(compare));
+}
+
+/// key extraction 함수로 원소를 하나씩 push하는 방식의 heap 구성을 수행합니다.
+///
+/// [`heapify_by_insertion`]과 동일한 기능을 하지만 원소 비교 시 `key` 함수로 추출한 값을 사용합니다.
+pub fn heapify_by_insertion_by_key<T, K, F>(arr: &mut [T], key: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    Impl::heapify_by_insertion(arr, key2reversed_compare(key));
+}
+
+/// 두 heap을 이어붙인 slice를 하나의 max heap으로 병합합니다.
+///
+/// 인접한 두 heap(`combined[..mid]`, `combined[mid..]`)을 하나로 합치는 meld 연산입니다.
+/// 뒤쪽 heap은 index가 이동하여 더 이상 heap 구조가 아니므로, slice 전체를 임의의 배열로 보고
+/// [`heapify`]로 다시 구성합니다. 따라서 두 부분이 heap이 아니어도 올바르게 동작합니다.
+/// 두 개의 분리된 slice는 `Vec`에 이어붙인 후 이 함수를 호출하면 됩니다.
+///
+/// Binary heap은 배열 상의 위치로 트리 구조를 표현하므로, 두 heap을 O(log n)에 병합할 수 없습니다.
+/// 병합이 잦다면 leftist heap, pairing heap 등 포인터 기반의 meldable heap을 사용하세요.
+///
+/// # 시간 복잡도
+///
+/// O(n) - n은 두 heap의 크기의 합입니다.
+///
+/// # Examples
+///
+/// ```rust
+/// use heap_on_slice::max_heap::{heapify, is_heap, merge_into_heap};
+///
+/// let mut a = vec![9, 6, 4, 1];
+/// let mut b = vec![7, 2, 3];
+/// heapify(&mut a);
+/// heapify(&mut b);
+///
+/// a.append(&mut b);
+/// merge_into_heap(&mut a);
+/// assert!(is_heap(&a));
+/// assert_eq!(a[0], 9); // 전체의 최대값이 root에 위치
+/// ```
+pub fn merge_into_heap<T: Ord>(combined: &mut [T]) {
+    heapify(combined);
+}
+
+/// 사용자 정의 comparator로 두 heap을 이어붙인 slice를 하나의 heap으로 병합합니다.
+///
+/// [`merge_into_heap`]과 동일한 기능을 하지만 `Ord::cmp` 대신 `compare` 함수를 인자로 받습니다.
+pub fn merge_into_heap_by<T, F>(combined: &mut [T], compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    heapify_by(combined, compare);
+}
+
+/// key extraction 함수로 두 heap을 이어붙인 slice를 하나의 heap으로 병합합니다.
+///
+/// [`merge_into_heap`]과 동일한 기능을 하지만 원소 비교 시 `key` 함수로 추출한 값을 사용합니다.
+pub fn merge_into_heap_by_key<T, K, F>(combined: &mut [T], key: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    heapify_by_key(combined, key);
+}
+
+/// 여러 위치를 임의로 수정한 slice의 heap property를 확인하고, 깨진 경우에만 복구합니다.
+///
+/// 먼저 [`is_heap`]으로 위반 여부를 확인하고, 위반이 있는 경우에만 [`heapify`]를 수행합니다.
+/// 반환값은 복구가 필요했는지 여부이므로, 수정이 heap property를 깨뜨렸는지 알 수 있습니다.
+/// 수정한 위치가 하나뿐이라면 O(log n)인 [`adjust_heap`]을 사용하세요.
+///
+/// # 시간 복잡도
+///
+/// O(n) - 확인과 heapify 모두 선형 시간입니다.
+///
+/// # Examples
+///
+/// ```rust
+/// use heap_on_slice::max_heap::{heapify, is_heap, repair_heap};
+///
+/// let mut arr = vec![7, 5, 2, 3, 1];
+/// heapify(&mut arr);
+/// assert!(!repair_heap(&mut arr)); // 이미 valid heap
+///
+/// arr[0] = 0; // root를 임의로 수정
+/// assert!(repair_heap(&mut arr));
+/// assert!(is_heap(&arr));
+/// ```
+pub fn repair_heap<T: Ord>(arr: &mut [T]) -> bool {
+    if is_heap(arr) {
+        return false;
+    }
+    heapify(arr);
+    true
+}
+
+/// 사용자 정의 comparator로 slice의 heap property를 확인하고, 깨진 경우에만 복구합니다.
+///
+/// [`repair_heap`]과 동일한 기능을 하지만 `Ord::cmp` 대신 `compare` 함수를 인자로 받습니다.
+pub fn repair_heap_by<T, F>(arr: &mut [T], mut compare: F) -> bool
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    if is_heap_by(arr, &mut compare) {
+        return false;
+    }
+    heapify_by(arr, compare);
+    true
+}
+
+/// key extraction 함수로 slice의 heap property를 확인하고, 깨진 경우에만 복구합니다.
+///
+/// [`repair_heap`]과 동일한 기능을 하지만 원소 비교 시 `key` 함수로 추출한 값을 사용합니다.
+pub fn repair_heap_by_key<T, K, F>(arr: &mut [T], mut key: F) -> bool
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    if is_heap_by_key(arr, &mut key) {
+        return false;
+    }
+    heapify_by_key(arr, key);
+    true
+}
+
 /// 새로운 원소를 heap에 추가하고 최대 원소를 반환합니다.
 ///
 /// 이 함수는 heap의 크기를 변경하지 않고 push와 pop을 한 번에 수행합니다.
 /// 새 원소가 현재 최대값보다 크면 즉시 반환하고, 그렇지 않으면 기존 최대값을
 /// 새 원소로 교체한 후 heap property를 복구합니다.
 ///
+/// # Panic safety
+///
+/// 비교 중 panic이 발생하더라도 원소가 복제되거나 유실되지 않습니다.
+/// 이 경우 slice의 heap property는 보장되지 않으며, 반환되지 못한 원소는 unwinding 중에 drop됩니다.
+///
 /// # 시간 복잡도
 ///
 /// O(log n) - 최대 한 번의 sift-down 연산이 필요합니다.
@@ -374,11 +623,95 @@ where
     Impl::heap_pop(arr, key2reversed_compare(key))
 }
 
+/// heap에서 최대 원소를 제거하고, 그 값을 나머지 slice와 함께 반환합니다.
+///
+/// [`heap_pop`]과 같이 root를 slice의 끝으로 옮긴 후 나머지 slice의 heap property를 복구합니다.
+/// slice는 길이를 줄일 수 없으므로 root를 slice 밖으로 move할 수 없습니다.
+/// 따라서 끝으로 옮겨진 root를 clone하여 반환하며, 원본은 slice의 끝에 그대로 남습니다.
+/// Clone 없이 값을 꺼내야 한다면 `Vec`에 대해 동작하는 [`vec_heap_pop`]을 사용하세요.
+/// 빈 slice의 경우 None을 반환합니다.
+///
+/// # 시간 복잡도
+///
+/// O(log n) - [`heap_pop`]에 원소 하나의 clone이 추가됩니다.
+///
+/// # Examples
+///
+/// ```rust
+/// use heap_on_slice::max_heap::{heapify, heap_pop_value};
+///
+/// let mut arr = vec![1, 5, 2, 4, 3];
+/// heapify(&mut arr);
+///
+/// let mut popped = Vec::new();
+/// let mut heap = arr.as_mut_slice();
+/// while let Some((value, rest)) = heap_pop_value(heap) {
+///     popped.push(value);
+///     heap = rest;
+/// }
+/// assert_eq!(popped, [5, 4, 3, 2, 1]);
+/// ```
+pub fn heap_pop_value<T: Ord + Clone>(arr: &mut [T]) -> Option<(T, &mut [T])> {
+    Impl::heap_pop_value(arr, reversed_cmp)
+}
+
+/// 사용자 정의 comparator로 heap에서 원소를 제거하고 그 값을 반환합니다.
+///
+/// [`heap_pop_value`]와 동일한 기능을 하지만 `Ord::cmp` 대신 `compare` 함수를 인자로 받습니다.
+pub fn heap_pop_value_by<T, F>(arr: &mut [T], compare: F) -> Option<(T, &mut [T])>
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    Impl::heap_pop_value(arr, reverse_compare(compare))
+}
+
+/// key extraction 함수로 heap에서 원소를 제거하고 그 값을 반환합니다.
+///
+/// [`heap_pop_value`]와 동일한 기능을 하지만 원소 비교 시 `key` 함수로 추출한 값을 사용합니다.
+pub fn heap_pop_value_by_key<T, K, F>(arr: &mut [T], key: F) -> Option<(T, &mut [T])>
+where
+    T: Clone,
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    Impl::heap_pop_value(arr, key2reversed_compare(key))
+}
+
+/// heap이 빌 때까지 [`heap_pop`]을 반복하여, pop된 순서대로 원소를 담은 `Vec`을 반환합니다.
+///
+/// `arr`는 valid max heap이어야 하며, 반환값은 내림차순으로 정렬됩니다.
+/// [`heap_pop`]은 제거한 원소를 slice의 끝으로 옮기므로, 함수가 반환된 후 `arr`는 오름차순으로 정렬된 상태가 됩니다.
+/// slice에서 원소를 move할 수 없으므로 `T: Clone`이 필요합니다.
+///
+/// # 시간 복잡도
+///
+/// O(n log n) - n번의 [`heap_pop`]이 필요합니다.
+///
+/// # Examples
+///
+/// ```rust
+/// use heap_on_slice::max_heap::{heapify, heap_drain};
+///
+/// let mut arr = vec![3, 1, 4, 1, 5];
+/// heapify(&mut arr);
+/// assert_eq!(heap_drain(&mut arr), vec![5, 4, 3, 1, 1]);
+/// assert_eq!(arr, vec![1, 1, 3, 4, 5]);
+/// ```
+pub fn heap_drain<T: Ord + Clone>(arr: &mut [T]) -> Vec<T> {
+    debug_assert!(is_heap(arr), "heap_drain: arr is not a valid max heap");
+    let mut heap = &mut *arr;
+    while let Some(rest) = heap_pop(heap) {
+        heap = rest;
+    }
+    arr.iter().rev().cloned().collect()
+}
+
 /// slice를 in-place로 오름차순 정렬합니다.
 ///
 /// Heap_sort algorithm을 사용하여 slice를 정렬합니다. Max heap의 특성상
 /// 결과는 오름차순으로 정렬됩니다.
-/// 내림차순 정렬을 원하는 경우 [`min_heap::heap_reverse_sort`](crate::min_heap::heap_reverse_sort)를 사용하세요.
+/// 내림차순 정렬을 원하는 경우 [`heap_sort_desc`]를 사용하세요.
 ///
 /// # 시간 복잡도
 ///
@@ -397,6 +730,28 @@ pub fn heap_sort<T: Ord>(arr: &mut [T]) {
     Impl::heap_reverse_sort(arr, reversed_cmp);
 }
 
+/// slice를 in-place로 내림차순 정렬합니다.
+///
+/// [`min_heap::heap_reverse_sort`](crate::min_heap::heap_reverse_sort)를 거치지 않고 max_heap 모듈에서 바로 내림차순 정렬을 할 수 있도록 제공됩니다.
+/// 내부적으로는 역순 비교로 min heap을 구성한 후 heap_sort를 수행합니다.
+///
+/// # 시간 복잡도
+///
+/// O(n log n) - 표준적인 heap_sort의 시간 복잡도입니다.
+///
+/// # Examples
+///
+/// ```rust
+/// use heap_on_slice::max_heap::heap_sort_desc;
+///
+/// let mut arr = vec![3, 1, 4, 1, 5];
+/// heap_sort_desc(&mut arr);
+/// assert_eq!(arr, vec![5, 4, 3, 1, 1]); // 내림차순 정렬
+/// ```
+pub fn heap_sort_desc<T: Ord>(arr: &mut [T]) {
+    Impl::heap_reverse_sort(arr, Ord::cmp);
+}
+
 /// 사용자 정의 comparator로 heap sort를 수행합니다.
 ///
 /// [`heap_sort`]와 동일한 기능을 하지만 `Ord::cmp` 대신 `compare` 함수를 인자로 받습니다.
@@ -418,11 +773,195 @@ where
     Impl::heap_reverse_sort(arr, key2reversed_compare(key));
 }
 
+/// key extraction 함수로 stable heap sort를 수행합니다.
+///
+/// Heap sort는 본래 stable하지 않으므로, 각 원소의 key를 원래 index와 함께 `(key, index)`로 장식하여
+/// heap sort한 후, 정렬된 index 순서대로 원소를 재배치합니다.
+/// key가 같은 원소들은 index로 비교되므로 원래의 순서가 유지됩니다.
+/// `key` 함수는 각 원소에 대해 한 번씩만 호출됩니다.
+///
+/// # 공간 복잡도
+///
+/// O(n) - 원소마다 key와 index를 저장하는 추가 공간이 필요합니다.
+///
+/// # 시간 복잡도
+///
+/// O(n log n) - `(key, index)`에 대한 heap sort와 O(n)의 재배치로 구성됩니다.
+///
+/// # Examples
+///
+/// ```rust
+/// use heap_on_slice::max_heap::stable_heap_sort_by_key;
+///
+/// let mut arr = vec![(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd')];
+/// stable_heap_sort_by_key(&mut arr, |p| p.0);
+/// assert_eq!(arr, vec![(1, 'b'), (1, 'd'), (2, 'a'), (2, 'c')]);
+/// ```
+pub fn stable_heap_sort_by_key<T, K, F>(arr: &mut [T], key: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    let mut decorated: Vec<(K, usize)> = arr.iter().map(key).zip(0..).collect();
+    heap_sort(&mut decorated);
+
+    // dest[j]는 원래 index j의 원소가 이동할 위치
+    let mut dest = vec![0; arr.len()];
+    for (i, (_, j)) in decorated.into_iter().enumerate() {
+        dest[j] = i;
+    }
+    // cycle을 따라 swap하며, swap마다 원소 하나가 제자리를 찾는다
+    for i in 0..arr.len() {
+        while dest[i] != i {
+            let j = dest[i];
+            arr.swap(i, j);
+            dest.swap(i, j);
+        }
+    }
+}
+
+/// heap sort 후 정렬되었는지, 다시 heapify 후 valid max heap인지 확인하는 round-trip 검증을 수행합니다.
+///
+/// [`heap_sort`]로 slice를 오름차순 정렬하고 정렬 여부를 확인한 후, [`heapify`]로 다시 heap을 구성하고
+/// [`is_heap`]으로 heap property를 확인합니다. 두 확인이 모두 성공한 경우 `true`를 반환합니다.
+/// 확인에는 같은 비교 기준만을 사용하므로, 사용자 정의 comparator가 일관된 total order인지
+/// 확인하는 self-check로 사용할 수 있습니다. 일관되지 않은 comparator는 대부분 `false`가 됩니다.
+///
+/// 함수가 끝난 후 slice는 항상 heapify된 상태입니다.
+///
+/// # 시간 복잡도
+///
+/// O(n log n) - heap sort가 지배적이며, 확인과 heapify는 O(n)입니다.
+///
+/// # Examples
+///
+/// ```rust
+/// use heap_on_slice::max_heap::{heapsort_then_rebuild, heapsort_then_rebuild_by, is_heap};
+///
+/// let mut arr = vec![3, 1, 4, 1, 5, 9, 2, 6];
+/// assert!(heapsort_then_rebuild(&mut arr));
+/// assert!(is_heap(&arr));
+///
+/// // a < b와 b < a가 동시에 성립하는 comparator
+/// assert!(!heapsort_then_rebuild_by(&mut arr, |_, _| std::cmp::Ordering::Less));
+/// ```
+pub fn heapsort_then_rebuild<T: Ord>(arr: &mut [T]) -> bool {
+    Impl::heapsort_then_rebuild(arr, reversed_cmp)
+}
+
+/// 사용자 정의 comparator로 heap sort와 heapify의 round-trip 검증을 수행합니다.
+///
+/// [`heapsort_then_rebuild`]와 동일한 기능을 하지만 `Ord::cmp` 대신 `compare` 함수를 인자로 받습니다.
+pub fn heapsort_then_rebuild_by<T, F>(arr: &mut [T], compare: F) -> bool
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    Impl::heapsort_then_rebuild(arr, reverse_compare(compare))
+}
+
+/// key extraction 함수로 heap sort와 heapify의 round-trip 검증을 수행합니다.
+///
+/// [`heapsort_then_rebuild`]와 동일한 기능을 하지만 원소 비교 시 `key` 함수로 추출한 값을 사용합니다.
+pub fn heapsort_then_rebuild_by_key<T, K, F>(arr: &mut [T], key: F) -> bool
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    Impl::heapsort_then_rebuild(arr, key2reversed_compare(key))
+}
+
+/// `Vec`으로 구성된 heap에 새 원소를 추가합니다.
+///
+/// slice 기반의 함수들은 크기를 바꿀 수 없으므로, heap을 키우려면 `Vec`이 필요합니다.
+/// 이 함수는 `x`를 `vec`의 끝에 추가한 후 sift-up하여, `vec`을 valid max heap으로 유지합니다.
+///
+/// # 시간 복잡도
+///
+/// O(log n) - 최대 트리의 높이만큼 이동이 필요합니다. (`Vec`의 재할당 비용 제외)
+///
+/// # Examples
+///
+/// ```rust
+/// use heap_on_slice::max_heap::{vec_heap_push, vec_heap_pop, is_heap};
+///
+/// let mut heap = Vec::new();
+/// for x in [5, 3, 8] {
+///     vec_heap_push(&mut heap, x);
+/// }
+/// assert!(is_heap(&heap));
+/// assert_eq!(vec_heap_pop(&mut heap), Some(8));
+/// assert_eq!(heap.len(), 2);
+/// ```
+pub fn vec_heap_push<T: Ord>(vec: &mut Vec<T>, x: T) {
+    Impl::vec_heap_push(vec, x, reversed_cmp);
+}
+
+/// 사용자 정의 comparator로 `Vec` heap에 원소를 추가합니다.
+///
+/// [`vec_heap_push`]와 동일한 기능을 하지만 `Ord::cmp` 대신 `compare` 함수를 인자로 받습니다.
+pub fn vec_heap_push_by<T, F>(vec: &mut Vec<T>, x: T, compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    Impl::vec_heap_push(vec, x, reverse_compare(compare));
+}
+
+/// key extraction 함수로 `Vec` heap에 원소를 추가합니다.
+///
+/// [`vec_heap_push`]와 동일한 기능을 하지만 원소 비교 시 `key` 함수로 추출한 값을 사용합니다.
+pub fn vec_heap_push_by_key<T, K, F>(vec: &mut Vec<T>, x: T, key: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    Impl::vec_heap_push(vec, x, key2reversed_compare(key));
+}
+
+/// `Vec`으로 구성된 heap에서 최대 원소를 제거하여 반환합니다.
+///
+/// [`heap_pop`]과 달리 제거된 원소를 반환하고 `vec`의 길이를 줄입니다.
+/// 빈 `Vec`의 경우 None을 반환합니다.
+///
+/// # 시간 복잡도
+///
+/// O(log n) - root 제거 후 heap property 복구가 필요합니다.
+pub fn vec_heap_pop<T: Ord>(vec: &mut Vec<T>) -> Option<T> {
+    Impl::vec_heap_pop(vec, reversed_cmp)
+}
+
+/// 사용자 정의 comparator로 `Vec` heap에서 root를 제거합니다.
+///
+/// [`vec_heap_pop`]과 동일한 기능을 하지만 `Ord::cmp` 대신 `compare` 함수를 인자로 받습니다.
+pub fn vec_heap_pop_by<T, F>(vec: &mut Vec<T>, compare: F) -> Option<T>
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    Impl::vec_heap_pop(vec, reverse_compare(compare))
+}
+
+/// key extraction 함수로 `Vec` heap에서 root를 제거합니다.
+///
+/// [`vec_heap_pop`]과 동일한 기능을 하지만 원소 비교 시 `key` 함수로 추출한 값을 사용합니다.
+pub fn vec_heap_pop_by_key<T, K, F>(vec: &mut Vec<T>, key: F) -> Option<T>
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    Impl::vec_heap_pop(vec, key2reversed_compare(key))
+}
+
 /// 특정 위치의 원소에 대해 heap property를 복구합니다.
 ///
 /// 지정된 인덱스의 원소 값이 변경되어 heap property가 깨진 경우,
 /// 해당 원소를 적절한 위치로 이동시켜 heap property를 복구합니다.
 ///
+/// # 전제 조건
+///
+/// `idx` 이외의 위치는 모두 heap property를 만족해야 합니다.
+/// 즉 heap에서 `idx`의 원소 하나만 변경된 경우에만 올바르게 동작하며,
+/// 여러 위치가 깨진 경우 panic 없이 heap이 아닌 결과를 남깁니다.
+/// 전제 조건을 확인하려면 [`adjust_heap_checked`]를 사용합니다.
+///
 /// # 반환값
 ///
 /// 실제로 원소 이동이 발생한 경우 `true`, 그렇지 않으면 `false`를 반환합니다.
@@ -468,9 +1007,154 @@ where
     Impl::adjust_heap(arr, idx, key2reversed_compare(key))
 }
 
+/// 특정 위치의 원소에 대해 heap property를 복구하고, 그 원소의 최종 위치를 반환합니다.
+///
+/// [`adjust_heap`]과 동일하게 원소를 이동시키지만, 이동 여부 대신 `idx`에 있던 원소가 도착한 index를 반환합니다.
+/// 이동이 없었다면 `idx`를 그대로 반환합니다.
+/// 원소의 위치를 외부의 index → 위치 map으로 관리하는 indexed priority queue를 slice 위에 구성할 때 사용합니다.
+///
+/// # 전제 조건
+///
+/// [`adjust_heap`]과 같이 `idx` 이외의 위치는 모두 heap property를 만족해야 합니다.
+///
+/// # 시간 복잡도
+///
+/// O(log n) - 최대 트리의 높이만큼 이동이 필요합니다.
+///
+/// # Examples
+///
+/// ```rust
+/// use heap_on_slice::max_heap::{adjust_heap_tracked, is_heap};
+///
+/// let mut arr = vec![7, 5, 3, 1, 2];
+/// assert!(is_heap(&arr));
+///
+/// // 마지막 원소가 root까지 올라감
+/// arr[4] = 10;
+/// assert_eq!(adjust_heap_tracked(&mut arr, 4), 0);
+/// assert!(is_heap(&arr));
+/// ```
+pub fn adjust_heap_tracked<T: Ord>(arr: &mut [T], idx: usize) -> usize {
+    Impl::adjust_heap_tracked(arr, idx, reversed_cmp)
+}
+
+/// 사용자 정의 comparator로 heap adjustment를 수행하고, 원소의 최종 위치를 반환합니다.
+///
+/// [`adjust_heap_tracked`]와 동일한 기능을 하지만 `Ord::cmp` 대신 `compare` 함수를 인자로 받습니다.
+pub fn adjust_heap_tracked_by<T, F>(arr: &mut [T], idx: usize, compare: F) -> usize
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    Impl::adjust_heap_tracked(arr, idx, reverse_compare(compare))
+}
+
+/// key extraction 함수로 heap adjustment를 수행하고, 원소의 최종 위치를 반환합니다.
+///
+/// [`adjust_heap_tracked`]와 동일한 기능을 하지만 원소 비교 시 `key` 함수로 추출한 값을 사용합니다.
+pub fn adjust_heap_tracked_by_key<T, K, F>(arr: &mut [T], idx: usize, key: F) -> usize
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    Impl::adjust_heap_tracked(arr, idx, key2reversed_compare(key))
+}
+
+/// 전제 조건을 확인한 후 특정 위치의 원소에 대해 heap property를 복구합니다.
+///
+/// [`adjust_heap`]과 동일한 기능을 하지만, 먼저 `idx` 이외의 위치가 모두
+/// heap property를 만족하는지 확인합니다.
+///
+/// # Panics
+///
+/// `idx` 이외의 위치에서 heap property가 깨져 있는 경우 panic이 발생합니다.
+///
+/// # 시간 복잡도
+///
+/// O(n) - 전제 조건 확인에 배열 전체를 순회합니다.
+pub fn adjust_heap_checked<T: Ord>(arr: &mut [T], idx: usize) -> bool {
+    Impl::adjust_heap_checked(arr, idx, reversed_cmp)
+}
+
+/// 사용자 정의 comparator로 전제 조건을 확인한 후 heap adjustment를 수행합니다.
+///
+/// [`adjust_heap_checked`]와 동일한 기능을 하지만 `Ord::cmp` 대신 `compare` 함수를 인자로 받습니다.
+pub fn adjust_heap_checked_by<T, F>(arr: &mut [T], idx: usize, compare: F) -> bool
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    Impl::adjust_heap_checked(arr, idx, reverse_compare(compare))
+}
+
+/// key extraction 함수로 전제 조건을 확인한 후 heap adjustment를 수행합니다.
+///
+/// [`adjust_heap_checked`]와 동일한 기능을 하지만 원소 비교 시 `key` 함수로 추출한 값을 사용합니다.
+pub fn adjust_heap_checked_by_key<T, K, F>(arr: &mut [T], idx: usize, key: F) -> bool
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    Impl::adjust_heap_checked(arr, idx, key2reversed_compare(key))
+}
+
+/// k번째로 작은 원소(0-based)를 찾아 `arr[k]`에 위치시킵니다.
+///
+/// 크기 `k + 1`의 max heap을 `arr[..=k]`에 유지하며 나머지 원소를 한 번씩 훑는
+/// heap selection을 사용합니다. Quickselect 기반의 [`slice::select_nth_unstable`]과 달리
+/// 입력에 관계없이 O(n log k)의 최악 시간 복잡도를 보장합니다.
+///
+/// 함수가 반환된 후 다음이 성립합니다:
+/// - `arr[k]`는 `arr`을 정렬했을 때 index k에 오는 원소입니다.
+/// - `arr[..k]`의 모든 원소는 `arr[k]`보다 작거나 같습니다. (순서는 정해지지 않습니다.)
+/// - `arr[k + 1..]`의 모든 원소는 `arr[k]`보다 크거나 같습니다. (순서는 정해지지 않습니다.)
+///
+/// # Panics
+///
+/// `k >= arr.len()`인 경우 panic이 발생합니다.
+///
+/// # 시간 복잡도
+///
+/// O(n log k) - 크기 `k + 1`인 heap에 최대 n번의 sift-down 연산이 필요합니다.
+///
+/// # Examples
+///
+/// ```rust
+/// use heap_on_slice::max_heap::heap_select_nth;
+///
+/// let mut arr = vec![9, 1, 8, 2, 7, 3, 6];
+/// heap_select_nth(&mut arr, 2);
+/// assert_eq!(arr[2], 3);
+/// assert!(arr[..2].iter().all(|x| *x <= 3));
+/// assert!(arr[3..].iter().all(|x| *x >= 3));
+/// ```
+pub fn heap_select_nth<T: Ord>(arr: &mut [T], k: usize) {
+    Impl::heap_select_nth(arr, k, reversed_cmp);
+}
+
+/// 사용자 정의 comparator로 heap selection을 수행합니다.
+///
+/// [`heap_select_nth`]와 동일한 기능을 하지만 `Ord::cmp` 대신 `compare` 함수를 인자로 받습니다.
+pub fn heap_select_nth_by<T, F>(arr: &mut [T], k: usize, compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    Impl::heap_select_nth(arr, k, reverse_compare(compare));
+}
+
+/// key extraction 함수로 heap selection을 수행합니다.
+///
+/// [`heap_select_nth`]와 동일한 기능을 하지만 원소 비교 시 `key` 함수로 추출한 값을 사용합니다.
+pub fn heap_select_nth_by_key<T, K, F>(arr: &mut [T], k: usize, key: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    Impl::heap_select_nth(arr, k, key2reversed_compare(key));
+}
+
 #[cfg(test)]
 mod unit_test {
     use super::*;
+    use crate::test_util::pseudo_random;
     use std::cmp::Ordering;
 
     // Helper function for custom comparison (reverse order for testing - making it min heap behavior)
@@ -557,6 +1241,64 @@ mod unit_test {
         assert!(!is_heap_by_key(&invalid_people, |p| p.age));
     }
 
+    #[test]
+    fn test_is_heap_by_key_cached() {
+        let mut calls = 0;
+        let arr = vec![7, 5, 6, 1, 3, 4];
+        assert!(is_heap_by_key_cached(&arr, |x| {
+            calls += 1;
+            *x
+        }));
+        // key is computed exactly once for each element
+        assert_eq!(calls, arr.len());
+
+        assert!(!is_heap_by_key_cached(&[1, 3, 2], |x| *x));
+        assert!(is_heap_by_key_cached(&Vec::<i32>::new(), |x| *x));
+    }
+
+    #[test]
+    fn test_vec_heap_push_pop() {
+        let mut heap: Vec<i32> = Vec::new();
+        let mut reference: Vec<i32> = Vec::new();
+        for i in 0..200 {
+            let x = (i * 7919) % 101;
+            vec_heap_push(&mut heap, x);
+            reference.push(x);
+            assert!(is_heap(&heap));
+
+            if i % 3 == 2 {
+                // reference is kept in the pop order
+                reference.sort();
+                assert_eq!(vec_heap_pop(&mut heap), reference.pop());
+                assert!(is_heap(&heap));
+            }
+        }
+        assert_eq!(heap.len(), reference.len());
+        reference.sort();
+        while let Some(x) = vec_heap_pop(&mut heap) {
+            assert_eq!(Some(x), reference.pop());
+        }
+        assert!(reference.is_empty());
+        assert_eq!(vec_heap_pop(&mut heap), None);
+    }
+
+    #[test]
+    fn test_vec_heap_push_pop_by_key() {
+        let mut heap: Vec<(u32, &str)> = Vec::new();
+        for item in [(3, "c"), (1, "a"), (2, "b")] {
+            vec_heap_push_by_key(&mut heap, item, |x| x.0);
+        }
+        assert!(is_heap_by_key(&heap, |x| x.0));
+        let first = vec_heap_pop_by_key(&mut heap, |x| x.0).unwrap();
+        assert_eq!(first.0, 3);
+
+        let mut heap: Vec<i32> = Vec::new();
+        for x in [3, 1, 2] {
+            vec_heap_push_by(&mut heap, x, reverse_compare);
+        }
+        assert_eq!(vec_heap_pop_by(&mut heap, reverse_compare), Some(1));
+    }
+
     #[test]
     fn test_heapify() {
         let mut arr = vec![5, 3, 8, 1, 9, 2, 7];
@@ -783,6 +1525,21 @@ mod unit_test {
         assert_eq!(single, vec![42]);
     }
 
+    #[test]
+    fn test_heap_sort_desc() {
+        let mut arr = vec![3, 1, 4, 2, 5, 2];
+        heap_sort_desc(&mut arr);
+        assert_eq!(arr, vec![5, 4, 3, 2, 2, 1]);
+
+        let mut asc = arr.clone();
+        heap_sort(&mut asc);
+        assert_eq!(asc, vec![1, 2, 2, 3, 4, 5]);
+
+        let mut empty: Vec<i32> = vec![];
+        heap_sort_desc(&mut empty);
+        assert!(empty.is_empty());
+    }
+
     #[test]
     fn test_heap_sort_by() {
         let mut arr = vec![3, 1, 4, 2, 5];
@@ -842,6 +1599,34 @@ mod unit_test {
         assert!(is_heap(&valid_heap));
     }
 
+    #[test]
+    fn test_adjust_heap_tracked() {
+        // sift up: 10 at index 4 goes to the root through index 1
+        let mut heap_up = vec![5, 4, 3, 2, 10];
+        assert_eq!(adjust_heap_tracked(&mut heap_up, 4), 0);
+        assert_eq!(heap_up[0], 10);
+        assert!(is_heap(&heap_up));
+
+        // sift down: 0 at the root goes to a leaf through the larger children
+        let mut heap_down = vec![0, 6, 5, 4, 3, 2, 1];
+        assert_eq!(adjust_heap_tracked(&mut heap_down, 0), 3);
+        assert_eq!(heap_down[3], 0);
+        assert!(is_heap(&heap_down));
+
+        // no move
+        let mut valid_heap = vec![5, 4, 3, 2, 1];
+        assert_eq!(adjust_heap_tracked(&mut valid_heap, 1), 1);
+        assert_eq!(valid_heap, [5, 4, 3, 2, 1]);
+
+        // by, by_key
+        let mut heap = vec![1, 2, 3, 4, 5, 6, 0];
+        assert_eq!(adjust_heap_tracked_by(&mut heap, 6, reverse_compare), 0);
+        assert!(is_heap_by(&heap, reverse_compare));
+        let mut heap = vec![(3, 'a'), (2, 'b'), (1, 'c'), (4, 'd')];
+        assert_eq!(adjust_heap_tracked_by_key(&mut heap, 3, |x| x.0), 0);
+        assert_eq!(heap[0], (4, 'd'));
+    }
+
     #[test]
     fn test_adjust_heap_by() {
         let mut arr = vec![1, 2, 3, 4, 5]; // Create a valid min heap with reverse comparator
@@ -884,6 +1669,165 @@ mod unit_test {
         assert!(is_heap_by_key(&people, |p| p.age));
     }
 
+    #[test]
+    fn test_adjust_heap_checked() {
+        // only index 4 is broken
+        let mut arr = vec![8, 7, 6, 5, 9];
+        assert!(adjust_heap_checked(&mut arr, 4));
+        assert!(is_heap(&arr));
+
+        // adjust_heap silently leaves a non-heap when two positions are broken
+        let mut arr = vec![8, 7, 6, 9, 9];
+        adjust_heap(&mut arr, 4);
+        assert!(!is_heap(&arr));
+    }
+
+    #[test]
+    #[should_panic(expected = "heap property is broken at positions other than idx")]
+    fn test_adjust_heap_checked_two_broken() {
+        let mut arr = vec![8, 7, 6, 9, 9];
+        adjust_heap_checked(&mut arr, 4);
+    }
+
+    #[test]
+    fn test_stable_heap_sort_by_key() {
+        // 중복 key가 많은 입력, 두 번째 값은 원래 순서
+        let mut arr: Vec<(u32, usize)> = (0..500).map(|i| (((i * 37) % 7) as u32, i)).collect();
+        let mut expected = arr.clone();
+        expected.sort_by_key(|p| p.0);
+
+        stable_heap_sort_by_key(&mut arr, |p| p.0);
+        assert_eq!(arr, expected);
+
+        // String처럼 Clone이 비싼 타입도 이동만으로 정렬
+        let mut words = vec!["pear", "fig", "apple", "kiwi", "plum", "date"]
+            .into_iter()
+            .map(String::from)
+            .collect::<Vec<_>>();
+        stable_heap_sort_by_key(&mut words, |w| w.len());
+        assert_eq!(words, vec!["fig", "pear", "kiwi", "plum", "date", "apple"]);
+
+        let mut empty: Vec<u32> = vec![];
+        stable_heap_sort_by_key(&mut empty, |x| *x);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_heapify_by_key_cached() {
+        let source: Vec<(u32, usize)> =
+            (0..1000).map(|i| (((i * 7919) % 1000) as u32, i)).collect();
+
+        let mut cached = source.clone();
+        let mut calls = 0;
+        heapify_by_key_cached(&mut cached, |p| {
+            calls += 1;
+            p.0
+        });
+        assert_eq!(calls, cached.len());
+        assert!(is_heap_by_key(&cached, |p| p.0));
+
+        // 같은 swap을 적용하므로 heapify_by_key와 같은 결과
+        let mut uncached = source;
+        let mut uncached_calls = 0;
+        heapify_by_key(&mut uncached, |p| {
+            uncached_calls += 1;
+            p.0
+        });
+        assert_eq!(cached, uncached);
+        assert!(uncached_calls > 2 * calls);
+    }
+
+    #[test]
+    fn test_merge_into_heap() {
+        let mut a: Vec<u32> = (0..100).map(|i| (i * 37) % 101).collect();
+        let mut b: Vec<u32> = (0..57).map(|i| (i * 13) % 61 + 50).collect();
+        heapify(&mut a);
+        heapify(&mut b);
+
+        let mut expected: Vec<u32> = a.iter().chain(b.iter()).copied().collect();
+        expected.sort();
+
+        // 인접한 두 heap
+        let mut merged = a.clone();
+        merged.extend_from_slice(&b);
+        merge_into_heap(&mut merged);
+        assert!(is_heap(&merged));
+        let mut sorted = merged.clone();
+        sorted.sort();
+        assert_eq!(sorted, expected);
+
+        // 순서를 바꾸어 이어붙인 경우와 한쪽이 빈 경우
+        let mut merged = b.clone();
+        merged.extend_from_slice(&a);
+        merge_into_heap(&mut merged);
+        assert!(is_heap(&merged));
+
+        let mut merged = a.clone();
+        merge_into_heap(&mut merged[..]);
+        assert_eq!(merged, a);
+
+        // comparator, key 버전
+        let mut merged: Vec<(u32, u32)> = a.iter().chain(b.iter()).map(|&x| (x, x % 7)).collect();
+        merge_into_heap_by_key(&mut merged, |p| p.1);
+        assert!(is_heap_by_key(&merged, |p| p.1));
+        merge_into_heap_by(&mut merged, |x, y| y.0.cmp(&x.0));
+        assert!(is_heap_by(&merged, |x, y| y.0.cmp(&x.0)));
+    }
+
+    #[test]
+    fn test_repair_heap() {
+        let mut arr: Vec<u32> = (0..300).map(|i| (i * 7919) % 257).collect();
+        heapify(&mut arr);
+        let valid = arr.clone();
+        assert!(!repair_heap(&mut arr));
+        assert_eq!(arr, valid); // valid heap은 변경하지 않음
+
+        // 여러 위치를 임의로 수정
+        for (i, idx) in [0, 17, 150, 299].into_iter().enumerate() {
+            arr[idx] = (i as u32 * 97) % 300;
+        }
+        assert!(!is_heap(&arr));
+        assert!(repair_heap(&mut arr));
+        assert!(is_heap(&arr));
+        assert!(!repair_heap(&mut arr));
+
+        let mut empty: [u32; 0] = [];
+        assert!(!repair_heap(&mut empty));
+
+        // comparator, key 버전
+        let mut words = vec!["a", "bbbb", "cc", "ddd"];
+        let was_heap = is_heap_by_key(&words, |w| w.len());
+        assert_eq!(repair_heap_by_key(&mut words, |w| w.len()), !was_heap);
+        assert!(is_heap_by_key(&words, |w| w.len()));
+        assert!(!repair_heap_by(&mut words, |a, b| a.len().cmp(&b.len())));
+    }
+
+    #[test]
+    fn test_heap_pop_value() {
+        let mut arr: Vec<u32> = (0..200).map(|i| (i * 7919) % 101).collect();
+        let mut expected = arr.clone();
+        expected.sort_by(|a, b| b.cmp(a));
+        heapify(&mut arr);
+
+        let mut popped = Vec::new();
+        let mut heap = arr.as_mut_slice();
+        while let Some((value, rest)) = heap_pop_value(heap) {
+            assert!(is_heap(rest));
+            popped.push(value);
+            heap = rest;
+        }
+        assert_eq!(popped, expected);
+        assert!(heap_pop_value::<u32>(&mut []).is_none());
+
+        // key 버전, 원본은 slice의 끝에 남음
+        let mut words = vec!["ccc", "a", "bbbb", "dd"];
+        heapify_by_key(&mut words, |w| w.len());
+        let (value, rest) = heap_pop_value_by_key(&mut words, |w| w.len()).unwrap();
+        assert_eq!(rest.len(), 3);
+        assert!(is_heap_by_key(rest, |w| w.len()));
+        assert_eq!(words[3], value);
+    }
+
     #[test]
     fn test_edge_cases() {
         // Test with duplicate elements
@@ -917,4 +1861,149 @@ mod unit_test {
             }
         }
     }
+
+    #[test]
+    fn test_heap_drain() {
+        let mut arr: Vec<i32> = (0..500).map(|i| (i * 7919) % 1009 - 500).collect();
+        let mut expected = arr.clone();
+        expected.sort_by(|a, b| b.cmp(a));
+
+        heapify(&mut arr);
+        assert_eq!(heap_drain(&mut arr), expected);
+        expected.reverse();
+        assert_eq!(arr, expected);
+
+        let mut empty: Vec<i32> = vec![];
+        assert!(heap_drain(&mut empty).is_empty());
+    }
+
+    fn check_select_nth(arr: &[i32], k: usize) {
+        let mut reference = arr.to_vec();
+        reference.sort();
+
+        let mut selected = arr.to_vec();
+        heap_select_nth(&mut selected, k);
+        assert_eq!(selected[k], reference[k]);
+        assert!(selected[..k].iter().all(|x| *x <= selected[k]));
+        assert!(selected[k + 1..].iter().all(|x| *x >= selected[k]));
+
+        selected.sort();
+        assert_eq!(selected, reference);
+    }
+
+    #[test]
+    fn test_heap_select_nth() {
+        let arr: Vec<i32> = (0..1000).map(|i| (i * 7919) % 1009 - 500).collect();
+        for k in [0, 1, 2, 10, 499, 500, 998, 999] {
+            check_select_nth(&arr, k);
+        }
+
+        // sorted, reversed, duplicates
+        let sorted: Vec<i32> = (0..100).collect();
+        let reversed: Vec<i32> = (0..100).rev().collect();
+        let dups: Vec<i32> = (0..100).map(|i| i % 3).collect();
+        for k in [0, 33, 50, 99] {
+            check_select_nth(&sorted, k);
+            check_select_nth(&reversed, k);
+            check_select_nth(&dups, k);
+        }
+
+        check_select_nth(&[42], 0);
+    }
+
+    #[test]
+    fn test_heap_select_nth_by() {
+        let mut arr = vec![3, 1, 4, 1, 5, 9, 2, 6];
+        heap_select_nth_by(&mut arr, 1, reverse_compare);
+        // 역순 비교이므로 두 번째로 큰 원소
+        assert_eq!(arr[1], 6);
+        assert!(arr[2..].iter().all(|x| *x <= 6));
+    }
+
+    #[test]
+    fn test_heap_select_nth_by_key() {
+        let mut people: Vec<Person> = [30, 25, 35, 20, 28]
+            .into_iter()
+            .map(|age| Person {
+                name: format!("P{age}"),
+                age,
+            })
+            .collect();
+        heap_select_nth_by_key(&mut people, 2, |p| p.age);
+        assert_eq!(people[2].age, 28);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_heap_select_nth_out_of_bounds() {
+        let mut arr = vec![1, 2, 3];
+        heap_select_nth(&mut arr, 3);
+    }
+
+    #[test]
+    fn test_heapsort_then_rebuild() {
+        // 임의의 데이터
+        let mut arr: Vec<u32> = pseudo_random(1000, 42)
+            .into_iter()
+            .map(|x| x as u32 % 500)
+            .collect();
+        let mut expected = arr.clone();
+        expected.sort();
+        assert!(heapsort_then_rebuild(&mut arr));
+        assert!(is_heap(&arr));
+        arr.sort();
+        assert_eq!(arr, expected);
+
+        // 사용자 정의 comparator, key
+        let mut arr: Vec<i32> = (0..100).map(|x| (x * 37) % 101 - 50).collect();
+        assert!(heapsort_then_rebuild_by(&mut arr, reverse_compare));
+        assert!(is_heap_by(&arr, reverse_compare));
+        assert!(heapsort_then_rebuild_by_key(&mut arr, |x: &i32| x.abs()));
+        assert!(is_heap_by_key(&arr, |x: &i32| x.abs()));
+
+        // 일관되지 않은 comparator
+        assert!(!heapsort_then_rebuild_by(&mut arr, |_, _| Ordering::Less));
+        assert!(!heapsort_then_rebuild_by(&mut arr, |_, _| {
+            Ordering::Greater
+        }));
+
+        let mut empty: Vec<i32> = vec![];
+        assert!(heapsort_then_rebuild(&mut empty));
+    }
+
+    #[test]
+    fn test_heapify_by_insertion() {
+        // 임의의 데이터, Floyd's heapify와 결과 배치는 다를 수 있지만 둘 다 valid heap
+        let arr: Vec<u32> = pseudo_random(1000, 42)
+            .into_iter()
+            .map(|x| x as u32 % 500)
+            .collect();
+        let mut by_insertion = arr.clone();
+        let mut by_floyd = arr.clone();
+        heapify_by_insertion(&mut by_insertion);
+        heapify(&mut by_floyd);
+        assert!(is_heap(&by_insertion));
+        assert!(is_heap(&by_floyd));
+        assert_eq!(by_insertion[0], by_floyd[0]);
+        assert_eq!(Some(&by_insertion[0]), arr.iter().max());
+
+        // 원소는 재배열될 뿐 그대로 유지
+        let mut expected = arr.clone();
+        expected.sort();
+        by_insertion.sort();
+        assert_eq!(by_insertion, expected);
+
+        // 사용자 정의 comparator, key
+        let mut arr: Vec<i32> = (0..100).map(|x| (x * 37) % 101 - 50).collect();
+        heapify_by_insertion_by(&mut arr, reverse_compare);
+        assert!(is_heap_by(&arr, reverse_compare));
+        heapify_by_insertion_by_key(&mut arr, |x: &i32| x.abs());
+        assert!(is_heap_by_key(&arr, |x: &i32| x.abs()));
+
+        let mut empty: Vec<i32> = vec![];
+        heapify_by_insertion(&mut empty);
+        let mut single = vec![1];
+        heapify_by_insertion(&mut single);
+        assert_eq!(single, vec![1]);
+    }
 }
@@ -11,18 +11,32 @@
 //! - [`is_heap`]: slice가 valid min heap인지 확인
 //! - [`is_heap_by`]: 사용자 정의 comparator로 heap 검증
 //! - [`is_heap_by_key`]: key extraction 함수로 heap 검증
+//! - [`is_heap_by_key_cached`]: key를 한 번씩만 계산하여 heap 검증
+//! - [`heapsort_then_rebuild`]: heap sort와 heapify의 round-trip으로 비교 기준을 검증
 //!
 //! ### Heap 구성
 //! - [`heapify`]: 임의의 slice를 valid min heap으로 변환
 //! - [`heapify_by`], [`heapify_by_key`]: 사용자 정의 비교 기준으로 heapify
+//! - [`heapify_by_key_cached`]: key를 한 번씩만 계산하여 heapify
+//! - [`heapify_by_insertion`]: 원소를 하나씩 push하는 방식으로 heap을 구성 (O(n log n), 비교용)
+//! - [`merge_into_heap`]: 두 heap을 이어붙인 slice를 하나의 heap으로 병합
+//! - [`repair_heap`]: heap property가 깨진 경우에만 heapify하고, 복구가 필요했는지 반환
 //!
 //! ### Priority Queue 연산
 //! - [`heap_pushpop`]: 새 원소 추가 후 최소값 제거
 //! - [`heap_pop`]: 최소 원소 제거
+//! - [`heap_pop_value`]: 최소 원소를 제거하고 그 값을 나머지 slice와 함께 반환
+//! - [`vec_heap_push`], [`vec_heap_pop`]: `Vec`으로 구성된 heap에 원소 추가 및 최소 원소 제거
 //! - [`adjust_heap`]: 특정 위치의 heap property 복구
+//! - [`adjust_heap_tracked`]: 특정 위치의 heap property 복구 후 원소의 최종 위치 반환
+//! - [`adjust_heap_checked`]: 전제 조건을 확인한 후 특정 위치의 heap property 복구
 //!
 //! ### 정렬
-//! - [`heap_reverse_sort`]: in-place 내림차순 정렬 (오름차순 정렬은 [`max_heap::heapsort`](crate::max_heap::heapsort) 사용)
+//! - [`heap_reverse_sort`]: in-place 내림차순 정렬
+//! - [`heap_sort_asc`]: in-place 오름차순 정렬
+//!
+//! ### 병합
+//! - [`merge_runs`]: 한 slice에 놓인 k개의 정렬된 run을 다른 slice에 stable하게 병합 (O(n log k))
 //!
 //! ## 사용 예시
 //!
@@ -167,6 +181,39 @@ where
     Impl::is_heap(arr, key2compare(key))
 }
 
+/// key extraction 함수로 slice가 valid heap인지 확인하되, 각 원소의 key를 한 번씩만 계산합니다.
+///
+/// [`is_heap_by_key`]는 비교할 때마다 key를 다시 계산하므로 `key`가 최대 약 2n번 호출됩니다.
+/// 이 함수는 key를 `Vec<K>`에 먼저 모은 후 heap property를 검사하므로, `key`는 정확히 n번 호출됩니다.
+/// key 계산 비용이 큰 경우에 사용하세요.
+///
+/// # 공간 복잡도
+///
+/// O(n) - key를 저장하기 위한 추가 메모리를 할당합니다.
+///
+/// # Examples
+///
+/// ```rust
+/// use heap_on_slice::min_heap::is_heap_by_key_cached;
+///
+/// struct Person { age: u32 }
+///
+/// let people = vec![
+///     Person { age: 25 },
+///     Person { age: 30 },
+///     Person { age: 28 },
+/// ];
+/// assert!(is_heap_by_key_cached(&people, |p| p.age));
+/// ```
+pub fn is_heap_by_key_cached<T, K, F>(arr: &[T], key: F) -> bool
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    let keys: Vec<K> = arr.iter().map(key).collect();
+    Impl::is_heap(&keys, K::cmp)
+}
+
 /// 임의의 slice를 valid min heap으로 변환합니다.
 ///
 /// Floyd's heap construction algorithm을 사용하여 bottom-up 방식으로 heap을 구성합니다.
@@ -237,12 +284,212 @@ where
     Impl::heapify(arr, key2compare(key));
 }
 
+/// key extraction 함수로 slice를 heap으로 변환하되, 각 원소의 key를 한 번씩만 계산합니다.
+///
+/// [`heapify_by_key`]는 비교할 때마다 key를 다시 계산하므로 `key`가 O(n)번, 약 4n번 호출됩니다.
+/// 이 함수는 key를 `Vec<K>`에 먼저 모은 후 key에 대해 heap을 구성하고,
+/// key에 적용한 swap을 slice에도 동일하게 적용합니다. 따라서 `key`는 정확히 n번 호출됩니다.
+/// key 계산 비용이 큰 경우에만 이득이 있으며, key가 저렴하다면 [`heapify_by_key`]를 사용하세요.
+///
+/// # 공간 복잡도
+///
+/// O(n) - key를 저장하기 위한 추가 메모리를 할당합니다.
+///
+/// # 시간 복잡도
+///
+/// O(n) - [`heapify`]와 같습니다.
+///
+/// # Examples
+///
+/// ```rust
+/// use heap_on_slice::min_heap::{heapify_by_key_cached, is_heap_by_key};
+///
+/// let mut words = vec!["ccc", "a", "bbbbbbbbb", "dd"];
+/// heapify_by_key_cached(&mut words, |w| w.len());
+/// assert!(is_heap_by_key(&words, |w| w.len()));
+/// ```
+pub fn heapify_by_key_cached<T, K, F>(arr: &mut [T], key: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    let mut keys: Vec<K> = arr.iter().map(key).collect();
+    Impl::heapify_cached(arr, &mut keys, K::cmp);
+}
+
+/// 원소를 하나씩 push하는 방식으로 slice를 valid min heap으로 변환합니다.
+///
+/// 앞쪽의 heap에 다음 원소를 추가하고 sift-up하는 과정을 반복하여, top-down으로 heap을 구성합니다.
+/// 결과는 [`heapify`]와 마찬가지로 valid heap이지만, 원소의 배치는 다를 수 있습니다.
+///
+/// 원소가 하나씩 도착하는 경우에는 이 방식이 자연스럽지만, 모든 원소가 이미 slice에 있다면
+/// O(n)인 [`heapify`] (Floyd's algorithm)를 사용하세요.
+/// 대부분의 node는 leaf 근처에 있어, sift-down은 짧게 끝나지만 sift-up은 root까지 길어질 수 있습니다.
+///
+/// # 시간 복잡도
+///
+/// O(n log n) - 각 원소의 sift-up이 O(log n)입니다.
+///
+/// # Examples
+///
+/// ```rust
+/// use heap_on_slice::min_heap::{heapify_by_insertion, is_heap};
+///
+/// let mut arr = vec![3, 1, 4, 1, 5, 9, 2];
+/// heapify_by_insertion(&mut arr);
+/// assert!(is_heap(&arr));
+/// assert_eq!(arr[0], 1); // 최소값이 root에 위치
+/// ```
+pub fn heapify_by_insertion<T: Ord>(arr: &mut [T]) {
+    Impl::heapify_by_insertion(arr, Ord::cmp);
+}
+
+/// 사용자 정의 comparator로 원소를 하나씩 push하는 방식의 heap 구성을 수행합니다.
+///
+/// [`heapify_by_insertion`]과 동일한 기능을 하지만 `Ord::cmp` 대신 `compare` 함수를 인자로 받습니다.
+pub fn heapify_by_insertion_by<T, F>(arr: &mut [T], compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    Impl::heapify_by_insertion(arr, compare);
+}
+
+/// key extraction 함수로 원소를 하나씩 push하는 방식의 heap 구성을 수행합니다.
+///
+/// [`heapify_by_insertion`]과 동일한 기능을 하지만 원소 비교 시 `key` 함수로 추출한 값을 사용합니다.
+pub fn heapify_by_insertion_by_key<T, K, F>(arr: &mut [T], key: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    Impl::heapify_by_insertion(arr, key2compare(key));
+}
+
+/// 두 heap을 이어붙인 slice를 하나의 min heap으로 병합합니다.
+///
+/// 인접한 두 heap(`combined[..mid]`, `combined[mid..]`)을 하나로 합치는 meld 연산입니다.
+/// 뒤쪽 heap은 index가 이동하여 더 이상 heap 구조가 아니므로, slice 전체를 임의의 배열로 보고
+/// [`heapify`]로 다시 구성합니다. 따라서 두 부분이 heap이 아니어도 올바르게 동작합니다.
+/// 두 개의 분리된 slice는 `Vec`에 이어붙인 후 이 함수를 호출하면 됩니다.
+///
+/// Binary heap은 배열 상의 위치로 트리 구조를 표현하므로, 두 heap을 O(log n)에 병합할 수 없습니다.
+/// 병합이 잦다면 leftist heap, pairing heap 등 포인터 기반의 meldable heap을 사용하세요.
+///
+/// # 시간 복잡도
+///
+/// O(n) - n은 두 heap의 크기의 합입니다.
+///
+/// # Examples
+///
+/// ```rust
+/// use heap_on_slice::min_heap::{heapify, is_heap, merge_into_heap};
+///
+/// let mut a = vec![1, 4, 6, 9];
+/// let mut b = vec![0, 2, 3];
+/// heapify(&mut a);
+/// heapify(&mut b);
+///
+/// a.append(&mut b);
+/// merge_into_heap(&mut a);
+/// assert!(is_heap(&a));
+/// assert_eq!(a[0], 0); // 전체의 최소값이 root에 위치
+/// ```
+pub fn merge_into_heap<T: Ord>(combined: &mut [T]) {
+    heapify(combined);
+}
+
+/// 사용자 정의 comparator로 두 heap을 이어붙인 slice를 하나의 heap으로 병합합니다.
+///
+/// [`merge_into_heap`]과 동일한 기능을 하지만 `Ord::cmp` 대신 `compare` 함수를 인자로 받습니다.
+pub fn merge_into_heap_by<T, F>(combined: &mut [T], compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    heapify_by(combined, compare);
+}
+
+/// key extraction 함수로 두 heap을 이어붙인 slice를 하나의 heap으로 병합합니다.
+///
+/// [`merge_into_heap`]과 동일한 기능을 하지만 원소 비교 시 `key` 함수로 추출한 값을 사용합니다.
+pub fn merge_into_heap_by_key<T, K, F>(combined: &mut [T], key: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    heapify_by_key(combined, key);
+}
+
+/// 여러 위치를 임의로 수정한 slice의 heap property를 확인하고, 깨진 경우에만 복구합니다.
+///
+/// 먼저 [`is_heap`]으로 위반 여부를 확인하고, 위반이 있는 경우에만 [`heapify`]를 수행합니다.
+/// 반환값은 복구가 필요했는지 여부이므로, 수정이 heap property를 깨뜨렸는지 알 수 있습니다.
+/// 수정한 위치가 하나뿐이라면 O(log n)인 [`adjust_heap`]을 사용하세요.
+///
+/// # 시간 복잡도
+///
+/// O(n) - 확인과 heapify 모두 선형 시간입니다.
+///
+/// # Examples
+///
+/// ```rust
+/// use heap_on_slice::min_heap::{heapify, is_heap, repair_heap};
+///
+/// let mut arr = vec![1, 3, 2, 7, 5];
+/// heapify(&mut arr);
+/// assert!(!repair_heap(&mut arr)); // 이미 valid heap
+///
+/// arr[0] = 9; // root를 임의로 수정
+/// assert!(repair_heap(&mut arr));
+/// assert!(is_heap(&arr));
+/// ```
+pub fn repair_heap<T: Ord>(arr: &mut [T]) -> bool {
+    if is_heap(arr) {
+        return false;
+    }
+    heapify(arr);
+    true
+}
+
+/// 사용자 정의 comparator로 slice의 heap property를 확인하고, 깨진 경우에만 복구합니다.
+///
+/// [`repair_heap`]과 동일한 기능을 하지만 `Ord::cmp` 대신 `compare` 함수를 인자로 받습니다.
+pub fn repair_heap_by<T, F>(arr: &mut [T], mut compare: F) -> bool
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    if is_heap_by(arr, &mut compare) {
+        return false;
+    }
+    heapify_by(arr, compare);
+    true
+}
+
+/// key extraction 함수로 slice의 heap property를 확인하고, 깨진 경우에만 복구합니다.
+///
+/// [`repair_heap`]과 동일한 기능을 하지만 원소 비교 시 `key` 함수로 추출한 값을 사용합니다.
+pub fn repair_heap_by_key<T, K, F>(arr: &mut [T], mut key: F) -> bool
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    if is_heap_by_key(arr, &mut key) {
+        return false;
+    }
+    heapify_by_key(arr, key);
+    true
+}
+
 /// 새로운 원소를 heap에 추가하고 최소 원소를 반환합니다.
 ///
 /// 이 함수는 heap의 크기를 변경하지 않고 push와 pop을 한 번에 수행합니다.
 /// 새 원소가 현재 최소값보다 작으면 즉시 반환하고, 그렇지 않으면 기존 최소값을
 /// 새 원소로 교체한 후 heap property를 복구합니다.
 ///
+/// # Panic safety
+///
+/// 비교 중 panic이 발생하더라도 원소가 복제되거나 유실되지 않습니다.
+/// 이 경우 slice의 heap property는 보장되지 않으며, 반환되지 못한 원소는 unwinding 중에 drop됩니다.
+///
 /// # 시간 복잡도
 ///
 /// O(log n) - 최대 한 번의 sift-down 연산이 필요합니다.
@@ -363,11 +610,66 @@ where
     Impl::heap_pop(arr, key2compare(key))
 }
 
+/// heap에서 최소 원소를 제거하고, 그 값을 나머지 slice와 함께 반환합니다.
+///
+/// [`heap_pop`]과 같이 root를 slice의 끝으로 옮긴 후 나머지 slice의 heap property를 복구합니다.
+/// slice는 길이를 줄일 수 없으므로 root를 slice 밖으로 move할 수 없습니다.
+/// 따라서 끝으로 옮겨진 root를 clone하여 반환하며, 원본은 slice의 끝에 그대로 남습니다.
+/// Clone 없이 값을 꺼내야 한다면 `Vec`에 대해 동작하는 [`vec_heap_pop`]을 사용하세요.
+/// 빈 slice의 경우 None을 반환합니다.
+///
+/// # 시간 복잡도
+///
+/// O(log n) - [`heap_pop`]에 원소 하나의 clone이 추가됩니다.
+///
+/// # Examples
+///
+/// ```rust
+/// use heap_on_slice::min_heap::{heapify, heap_pop_value};
+///
+/// let mut arr = vec![5, 1, 4, 2, 3];
+/// heapify(&mut arr);
+///
+/// let mut popped = Vec::new();
+/// let mut heap = arr.as_mut_slice();
+/// while let Some((value, rest)) = heap_pop_value(heap) {
+///     popped.push(value);
+///     heap = rest;
+/// }
+/// assert_eq!(popped, [1, 2, 3, 4, 5]);
+/// ```
+pub fn heap_pop_value<T: Ord + Clone>(arr: &mut [T]) -> Option<(T, &mut [T])> {
+    Impl::heap_pop_value(arr, Ord::cmp)
+}
+
+/// 사용자 정의 comparator로 heap에서 원소를 제거하고 그 값을 반환합니다.
+///
+/// [`heap_pop_value`]와 동일한 기능을 하지만 `Ord::cmp` 대신 `compare` 함수를 인자로 받습니다.
+pub fn heap_pop_value_by<T, F>(arr: &mut [T], compare: F) -> Option<(T, &mut [T])>
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    Impl::heap_pop_value(arr, compare)
+}
+
+/// key extraction 함수로 heap에서 원소를 제거하고 그 값을 반환합니다.
+///
+/// [`heap_pop_value`]와 동일한 기능을 하지만 원소 비교 시 `key` 함수로 추출한 값을 사용합니다.
+pub fn heap_pop_value_by_key<T, K, F>(arr: &mut [T], key: F) -> Option<(T, &mut [T])>
+where
+    T: Clone,
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    Impl::heap_pop_value(arr, key2compare(key))
+}
+
 /// slice를 in-place로 내림차순 정렬합니다.
 ///
 /// Heapsort algorithm을 사용하여 slice를 정렬합니다. Min heap의 특성상
 /// 결과는 내림차순으로 정렬됩니다.
-/// 오름차순 정렬을 원하는 경우 [`max_heap::heapsort`](crate::max_heap::heapsort)를 사용하세요.
+/// 오름차순 정렬을 원하는 경우 [`heap_sort_asc`]를 사용하세요.
 ///
 /// # 시간 복잡도
 ///
@@ -407,11 +709,170 @@ where
     Impl::heap_reverse_sort(arr, key2compare(key));
 }
 
+/// slice를 in-place로 오름차순 정렬합니다.
+///
+/// [`max_heap::heap_sort`](crate::max_heap::heap_sort)를 거치지 않고 min_heap 모듈에서 바로 오름차순 정렬을 할 수 있도록 제공됩니다.
+/// 내부적으로는 역순 비교로 max heap을 구성한 후 heap_sort를 수행합니다.
+///
+/// # 시간 복잡도
+///
+/// O(n log n) - 표준적인 heapsort의 시간 복잡도입니다.
+///
+/// # Examples
+///
+/// ```rust
+/// use heap_on_slice::min_heap::heap_sort_asc;
+///
+/// let mut arr = vec![3, 1, 4, 1, 5];
+/// heap_sort_asc(&mut arr);
+/// assert_eq!(arr, vec![1, 1, 3, 4, 5]); // 오름차순 정렬
+/// ```
+pub fn heap_sort_asc<T: Ord>(arr: &mut [T]) {
+    Impl::heap_reverse_sort(arr, |a: &T, b: &T| Ord::cmp(b, a));
+}
+
+/// heap sort 후 정렬되었는지, 다시 heapify 후 valid min heap인지 확인하는 round-trip 검증을 수행합니다.
+///
+/// [`heap_reverse_sort`]로 slice를 내림차순 정렬하고 정렬 여부를 확인한 후, [`heapify`]로 다시 heap을 구성하고
+/// [`is_heap`]으로 heap property를 확인합니다. 두 확인이 모두 성공한 경우 `true`를 반환합니다.
+/// 확인에는 같은 비교 기준만을 사용하므로, 사용자 정의 comparator가 일관된 total order인지
+/// 확인하는 self-check로 사용할 수 있습니다. 일관되지 않은 comparator는 대부분 `false`가 됩니다.
+///
+/// 함수가 끝난 후 slice는 항상 heapify된 상태입니다.
+///
+/// # 시간 복잡도
+///
+/// O(n log n) - heap sort가 지배적이며, 확인과 heapify는 O(n)입니다.
+///
+/// # Examples
+///
+/// ```rust
+/// use heap_on_slice::min_heap::{heapsort_then_rebuild, heapsort_then_rebuild_by, is_heap};
+///
+/// let mut arr = vec![3, 1, 4, 1, 5, 9, 2, 6];
+/// assert!(heapsort_then_rebuild(&mut arr));
+/// assert!(is_heap(&arr));
+///
+/// // a < b와 b < a가 동시에 성립하는 comparator
+/// assert!(!heapsort_then_rebuild_by(&mut arr, |_, _| std::cmp::Ordering::Less));
+/// ```
+pub fn heapsort_then_rebuild<T: Ord>(arr: &mut [T]) -> bool {
+    Impl::heapsort_then_rebuild(arr, Ord::cmp)
+}
+
+/// 사용자 정의 comparator로 heap sort와 heapify의 round-trip 검증을 수행합니다.
+///
+/// [`heapsort_then_rebuild`]와 동일한 기능을 하지만 `Ord::cmp` 대신 `compare` 함수를 인자로 받습니다.
+pub fn heapsort_then_rebuild_by<T, F>(arr: &mut [T], compare: F) -> bool
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    Impl::heapsort_then_rebuild(arr, compare)
+}
+
+/// key extraction 함수로 heap sort와 heapify의 round-trip 검증을 수행합니다.
+///
+/// [`heapsort_then_rebuild`]와 동일한 기능을 하지만 원소 비교 시 `key` 함수로 추출한 값을 사용합니다.
+pub fn heapsort_then_rebuild_by_key<T, K, F>(arr: &mut [T], key: F) -> bool
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    Impl::heapsort_then_rebuild(arr, key2compare(key))
+}
+
+/// `Vec`으로 구성된 heap에 새 원소를 추가합니다.
+///
+/// slice 기반의 함수들은 크기를 바꿀 수 없으므로, heap을 키우려면 `Vec`이 필요합니다.
+/// 이 함수는 `x`를 `vec`의 끝에 추가한 후 sift-up하여, `vec`을 valid min heap으로 유지합니다.
+///
+/// # 시간 복잡도
+///
+/// O(log n) - 최대 트리의 높이만큼 이동이 필요합니다. (`Vec`의 재할당 비용 제외)
+///
+/// # Examples
+///
+/// ```rust
+/// use heap_on_slice::min_heap::{vec_heap_push, vec_heap_pop, is_heap};
+///
+/// let mut heap = Vec::new();
+/// for x in [5, 3, 8] {
+///     vec_heap_push(&mut heap, x);
+/// }
+/// assert!(is_heap(&heap));
+/// assert_eq!(vec_heap_pop(&mut heap), Some(3));
+/// assert_eq!(heap.len(), 2);
+/// ```
+pub fn vec_heap_push<T: Ord>(vec: &mut Vec<T>, x: T) {
+    Impl::vec_heap_push(vec, x, Ord::cmp);
+}
+
+/// 사용자 정의 comparator로 `Vec` heap에 원소를 추가합니다.
+///
+/// [`vec_heap_push`]와 동일한 기능을 하지만 `Ord::cmp` 대신 `compare` 함수를 인자로 받습니다.
+pub fn vec_heap_push_by<T, F>(vec: &mut Vec<T>, x: T, compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    Impl::vec_heap_push(vec, x, compare);
+}
+
+/// key extraction 함수로 `Vec` heap에 원소를 추가합니다.
+///
+/// [`vec_heap_push`]와 동일한 기능을 하지만 원소 비교 시 `key` 함수로 추출한 값을 사용합니다.
+pub fn vec_heap_push_by_key<T, K, F>(vec: &mut Vec<T>, x: T, key: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    Impl::vec_heap_push(vec, x, key2compare(key));
+}
+
+/// `Vec`으로 구성된 heap에서 최소 원소를 제거하여 반환합니다.
+///
+/// [`heap_pop`]과 달리 제거된 원소를 반환하고 `vec`의 길이를 줄입니다.
+/// 빈 `Vec`의 경우 None을 반환합니다.
+///
+/// # 시간 복잡도
+///
+/// O(log n) - root 제거 후 heap property 복구가 필요합니다.
+pub fn vec_heap_pop<T: Ord>(vec: &mut Vec<T>) -> Option<T> {
+    Impl::vec_heap_pop(vec, Ord::cmp)
+}
+
+/// 사용자 정의 comparator로 `Vec` heap에서 root를 제거합니다.
+///
+/// [`vec_heap_pop`]과 동일한 기능을 하지만 `Ord::cmp` 대신 `compare` 함수를 인자로 받습니다.
+pub fn vec_heap_pop_by<T, F>(vec: &mut Vec<T>, compare: F) -> Option<T>
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    Impl::vec_heap_pop(vec, compare)
+}
+
+/// key extraction 함수로 `Vec` heap에서 root를 제거합니다.
+///
+/// [`vec_heap_pop`]과 동일한 기능을 하지만 원소 비교 시 `key` 함수로 추출한 값을 사용합니다.
+pub fn vec_heap_pop_by_key<T, K, F>(vec: &mut Vec<T>, key: F) -> Option<T>
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    Impl::vec_heap_pop(vec, key2compare(key))
+}
+
 /// 특정 위치의 원소에 대해 heap property를 복구합니다.
 ///
 /// 지정된 인덱스의 원소 값이 변경되어 heap property가 깨진 경우,
 /// 해당 원소를 적절한 위치로 이동시켜 heap property를 복구합니다.
 ///
+/// # 전제 조건
+///
+/// `idx` 이외의 위치는 모두 heap property를 만족해야 합니다.
+/// 즉 heap에서 `idx`의 원소 하나만 변경된 경우에만 올바르게 동작하며,
+/// 여러 위치가 깨진 경우 panic 없이 heap이 아닌 결과를 남깁니다.
+/// 전제 조건을 확인하려면 [`adjust_heap_checked`]를 사용합니다.
+///
 /// # 반환값
 ///
 /// 실제로 원소 이동이 발생한 경우 `true`, 그렇지 않으면 `false`를 반환합니다.
@@ -461,9 +922,159 @@ where
     Impl::adjust_heap(arr, idx, key2compare(key))
 }
 
+/// 특정 위치의 원소에 대해 heap property를 복구하고, 그 원소의 최종 위치를 반환합니다.
+///
+/// [`adjust_heap`]과 동일하게 원소를 이동시키지만, 이동 여부 대신 `idx`에 있던 원소가 도착한 index를 반환합니다.
+/// 이동이 없었다면 `idx`를 그대로 반환합니다.
+/// 원소의 위치를 외부의 index → 위치 map으로 관리하는 indexed priority queue를 slice 위에 구성할 때 사용합니다.
+///
+/// # 전제 조건
+///
+/// [`adjust_heap`]과 같이 `idx` 이외의 위치는 모두 heap property를 만족해야 합니다.
+///
+/// # 시간 복잡도
+///
+/// O(log n) - 최대 트리의 높이만큼 이동이 필요합니다.
+///
+/// # Examples
+///
+/// ```rust
+/// use heap_on_slice::min_heap::{adjust_heap_tracked, is_heap};
+///
+/// let mut arr = vec![1, 3, 2, 7, 5];
+/// assert!(is_heap(&arr));
+///
+/// // 마지막 원소가 root까지 올라감
+/// arr[4] = 0;
+/// assert_eq!(adjust_heap_tracked(&mut arr, 4), 0);
+/// assert!(is_heap(&arr));
+/// ```
+pub fn adjust_heap_tracked<T: Ord>(arr: &mut [T], idx: usize) -> usize {
+    Impl::adjust_heap_tracked(arr, idx, Ord::cmp)
+}
+
+/// 사용자 정의 comparator로 heap adjustment를 수행하고, 원소의 최종 위치를 반환합니다.
+///
+/// [`adjust_heap_tracked`]와 동일한 기능을 하지만 `Ord::cmp` 대신 `compare` 함수를 인자로 받습니다.
+pub fn adjust_heap_tracked_by<T, F>(arr: &mut [T], idx: usize, compare: F) -> usize
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    Impl::adjust_heap_tracked(arr, idx, compare)
+}
+
+/// key extraction 함수로 heap adjustment를 수행하고, 원소의 최종 위치를 반환합니다.
+///
+/// [`adjust_heap_tracked`]와 동일한 기능을 하지만 원소 비교 시 `key` 함수로 추출한 값을 사용합니다.
+pub fn adjust_heap_tracked_by_key<T, K, F>(arr: &mut [T], idx: usize, key: F) -> usize
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    Impl::adjust_heap_tracked(arr, idx, key2compare(key))
+}
+
+/// 전제 조건을 확인한 후 특정 위치의 원소에 대해 heap property를 복구합니다.
+///
+/// [`adjust_heap`]과 동일한 기능을 하지만, 먼저 `idx` 이외의 위치가 모두
+/// heap property를 만족하는지 확인합니다.
+///
+/// # Panics
+///
+/// `idx` 이외의 위치에서 heap property가 깨져 있는 경우 panic이 발생합니다.
+///
+/// # 시간 복잡도
+///
+/// O(n) - 전제 조건 확인에 배열 전체를 순회합니다.
+pub fn adjust_heap_checked<T: Ord>(arr: &mut [T], idx: usize) -> bool {
+    Impl::adjust_heap_checked(arr, idx, Ord::cmp)
+}
+
+/// 사용자 정의 comparator로 전제 조건을 확인한 후 heap adjustment를 수행합니다.
+///
+/// [`adjust_heap_checked`]와 동일한 기능을 하지만 `Ord::cmp` 대신 `compare` 함수를 인자로 받습니다.
+pub fn adjust_heap_checked_by<T, F>(arr: &mut [T], idx: usize, compare: F) -> bool
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    Impl::adjust_heap_checked(arr, idx, compare)
+}
+
+/// key extraction 함수로 전제 조건을 확인한 후 heap adjustment를 수행합니다.
+///
+/// [`adjust_heap_checked`]와 동일한 기능을 하지만 원소 비교 시 `key` 함수로 추출한 값을 사용합니다.
+pub fn adjust_heap_checked_by_key<T, K, F>(arr: &mut [T], idx: usize, key: F) -> bool
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    Impl::adjust_heap_checked(arr, idx, key2compare(key))
+}
+
+/// 하나의 slice에 연속으로 놓인 여러 개의 정렬된 run을 `out`에 stable하게 병합합니다.
+///
+/// run은 `run_boundaries`로 나뉩니다. 경계가 `[b0, b1, ..., b(k-2)]`이면
+/// `slice[..b0]`, `slice[b0..b1]`, ..., `slice[b(k-2)..]`의 k개의 run이 되며, 빈 run은 무시합니다.
+/// 따라서 `[0, b0, ..., slice.len()]`처럼 양 끝을 포함한 경계를 넘겨도 같은 결과가 됩니다.
+///
+/// 각 run의 다음 원소를 크기 k의 min heap으로 관리하여 가장 작은 원소를 차례로 `out`에 씁니다.
+/// 같은 원소는 앞선 run의 원소가 먼저 나오므로, run 내부의 순서와 run 사이의 순서가 모두 보존됩니다.
+/// external sort나 merge sort의 마지막 k-way merge 단계에 사용할 수 있습니다.
+///
+/// 원소는 clone하지 않고 `out`의 원소와 swap하여 옮기므로, 병합 후 `slice`에는 `out`의 원래 원소들이
+/// 임의의 순서로 남습니다. heap을 위해 크기 k의 `Vec`을 할당합니다.
+///
+/// # Panics
+///
+/// - `out`의 길이가 `slice`와 다른 경우
+/// - `run_boundaries`가 정렬되어 있지 않거나, `slice.len()`보다 큰 경계가 있는 경우
+/// - debug build에서 정렬되지 않은 run이 있는 경우
+///
+/// # 시간 복잡도
+///
+/// O(n log k) - n은 slice의 길이, k는 run의 개수입니다.
+///
+/// # Examples
+///
+/// ```rust
+/// use heap_on_slice::min_heap::merge_runs;
+///
+/// let mut runs = vec![1, 4, 7, 2, 5, 8, 0, 3, 6, 9];
+/// let mut out = vec![0; runs.len()];
+/// merge_runs(&mut runs, &[3, 6], &mut out);
+/// assert_eq!(out, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+/// ```
+pub fn merge_runs<T: Ord>(slice: &mut [T], run_boundaries: &[usize], out: &mut [T]) {
+    Impl::merge_runs(slice, run_boundaries, out, Ord::cmp)
+}
+
+/// 사용자 정의 comparator로 정렬된 run들을 병합합니다.
+///
+/// [`merge_runs`]와 동일한 기능을 하지만 `Ord::cmp` 대신 `compare` 함수를 인자로 받습니다.
+/// 각 run은 `compare` 기준으로 정렬되어 있어야 합니다.
+pub fn merge_runs_by<T, F>(slice: &mut [T], run_boundaries: &[usize], out: &mut [T], compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    Impl::merge_runs(slice, run_boundaries, out, compare)
+}
+
+/// key extraction 함수로 정렬된 run들을 병합합니다.
+///
+/// [`merge_runs`]와 동일한 기능을 하지만 원소 비교 시 `key` 함수로 추출한 값을 사용합니다.
+/// 각 run은 `key` 기준으로 정렬되어 있어야 합니다.
+pub fn merge_runs_by_key<T, K, F>(slice: &mut [T], run_boundaries: &[usize], out: &mut [T], key: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    Impl::merge_runs(slice, run_boundaries, out, key2compare(key))
+}
+
 #[cfg(test)]
 mod unit_test {
     use super::*;
+    use crate::test_util::pseudo_random;
     use std::cmp::Ordering;
 
     // Helper function for custom comparison (reverse order for testing)
@@ -550,6 +1161,64 @@ mod unit_test {
         assert!(!is_heap_by_key(&invalid_people, |p| p.age));
     }
 
+    #[test]
+    fn test_is_heap_by_key_cached() {
+        let mut calls = 0;
+        let arr = vec![1, 3, 2, 7, 5, 4];
+        assert!(is_heap_by_key_cached(&arr, |x| {
+            calls += 1;
+            *x
+        }));
+        // key is computed exactly once for each element
+        assert_eq!(calls, arr.len());
+
+        assert!(!is_heap_by_key_cached(&[3, 1, 2], |x| *x));
+        assert!(is_heap_by_key_cached(&Vec::<i32>::new(), |x| *x));
+    }
+
+    #[test]
+    fn test_vec_heap_push_pop() {
+        let mut heap: Vec<i32> = Vec::new();
+        let mut reference: Vec<i32> = Vec::new();
+        for i in 0..200 {
+            let x = (i * 7919) % 101;
+            vec_heap_push(&mut heap, x);
+            reference.push(x);
+            assert!(is_heap(&heap));
+
+            if i % 3 == 2 {
+                // reference is kept in the pop order
+                reference.sort_by(|a, b| b.cmp(a));
+                assert_eq!(vec_heap_pop(&mut heap), reference.pop());
+                assert!(is_heap(&heap));
+            }
+        }
+        assert_eq!(heap.len(), reference.len());
+        reference.sort_by(|a, b| b.cmp(a));
+        while let Some(x) = vec_heap_pop(&mut heap) {
+            assert_eq!(Some(x), reference.pop());
+        }
+        assert!(reference.is_empty());
+        assert_eq!(vec_heap_pop(&mut heap), None);
+    }
+
+    #[test]
+    fn test_vec_heap_push_pop_by_key() {
+        let mut heap: Vec<(u32, &str)> = Vec::new();
+        for item in [(3, "c"), (1, "a"), (2, "b")] {
+            vec_heap_push_by_key(&mut heap, item, |x| x.0);
+        }
+        assert!(is_heap_by_key(&heap, |x| x.0));
+        let first = vec_heap_pop_by_key(&mut heap, |x| x.0).unwrap();
+        assert_eq!(first.0, 1);
+
+        let mut heap: Vec<i32> = Vec::new();
+        for x in [3, 1, 2] {
+            vec_heap_push_by(&mut heap, x, reverse_compare);
+        }
+        assert_eq!(vec_heap_pop_by(&mut heap, reverse_compare), Some(3));
+    }
+
     #[test]
     fn test_heapify() {
         let mut arr = vec![5, 3, 8, 1, 9, 2, 7];
@@ -753,6 +1422,21 @@ mod unit_test {
         assert_eq!(single, vec![42]);
     }
 
+    #[test]
+    fn test_heap_sort_asc() {
+        let mut arr = vec![3, 1, 4, 2, 5, 2];
+        heap_sort_asc(&mut arr);
+        assert_eq!(arr, vec![1, 2, 2, 3, 4, 5]);
+
+        let mut desc = arr.clone();
+        heap_reverse_sort(&mut desc);
+        assert_eq!(desc, vec![5, 4, 3, 2, 2, 1]);
+
+        let mut empty: Vec<i32> = vec![];
+        heap_sort_asc(&mut empty);
+        assert!(empty.is_empty());
+    }
+
     #[test]
     fn test_heap_reverse_sort_by() {
         let mut arr = vec![3, 1, 4, 2, 5];
@@ -811,6 +1495,40 @@ mod unit_test {
         assert!(is_heap(&valid_heap));
     }
 
+    #[test]
+    fn test_adjust_heap_tracked() {
+        // sift up: 0 at index 4 goes to the root through index 1
+        let mut heap_up = vec![1, 2, 3, 4, 0];
+        assert_eq!(adjust_heap_tracked(&mut heap_up, 4), 0);
+        assert_eq!(heap_up[0], 0);
+        assert!(is_heap(&heap_up));
+
+        // sift up: stops below the root
+        let mut heap_mid = vec![1, 5, 3, 6, 7, 8, 9, 10, 2];
+        assert_eq!(adjust_heap_tracked(&mut heap_mid, 8), 1);
+        assert_eq!(heap_mid[1], 2);
+        assert!(is_heap(&heap_mid));
+
+        // sift down: 9 at the root goes to a leaf through the smaller children
+        let mut heap_down = vec![9, 1, 2, 3, 4, 5, 6];
+        assert_eq!(adjust_heap_tracked(&mut heap_down, 0), 3);
+        assert_eq!(heap_down[3], 9);
+        assert!(is_heap(&heap_down));
+
+        // no move
+        let mut valid_heap = vec![1, 2, 3, 4, 5];
+        assert_eq!(adjust_heap_tracked(&mut valid_heap, 2), 2);
+        assert_eq!(valid_heap, [1, 2, 3, 4, 5]);
+
+        // by, by_key
+        let mut heap = vec![5, 4, 3, 2, 1, 0, 9];
+        assert_eq!(adjust_heap_tracked_by(&mut heap, 6, reverse_compare), 0);
+        assert!(is_heap_by(&heap, reverse_compare));
+        let mut heap = vec![(1, 'a'), (2, 'b'), (3, 'c'), (0, 'd')];
+        assert_eq!(adjust_heap_tracked_by_key(&mut heap, 3, |x| x.0), 0);
+        assert_eq!(heap[0], (0, 'd'));
+    }
+
     #[test]
     fn test_adjust_heap_by() {
         let mut arr = vec![5, 3, 4, 1, 2]; // Create a valid max heap with reverse comparator
@@ -853,6 +1571,142 @@ mod unit_test {
         assert!(is_heap_by_key(&people, |p| p.age));
     }
 
+    #[test]
+    fn test_adjust_heap_checked() {
+        // only index 4 is broken
+        let mut arr = vec![2, 3, 4, 5, 1];
+        assert!(adjust_heap_checked(&mut arr, 4));
+        assert!(is_heap(&arr));
+
+        // adjust_heap silently leaves a non-heap when two positions are broken
+        let mut arr = vec![2, 3, 0, 5, 1];
+        adjust_heap(&mut arr, 4);
+        assert!(!is_heap(&arr));
+    }
+
+    #[test]
+    #[should_panic(expected = "heap property is broken at positions other than idx")]
+    fn test_adjust_heap_checked_two_broken() {
+        let mut arr = vec![2, 3, 0, 5, 1];
+        adjust_heap_checked(&mut arr, 4);
+    }
+
+    #[test]
+    fn test_heapify_by_key_cached() {
+        let source: Vec<(u32, usize)> =
+            (0..1000).map(|i| (((i * 7919) % 1000) as u32, i)).collect();
+
+        let mut cached = source.clone();
+        let mut calls = 0;
+        heapify_by_key_cached(&mut cached, |p| {
+            calls += 1;
+            p.0
+        });
+        assert_eq!(calls, cached.len());
+        assert!(is_heap_by_key(&cached, |p| p.0));
+
+        // 같은 swap을 적용하므로 heapify_by_key와 같은 결과
+        let mut uncached = source;
+        let mut uncached_calls = 0;
+        heapify_by_key(&mut uncached, |p| {
+            uncached_calls += 1;
+            p.0
+        });
+        assert_eq!(cached, uncached);
+        assert!(uncached_calls > 2 * calls);
+    }
+
+    #[test]
+    fn test_merge_into_heap() {
+        let mut a: Vec<u32> = (0..100).map(|i| (i * 37) % 101).collect();
+        let mut b: Vec<u32> = (0..57).map(|i| (i * 13) % 61 + 50).collect();
+        heapify(&mut a);
+        heapify(&mut b);
+
+        let mut expected: Vec<u32> = a.iter().chain(b.iter()).copied().collect();
+        expected.sort();
+
+        // 인접한 두 heap
+        let mut merged = a.clone();
+        merged.extend_from_slice(&b);
+        merge_into_heap(&mut merged);
+        assert!(is_heap(&merged));
+        let mut sorted = merged.clone();
+        sorted.sort();
+        assert_eq!(sorted, expected);
+
+        // 순서를 바꾸어 이어붙인 경우와 한쪽이 빈 경우
+        let mut merged = b.clone();
+        merged.extend_from_slice(&a);
+        merge_into_heap(&mut merged);
+        assert!(is_heap(&merged));
+
+        let mut merged = a.clone();
+        merge_into_heap(&mut merged[..]);
+        assert_eq!(merged, a);
+
+        // comparator, key 버전
+        let mut merged: Vec<(u32, u32)> = a.iter().chain(b.iter()).map(|&x| (x, x % 7)).collect();
+        merge_into_heap_by_key(&mut merged, |p| p.1);
+        assert!(is_heap_by_key(&merged, |p| p.1));
+        merge_into_heap_by(&mut merged, |x, y| y.0.cmp(&x.0));
+        assert!(is_heap_by(&merged, |x, y| y.0.cmp(&x.0)));
+    }
+
+    #[test]
+    fn test_repair_heap() {
+        let mut arr: Vec<u32> = (0..300).map(|i| (i * 7919) % 257).collect();
+        heapify(&mut arr);
+        let valid = arr.clone();
+        assert!(!repair_heap(&mut arr));
+        assert_eq!(arr, valid); // valid heap은 변경하지 않음
+
+        // 여러 위치를 임의로 수정
+        for (i, idx) in [0, 17, 150, 299].into_iter().enumerate() {
+            arr[idx] = (i as u32 * 97) % 300;
+        }
+        assert!(!is_heap(&arr));
+        assert!(repair_heap(&mut arr));
+        assert!(is_heap(&arr));
+        assert!(!repair_heap(&mut arr));
+
+        let mut empty: [u32; 0] = [];
+        assert!(!repair_heap(&mut empty));
+
+        // comparator, key 버전
+        let mut words = vec!["a", "bbbb", "cc", "ddd"];
+        let was_heap = is_heap_by_key(&words, |w| w.len());
+        assert_eq!(repair_heap_by_key(&mut words, |w| w.len()), !was_heap);
+        assert!(is_heap_by_key(&words, |w| w.len()));
+        assert!(!repair_heap_by(&mut words, |a, b| a.len().cmp(&b.len())));
+    }
+
+    #[test]
+    fn test_heap_pop_value() {
+        let mut arr: Vec<u32> = (0..200).map(|i| (i * 7919) % 101).collect();
+        let mut expected = arr.clone();
+        expected.sort();
+        heapify(&mut arr);
+
+        let mut popped = Vec::new();
+        let mut heap = arr.as_mut_slice();
+        while let Some((value, rest)) = heap_pop_value(heap) {
+            assert!(is_heap(rest));
+            popped.push(value);
+            heap = rest;
+        }
+        assert_eq!(popped, expected);
+        assert!(heap_pop_value::<u32>(&mut []).is_none());
+
+        // key 버전, 원본은 slice의 끝에 남음
+        let mut words = vec!["ccc", "a", "bbbb", "dd"];
+        heapify_by_key(&mut words, |w| w.len());
+        let (value, rest) = heap_pop_value_by_key(&mut words, |w| w.len()).unwrap();
+        assert_eq!(rest.len(), 3);
+        assert!(is_heap_by_key(rest, |w| w.len()));
+        assert_eq!(words[3], value);
+    }
+
     #[test]
     fn test_edge_cases() {
         // Test with duplicate elements
@@ -886,4 +1740,133 @@ mod unit_test {
             }
         }
     }
+
+    #[test]
+    fn test_merge_runs() {
+        // 4개의 정렬된 run, 서로 다른 run 사이에 같은 key가 있음
+        let mut runs: Vec<(u32, usize)> = vec![
+            (1, 0),
+            (4, 0),
+            (4, 0),
+            (9, 0),
+            (0, 1),
+            (4, 1),
+            (8, 1),
+            (2, 2),
+            (4, 2),
+            (9, 2),
+            (9, 2),
+            (10, 2),
+            (1, 3),
+            (3, 3),
+        ];
+        let mut expected = runs.clone();
+        expected.sort_by_key(|x| x.0); // stable, run 순서가 유지됨
+
+        let mut out = vec![(0, 0); runs.len()];
+        merge_runs_by_key(&mut runs, &[4, 7, 12], &mut out, |x| x.0);
+        assert_eq!(out, expected);
+
+        // 양 끝을 포함한 경계와 빈 run
+        let mut runs = vec![3, 5, 1, 2, 6, 4];
+        let mut out = vec![0; runs.len()];
+        merge_runs(&mut runs, &[0, 2, 2, 5, 6], &mut out);
+        assert_eq!(out, [1, 2, 3, 4, 5, 6]);
+
+        // run 하나, 빈 slice
+        let mut runs = vec![1, 2, 3];
+        let mut out = vec![0; 3];
+        merge_runs(&mut runs, &[], &mut out);
+        assert_eq!(out, [1, 2, 3]);
+        merge_runs::<i32>(&mut [], &[], &mut []);
+
+        // 내림차순 run
+        let mut runs = vec![9, 5, 1, 8, 2];
+        let mut out = vec![0; runs.len()];
+        merge_runs_by(&mut runs, &[3], &mut out, reverse_compare);
+        assert_eq!(out, [9, 8, 5, 2, 1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "merge_runs: run_boundaries must be sorted and within the slice")]
+    fn test_merge_runs_invalid_boundaries() {
+        let mut runs = vec![1, 2, 3, 4];
+        let mut out = vec![0; 4];
+        merge_runs(&mut runs, &[3, 1], &mut out);
+    }
+
+    #[test]
+    #[should_panic(expected = "merge_runs: out must have the same length as slice")]
+    fn test_merge_runs_length_mismatch() {
+        let mut runs = vec![1, 2, 3, 4];
+        let mut out = vec![0; 3];
+        merge_runs(&mut runs, &[2], &mut out);
+    }
+
+    #[test]
+    fn test_heapsort_then_rebuild() {
+        // 임의의 데이터
+        let mut arr: Vec<u32> = pseudo_random(1000, 42)
+            .into_iter()
+            .map(|x| x as u32 % 500)
+            .collect();
+        let mut expected = arr.clone();
+        expected.sort();
+        assert!(heapsort_then_rebuild(&mut arr));
+        assert!(is_heap(&arr));
+        arr.sort();
+        assert_eq!(arr, expected);
+
+        // 사용자 정의 comparator, key
+        let mut arr: Vec<i32> = (0..100).map(|x| (x * 37) % 101 - 50).collect();
+        assert!(heapsort_then_rebuild_by(&mut arr, reverse_compare));
+        assert!(is_heap_by(&arr, reverse_compare));
+        assert!(heapsort_then_rebuild_by_key(&mut arr, |x: &i32| x.abs()));
+        assert!(is_heap_by_key(&arr, |x: &i32| x.abs()));
+
+        // 일관되지 않은 comparator
+        assert!(!heapsort_then_rebuild_by(&mut arr, |_, _| Ordering::Less));
+        assert!(!heapsort_then_rebuild_by(&mut arr, |_, _| {
+            Ordering::Greater
+        }));
+
+        let mut empty: Vec<i32> = vec![];
+        assert!(heapsort_then_rebuild(&mut empty));
+    }
+
+    #[test]
+    fn test_heapify_by_insertion() {
+        // 임의의 데이터, Floyd's heapify와 결과 배치는 다를 수 있지만 둘 다 valid heap
+        let arr: Vec<u32> = pseudo_random(1000, 42)
+            .into_iter()
+            .map(|x| x as u32 % 500)
+            .collect();
+        let mut by_insertion = arr.clone();
+        let mut by_floyd = arr.clone();
+        heapify_by_insertion(&mut by_insertion);
+        heapify(&mut by_floyd);
+        assert!(is_heap(&by_insertion));
+        assert!(is_heap(&by_floyd));
+        assert_eq!(by_insertion[0], by_floyd[0]);
+        assert_eq!(Some(&by_insertion[0]), arr.iter().min());
+
+        // 원소는 재배열될 뿐 그대로 유지
+        let mut expected = arr.clone();
+        expected.sort();
+        by_insertion.sort();
+        assert_eq!(by_insertion, expected);
+
+        // 사용자 정의 comparator, key
+        let mut arr: Vec<i32> = (0..100).map(|x| (x * 37) % 101 - 50).collect();
+        heapify_by_insertion_by(&mut arr, reverse_compare);
+        assert!(is_heap_by(&arr, reverse_compare));
+        heapify_by_insertion_by_key(&mut arr, |x: &i32| x.abs());
+        assert!(is_heap_by_key(&arr, |x: &i32| x.abs()));
+
+        let mut empty: Vec<i32> = vec![];
+        heapify_by_insertion(&mut empty);
+        let mut single = vec![1];
+        heapify_by_insertion(&mut single);
+        assert_eq!(single, vec![1]);
+    }
 }
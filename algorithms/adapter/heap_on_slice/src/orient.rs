@@ -0,0 +1,128 @@
+//! # Heap orientation
+//!
+//! min heap과 max heap의 연산을 하나의 trait으로 추상화합니다.
+//!
+//! [`Heap`] trait은 zero-sized marker 타입인 [`MinOrient`]와 [`MaxOrient`]가 구현하며,
+//! 각각 [`min_heap`](crate::min_heap)과 [`max_heap`](crate::max_heap)의 함수를 호출합니다.
+//! 따라서 k-way merge처럼 heap의 방향과 무관한 알고리즘을 `O: Heap<T>`로 parameterize하여
+//! 한 번만 작성할 수 있습니다.
+//!
+//! ```rust
+//! use heap_on_slice::orient::{Heap, MaxOrient, MinOrient};
+//!
+//! fn root<O: Heap<i32>>(arr: &mut [i32]) -> Option<i32> {
+//!     O::heapify(arr);
+//!     O::peek(arr).copied()
+//! }
+//!
+//! assert_eq!(root::<MinOrient>(&mut [3, 1, 4]), Some(1));
+//! assert_eq!(root::<MaxOrient>(&mut [3, 1, 4]), Some(4));
+//! ```
+
+use crate::{max_heap, min_heap};
+
+/// slice heap 연산을 heap의 방향에 대해 추상화한 trait입니다.
+///
+/// 모든 method는 associated function이며, 구현 타입은 방향을 나타내는 marker로만 사용됩니다.
+/// root는 방향에 따라 가장 먼저 pop되는 원소입니다.
+pub trait Heap<T> {
+    /// 임의의 slice를 valid heap으로 변환합니다.
+    fn heapify(arr: &mut [T]);
+
+    /// root를 slice의 마지막으로 옮기고, 나머지 heap을 반환합니다.
+    /// 빈 slice에 대해서는 `None`을 반환합니다.
+    fn pop(arr: &mut [T]) -> Option<&mut [T]>;
+
+    /// `x`를 추가한 후 root를 제거하여 반환합니다.
+    fn pushpop(arr: &mut [T], x: T) -> T;
+
+    /// root의 참조를 반환합니다. 빈 slice에 대해서는 `None`을 반환합니다.
+    fn peek(arr: &[T]) -> Option<&T> {
+        arr.first()
+    }
+}
+
+/// 가장 작은 원소가 root인 min heap 방향입니다.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MinOrient;
+
+/// 가장 큰 원소가 root인 max heap 방향입니다.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MaxOrient;
+
+impl<T: Ord> Heap<T> for MinOrient {
+    fn heapify(arr: &mut [T]) {
+        min_heap::heapify(arr);
+    }
+
+    fn pop(arr: &mut [T]) -> Option<&mut [T]> {
+        min_heap::heap_pop(arr)
+    }
+
+    fn pushpop(arr: &mut [T], x: T) -> T {
+        min_heap::heap_pushpop(arr, x)
+    }
+}
+
+impl<T: Ord> Heap<T> for MaxOrient {
+    fn heapify(arr: &mut [T]) {
+        max_heap::heapify(arr);
+    }
+
+    fn pop(arr: &mut [T]) -> Option<&mut [T]> {
+        max_heap::heap_pop(arr)
+    }
+
+    fn pushpop(arr: &mut [T], x: T) -> T {
+        max_heap::heap_pushpop(arr, x)
+    }
+}
+
+#[cfg(test)]
+mod unit_test {
+    use super::*;
+
+    /// heap의 모든 원소를 pop 순서대로 꺼내 반환합니다.
+    fn drain_sorted<O: Heap<i32>>(arr: &mut [i32]) -> Vec<i32> {
+        O::heapify(arr);
+        let mut drained = Vec::with_capacity(arr.len());
+        let mut heap = &mut *arr;
+        while let Some(&root) = O::peek(heap) {
+            drained.push(root);
+            heap = O::pop(heap).unwrap();
+        }
+        drained
+    }
+
+    #[test]
+    fn test_drain_sorted_both_orientations() {
+        let source = vec![5, 3, 8, 1, 9, 2, 7, 3];
+
+        let mut arr = source.clone();
+        assert_eq!(
+            drain_sorted::<MinOrient>(&mut arr),
+            vec![1, 2, 3, 3, 5, 7, 8, 9]
+        );
+
+        let mut arr = source.clone();
+        assert_eq!(
+            drain_sorted::<MaxOrient>(&mut arr),
+            vec![9, 8, 7, 5, 3, 3, 2, 1]
+        );
+
+        assert!(drain_sorted::<MinOrient>(&mut []).is_empty());
+    }
+
+    #[test]
+    fn test_pushpop_both_orientations() {
+        let mut arr = vec![4, 6, 2];
+        MinOrient::heapify(&mut arr);
+        assert_eq!(<MinOrient as Heap<i32>>::pushpop(&mut arr, 3), 2);
+        assert_eq!(MinOrient::peek(&arr), Some(&3));
+
+        let mut arr = vec![4, 6, 2];
+        MaxOrient::heapify(&mut arr);
+        assert_eq!(<MaxOrient as Heap<i32>>::pushpop(&mut arr, 5), 6);
+        assert_eq!(MaxOrient::peek(&arr), Some(&5));
+    }
+}
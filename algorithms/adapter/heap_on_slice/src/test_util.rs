@@ -0,0 +1,15 @@
+//! 여러 module의 unit test에서 공유하는 helper.
+
+/// 간단한 LCG로 재현 가능한 난수 `len`개를 생성합니다.
+/// 상위 24bit를 반환하므로, 원하는 범위로 나머지를 취해 사용합니다.
+pub(crate) fn pseudo_random(len: usize, seed: u64) -> Vec<u64> {
+    let mut state = seed;
+    (0..len)
+        .map(|_| {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            state >> 40
+        })
+        .collect()
+}
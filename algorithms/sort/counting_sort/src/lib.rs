@@ -8,8 +8,20 @@
 //! - `TryCountingSort`: i8, i16, i32, i64, isize, u32, u64와 같이, TryInto<usize>를 구현하는 타입에 대한 정렬을 지원합니다.
 //! - `CountingSortByKey`: 키를 기준으로 정렬하는 trait. 키 함수를 인자로 받아 정렬을 수행합니다.
 //! - `CountingSortByKeyCached`: `CountingSortByKey`와 동일하지만, 각 요소에 대해 키를 한번씩 계산합니다. 키 값을 계산해 캐싱한 후 정렬하는 데 사용됩니다.
+//! - `CountingSortByKeys`: 여러 개의 키를 사전식(lexicographic)으로 비교하여 정렬하는 trait. 가장 덜 중요한 키부터 stable counting sort를 반복합니다.
 //!
-//! 네 trait은 모두 `&mut [T]`에 autoimplement하는 것을 목적으로 정의되었으며, 사용자가 타 타입에 별도로 implement하는 것을 상정하지 않습니다.
+//! 다섯 trait은 모두 `&mut [T]`에 autoimplement하는 것을 목적으로 정의되었으며, 사용자가 타 타입에 별도로 implement하는 것을 상정하지 않습니다.
+//!
+//! 또한 정렬 없이 키 값의 분포만 필요한 경우를 위해 `key_histogram` 함수를,
+//! 각 요소의 정렬 후 위치(순위)만 필요한 경우를 위해 `counting_sort_ranks_by_key` 함수를,
+//! slice가 아닌 반복자를 정렬하여 `Vec`으로 받는 경우를 위해 `counting_sort_collect_by_key` 함수를,
+//! 키의 내림차순으로 stable하게 정렬하는 경우를 위해 `counting_sort_by_key_desc` 함수를,
+//! 정렬과 함께 병렬 배열을 재배치하기 위한 순열이 필요한 경우를 위해 `counting_sort_by_key_with_perm` 함수를,
+//! `Copy` 타입을 순열 없이 출력 버퍼에 직접 scatter하여 정렬하는 경우를 위해 `counting_sort_direct` 함수를,
+//! `counting_sort_direct`의 scatter를 여러 thread에서 수행하는 `counting_sort_direct_parallel` 함수(`parallel` feature)를,
+//! `Copy` 타입을 출력 버퍼 없이 cycle을 따라 제자리에서 정렬하는 경우를 위해 `counting_sort_by_key_inplace` 함수를,
+//! 키가 `u16`인 경우 고정 크기 histogram을 사용하는 `counting_sort_u16`, `counting_sort_by_key_u16` 함수를,
+//! 키의 범위가 너무 넓은 경우 histogram 대신 merge sort로 정렬하는 `counting_sort_or_fallback_by_key` 함수를 제공합니다.
 //!
 //! # Clone trait
 //! `CountingSort`와 `TryCountingSort`는 clone이 cheap한 Numeric 타입을 상정하고 디자인 되어 있습니다.
@@ -162,6 +174,8 @@ pub trait TryCountingSort {
 /// 이 trait은 key_fn을 인자로 받아, key_fn을 통해 계산된 키를 기준으로 정렬합니다.
 /// key_fn은 Fn(&T) -> usize 타입의 함수를 인자로 받습니다.
 /// 이 trait은 각 요소에 대해 키를 두번씩 계산합니다.
+/// 두 번째 계산에서는 첫 번째 계산에서 만든 길이 `max_key + 1`의 histogram을 그대로 사용하므로 추가적인 resize가 없습니다.
+/// key_fn은 두 번의 계산에서 같은 키를 반환해야 합니다.
 /// 따라서 키를 계산하는 비용이 키를 캐싱하는 비용보다 적을 때 사용할 수 있습니다.
 ///
 /// # Panics
@@ -188,42 +202,712 @@ pub trait CountingSortByKeyCached<T> {
         F: FnMut(&T) -> usize;
 }
 
+/// CountingSortByKeys trait은 Sized 타입 T에 대해서 &mut [T]에 autoimplement됩니다.
+/// 이 trait은 여러 개의 key_fn을 인자로 받아, 앞의 키가 더 중요한 사전식(lexicographic) 순서로 정렬합니다.
+/// 즉, `(k1(x), k2(x))` 튜플을 기준으로 정렬한 것과 같은 결과를 가집니다.
+///
+/// 내부적으로는 가장 덜 중요한 키(마지막 키)부터 `counting_sort_by_key`를 수행하는 LSD radix sort입니다.
+/// 각 pass가 stable하므로, 모든 키가 같은 원소들은 원래 순서를 유지합니다.
+/// 더 많은 키가 필요한 경우, 같은 방식으로 마지막 키부터 `counting_sort_by_key`를 반복하거나
+/// radix_sort crate의 scheme을 사용할 수 있습니다.
+///
+/// # Panics
+/// - 어떤 key_fn이 반환하는 키 값이 `usize::MAX`인 경우.
+/// - 특정 키 값의 개수 또는 누적 개수가 `usize::MAX`를 초과하는 경우.
+pub trait CountingSortByKeys<T> {
+    fn counting_sort_by_keys_2<F1, F2>(self, k1: F1, k2: F2)
+    where
+        F1: FnMut(&T) -> usize,
+        F2: FnMut(&T) -> usize;
+
+    fn counting_sort_by_keys_3<F1, F2, F3>(self, k1: F1, k2: F2, k3: F3)
+    where
+        F1: FnMut(&T) -> usize,
+        F2: FnMut(&T) -> usize,
+        F3: FnMut(&T) -> usize;
+}
+
 /// 에러 타입을 사용하지 않는 연산의 오류 채널을 위한 빈 열거형입니다.
 /// Result<T, Never>는 T와 동일하며, 이는
 #[derive(Debug, Clone, Copy)]
 enum Never {} // 이 타입의 값은 생성될 수 없습니다.
 
-/// 주어진 아이템 반복자로부터 키를 추출하여 카운터 배열을 생성하고,
-/// 각 키의 누적 등장 횟수를 계산하여 반환합니다.
+/// slice의 각 요소에 대해 key_fn을 적용하여 키 값의 분포(히스토그램)를 반환합니다.
+///
+/// 반환값의 인덱스는 키 값이고, 해당 인덱스의 값은 그 키를 가진 요소의 개수입니다.
+/// 반환값의 길이는 `최대 키 + 1`이며, 등장하지 않은 키의 개수는 0입니다. 빈 slice의 경우 빈 `Vec`을 반환합니다.
+/// counting sort 내부에서 사용하는 카운터와 동일한 방식으로 계산되며, 누적합만 수행하지 않습니다.
+///
+/// # Panics
+/// - key_fn이 반환하는 키 값이 `usize::MAX`인 경우.
+/// - 특정 키 값의 개수가 `usize::MAX`를 초과하는 경우.
+///
+/// # Examples
+/// ```
+/// use counting_sort::key_histogram;
+///
+/// let words = ["a", "bb", "cc", "", "ddd"];
+/// assert_eq!(key_histogram(&words, |w| w.len()), vec![1, 1, 2, 1]);
+/// ```
+pub fn key_histogram<T, F>(slice: &[T], mut key_fn: F) -> Vec<usize>
+where
+    F: FnMut(&T) -> usize,
+{
+    let it = slice
+        .iter()
+        .map(&mut key_fn)
+        .map(Result::<usize, Never>::Ok);
+    get_counter(it).unwrap()
+}
+
+/// 키를 기준으로 slice를 내림차순으로 stable counting sort합니다.
+///
+/// 오름차순 정렬 결과를 뒤집으면 같은 키를 가진 요소들의 순서도 뒤집히므로 stable하지 않습니다.
+/// 대신 큰 키부터 누적한 카운터를 사용하여, 큰 키가 앞에 오면서 같은 키를 가진 요소들은 원래의 순서를 유지합니다.
+/// key_fn은 각 요소에 대해 두 번씩 호출됩니다.
+///
+/// # Panics
+/// - key_fn이 반환하는 키 값이 `usize::MAX`인 경우.
+/// - 특정 키 값의 개수 또는 누적 개수가 `usize::MAX`를 초과하는 경우.
+///
+/// # Examples
+/// ```
+/// use counting_sort::counting_sort_by_key_desc;
+///
+/// let mut arr = [(1, 'a'), (3, 'b'), (1, 'c'), (2, 'd'), (3, 'e')];
+/// counting_sort_by_key_desc(&mut arr, |x| x.0);
+/// assert_eq!(arr, [(3, 'b'), (3, 'e'), (2, 'd'), (1, 'a'), (1, 'c')]);
+/// ```
+pub fn counting_sort_by_key_desc<T, F>(slice: &mut [T], mut key_fn: F)
+where
+    F: FnMut(&T) -> usize,
+{
+    if slice.len() <= 1 {
+        return;
+    }
+
+    let mut counter = {
+        let it = slice
+            .iter()
+            .map(&mut key_fn)
+            .map(Result::<usize, Never>::Ok);
+        get_counter(it).unwrap()
+    };
+    // 큰 키부터 누적하여, counter[k]는 키가 k 이상인 요소의 개수, 즉 키 k 구간의 끝 위치
+    for i in (1..counter.len()).rev() {
+        counter[i - 1] = counter[i - 1].checked_add(counter[i]).unwrap();
+    }
+
+    let mut perm = {
+        let it = slice.iter().map(key_fn).map(Result::<usize, Never>::Ok);
+        accumulated_counter2permutation(&mut counter, it, slice.len()).unwrap()
+    };
+
+    apply_permutation(slice, &mut perm);
+}
+
+/// slice를 변경하지 않고, 각 요소가 stable counting sort 후 위치하게 될 순위(rank)를 반환합니다.
+///
+/// 반환값 `rank`에 대해 `rank[i]`는 `slice[i]`가 정렬된 결과에서 위치하는 인덱스입니다.
+/// 즉, 정렬 순서의 역순열(inverse permutation)이며, 같은 키를 가진 요소들은 원래의 순서대로 순위가 매겨집니다.
+/// key_fn은 각 요소에 대해 두 번씩 호출됩니다.
+///
+/// # Panics
+/// - key_fn이 반환하는 키 값이 `usize::MAX`인 경우.
+/// - 특정 키 값의 개수 또는 누적 개수가 `usize::MAX`를 초과하는 경우.
+///
+/// # Examples
+/// ```
+/// use counting_sort::counting_sort_ranks_by_key;
+///
+/// let arr = [3usize, 1, 2, 1];
+/// assert_eq!(counting_sort_ranks_by_key(&arr, |x| *x), vec![3, 0, 2, 1]);
+/// ```
+pub fn counting_sort_ranks_by_key<T, F>(slice: &[T], mut key_fn: F) -> Vec<usize>
+where
+    F: FnMut(&T) -> usize,
+{
+    let mut counter = {
+        let it = slice
+            .iter()
+            .map(&mut key_fn)
+            .map(Result::<usize, Never>::Ok);
+        get_accumulated_counter(it).unwrap()
+    };
+    let it = slice
+        .iter()
+        .map(&mut key_fn)
+        .map(Result::<usize, Never>::Ok);
+    accumulated_counter2permutation(&mut counter, it, slice.len()).unwrap()
+}
+
+/// `u8`을 반환하는 키 함수를 기준으로 slice를 stable counting sort합니다.
+///
+/// 키가 256개 이하이므로 histogram은 heap 할당이나 resize 없이 stack의 `[usize; 256]` 배열 하나로 충분합니다.
+/// 누적 개수도 slice의 길이를 넘지 않으므로 overflow 확인이 필요하지 않습니다.
+/// byte 단위로 나눈 데이터나 radix sort의 한 pass처럼 키가 byte인 경우에 사용합니다.
+///
+/// 키는 `Vec<u8>`에 한 번씩만 계산하여 저장하며, 순열을 만든 후 swap으로 재배치하므로 `T`는 Clone이 필요하지 않습니다.
+///
+/// # Examples
+/// ```
+/// use counting_sort::counting_sort_u8_by_key;
+///
+/// let mut arr = [(u8::MAX, 'a'), (7, 'b'), (0, 'c'), (7, 'd')];
+/// counting_sort_u8_by_key(&mut arr, |x| x.0);
+/// assert_eq!(arr, [(0, 'c'), (7, 'b'), (7, 'd'), (u8::MAX, 'a')]);
+/// ```
+pub fn counting_sort_u8_by_key<T, F>(slice: &mut [T], key_fn: F)
+where
+    F: FnMut(&T) -> u8,
+{
+    if slice.len() <= 1 {
+        return;
+    }
+
+    let keys: Vec<u8> = slice.iter().map(key_fn).collect();
+    let mut counter = [0usize; 256];
+    for &key in keys.iter() {
+        counter[key as usize] += 1;
+    }
+    for i in 1..256 {
+        counter[i] += counter[i - 1];
+    }
+
+    let mut perm = {
+        let it = keys
+            .iter()
+            .map(|&key| Result::<usize, Never>::Ok(key as usize));
+        accumulated_counter2permutation(&mut counter, it, slice.len()).unwrap()
+    };
+    apply_permutation(slice, &mut perm);
+}
+
+/// `u16` 키의 histogram 크기. 모든 `u16` 값을 담을 수 있습니다.
+const U16_KEYS: usize = u16::MAX as usize + 1;
+
+/// `u16` slice를 counting sort합니다.
+///
+/// `u16`의 키는 항상 65536개 이하이므로, histogram을 키의 최대값에 맞춰 늘려가는 대신
+/// 길이 65536의 histogram을 heap에 한 번만 할당합니다. 따라서 resize가 없으며 입력에 관계없이 일정하게 동작합니다.
+/// 정렬 대상이 키 자체이므로 순열을 만들지 않고, 각 키를 등장 횟수만큼 slice에 다시 씁니다.
+/// histogram 전체를 순회하므로 slice가 매우 짧은 경우에는 `CountingSort`가 더 빠를 수 있습니다.
+///
+/// # Examples
+/// ```
+/// use counting_sort::counting_sort_u16;
+///
+/// let mut arr = [u16::MAX, 3, 0, 3, 1];
+/// counting_sort_u16(&mut arr);
+/// assert_eq!(arr, [0, 1, 3, 3, u16::MAX]);
+/// ```
+pub fn counting_sort_u16(slice: &mut [u16]) {
+    if slice.len() <= 1 {
+        return;
+    }
+
+    let mut counter = vec![0usize; U16_KEYS];
+    for &x in slice.iter() {
+        counter[x as usize] += 1;
+    }
+
+    let mut pos = 0;
+    for (key, &count) in counter.iter().enumerate() {
+        slice[pos..pos + count].fill(key as u16);
+        pos += count;
+    }
+}
+
+/// `u16`을 반환하는 키 함수를 기준으로 slice를 stable counting sort합니다.
+///
+/// [`counting_sort_u16`]과 같이 길이 65536의 histogram을 한 번만 할당하여 resize를 하지 않습니다.
+/// 키는 `Vec<u16>`에 한 번씩만 계산하여 저장하며, 순열을 만든 후 swap으로 재배치하므로 `T`는 Clone이 필요하지 않습니다.
+///
+/// # Panics
+/// - 특정 키 값의 개수 또는 누적 개수가 `usize::MAX`를 초과하는 경우.
+///
+/// # Examples
+/// ```
+/// use counting_sort::counting_sort_by_key_u16;
+///
+/// let mut arr = [(u16::MAX, 'a'), (7, 'b'), (0, 'c'), (7, 'd')];
+/// counting_sort_by_key_u16(&mut arr, |x| x.0);
+/// assert_eq!(arr, [(0, 'c'), (7, 'b'), (7, 'd'), (u16::MAX, 'a')]);
+/// ```
+pub fn counting_sort_by_key_u16<T, F>(slice: &mut [T], key_fn: F)
+where
+    F: FnMut(&T) -> u16,
+{
+    if slice.len() <= 1 {
+        return;
+    }
+
+    let keys: Vec<u16> = slice.iter().map(key_fn).collect();
+    let mut counter = vec![0usize; U16_KEYS];
+    for &key in keys.iter() {
+        counter[key as usize] += 1;
+    }
+    for i in 1..U16_KEYS {
+        counter[i] = counter[i].checked_add(counter[i - 1]).unwrap();
+    }
+
+    let mut perm = {
+        let it = keys
+            .iter()
+            .map(|&key| Result::<usize, Never>::Ok(key as usize));
+        accumulated_counter2permutation(&mut counter, it, slice.len()).unwrap()
+    };
+    apply_permutation(slice, &mut perm);
+}
+
+/// 키의 범위가 `max_allowed_range` 이하인 경우 stable counting sort를, 초과하는 경우 stable merge sort를 수행합니다.
+///
+/// counting sort는 최대 키 크기의 histogram을 할당하므로, `0..1_000_000_000`처럼 드문드문 분포한 큰 키에 대해서는
+/// 요소 수와 관계없이 거대한 메모리를 할당하게 됩니다.
+/// 이 함수는 먼저 한 번의 순회로 키의 최소값 `min`과 최대값 `max`를 구하고,
+/// histogram의 크기가 될 `max - min + 1`이 `max_allowed_range` 이하인 경우에만 `key - min`을 키로 counting sort합니다.
+/// 그렇지 않은 경우 histogram을 할당하지 않고 `merge_sort_by`로 키를 비교하여 정렬합니다.
+/// 두 경우 모두 stable하므로, 어느 쪽으로 정렬되었는지에 관계없이 결과는 같습니다.
+///
+/// `max_allowed_range`는 허용할 histogram의 최대 길이(`usize` 개수)입니다.
+/// 요소 수의 상수배 정도로 두면 histogram의 크기가 입력에 비례하는 범위로 제한되며,
+/// 메모리 상한을 직접 정하고 싶다면 허용할 바이트 수를 `size_of::<usize>()`로 나눈 값을 사용합니다.
+/// key_fn은 각 요소에 대해 counting sort의 경우 세 번씩, merge sort의 경우 비교마다 호출됩니다.
+///
+/// # Panics
+/// - 특정 키 값의 개수 또는 누적 개수가 `usize::MAX`를 초과하는 경우.
+///
+/// # Examples
+/// ```
+/// use counting_sort::counting_sort_or_fallback_by_key;
+///
+/// // 범위가 작으므로 counting sort
+/// let mut dense = [(3, 'a'), (1, 'b'), (3, 'c'), (2, 'd')];
+/// counting_sort_or_fallback_by_key(&mut dense, |x| x.0, 1024);
+/// assert_eq!(dense, [(1, 'b'), (2, 'd'), (3, 'a'), (3, 'c')]);
+///
+/// // 범위가 크므로 histogram을 할당하지 않고 merge sort
+/// let mut sparse = [(999_999_999, 'a'), (7, 'b'), (999_999_999, 'c')];
+/// counting_sort_or_fallback_by_key(&mut sparse, |x| x.0, 1024);
+/// assert_eq!(sparse, [(7, 'b'), (999_999_999, 'a'), (999_999_999, 'c')]);
+/// ```
+pub fn counting_sort_or_fallback_by_key<T, F>(
+    slice: &mut [T],
+    mut key_fn: F,
+    max_allowed_range: usize,
+) where
+    F: FnMut(&T) -> usize,
+{
+    let Some((min, max)) = slice
+        .iter()
+        .map(&mut key_fn)
+        .fold(None, |acc, key| match acc {
+            None => Some((key, key)),
+            Some((min, max)) => Some((min.min(key), max.max(key))),
+        })
+    else {
+        return;
+    };
+
+    if max - min < max_allowed_range {
+        slice.counting_sort_by_key(|x| key_fn(x) - min);
+    } else {
+        merge_sort::merge_sort_by(slice, |a, b| key_fn(a).cmp(&key_fn(b)));
+    }
+}
+
+/// `Copy` 타입의 slice를 키를 기준으로 stable counting sort하되, 순열을 만들지 않고 출력 버퍼에 직접 scatter합니다.
+///
+/// `CountingSortByKey`는 키로부터 순열 배열을 만든 후 swap으로 재배치합니다.
+/// 이 함수는 누적 카운터를 만든 후, 뒤에서부터 각 요소를 누적 카운터가 가리키는 위치의 출력 버퍼에 바로 복사하고,
+/// 마지막에 출력 버퍼를 slice에 다시 복사합니다. 따라서 순열 배열과 swap을 위한 cycle 추적이 필요하지 않습니다.
+/// 뒤에서부터 scatter하므로 같은 키를 가진 요소들은 원래의 순서를 유지합니다.
+/// key_fn은 각 요소에 대해 두 번씩 호출됩니다.
+///
+/// # Panics
+/// - key_fn이 반환하는 키 값이 `usize::MAX`인 경우.
+/// - 특정 키 값의 개수 또는 누적 개수가 `usize::MAX`를 초과하는 경우.
+///
+/// # Examples
+/// ```
+/// use counting_sort::counting_sort_direct;
+///
+/// let mut arr = [(3u8, 'a'), (1, 'b'), (3, 'c'), (0, 'd')];
+/// counting_sort_direct(&mut arr, |x| x.0 as usize);
+/// assert_eq!(arr, [(0, 'd'), (1, 'b'), (3, 'a'), (3, 'c')]);
+/// ```
+pub fn counting_sort_direct<T, F>(slice: &mut [T], key_fn: F)
+where
+    T: Copy,
+    F: Fn(&T) -> usize,
+{
+    if slice.len() <= 1 {
+        return;
+    }
+
+    let mut counter = {
+        let it = slice.iter().map(&key_fn).map(Result::<usize, Never>::Ok);
+        get_accumulated_counter(it).unwrap()
+    };
+
+    let mut buffer = slice.to_vec();
+    for x in slice.iter().rev() {
+        let key = key_fn(x);
+        debug_assert!(
+            key < counter.len(),
+            "counting_sort_direct: key was not seen in the histogram pass"
+        );
+        counter[key] -= 1;
+        buffer[counter[key]] = *x;
+    }
+    slice.copy_from_slice(&buffer);
+}
+
+/// `Copy` 타입의 slice를 키를 기준으로 stable counting sort하되, 출력 버퍼 없이 순열의 cycle을 따라 제자리에서 재배치합니다.
+///
+/// [`counting_sort_direct`]는 slice 전체의 복사본을 출력 버퍼로 사용하므로 `n * size_of::<T>()` 바이트를 추가로 할당합니다.
+/// 이 함수는 순열 배열을 만든 후 제자리에서 재배치하므로,
+/// 요소의 크기와 관계없이 `n * size_of::<usize>()` 바이트만을 추가로 할당합니다.
+/// 따라서 `T`가 `usize`보다 큰 경우 peak memory가 줄어듭니다.
+///
+/// `CountingSortByKey`의 swap 기반 재배치는 cycle의 요소마다 swap, 즉 세 번의 이동을 합니다.
+/// 이 함수는 `T: Copy`를 이용해 cycle의 첫 요소를 복사해 들고, 목적지의 요소와 바꿔 들며 cycle을 한 바퀴 돌기 때문에
+/// 요소마다 두 번의 복사만 합니다.
+/// 대신 cycle을 따라 slice를 무작위로 접근하므로, 작은 `T`에서는 [`counting_sort_direct`]보다 느립니다.
+/// 요소가 크고 slice가 긴 경우에는 복사본의 할당과 두 번의 전체 복사가 더 비싸서, 이 함수가 더 빠를 수 있습니다.
+/// key_fn은 각 요소에 대해 두 번씩 호출됩니다.
+///
+/// # Panics
+/// - key_fn이 반환하는 키 값이 `usize::MAX`인 경우.
+/// - 특정 키 값의 개수 또는 누적 개수가 `usize::MAX`를 초과하는 경우.
+///
+/// # Examples
+/// ```
+/// use counting_sort::counting_sort_by_key_inplace;
+///
+/// let mut arr = [(3u8, [0u64; 8]), (1, [1; 8]), (3, [2; 8]), (0, [3; 8])];
+/// counting_sort_by_key_inplace(&mut arr, |x| x.0 as usize);
+/// assert_eq!(arr, [(0, [3; 8]), (1, [1; 8]), (3, [0; 8]), (3, [2; 8])]);
+/// ```
+pub fn counting_sort_by_key_inplace<T, F>(slice: &mut [T], mut key_fn: F)
+where
+    T: Copy,
+    F: FnMut(&T) -> usize,
+{
+    if slice.len() <= 1 {
+        return;
+    }
+
+    let mut counter = {
+        let it = slice
+            .iter()
+            .map(&mut key_fn)
+            .map(Result::<usize, Never>::Ok);
+        get_accumulated_counter(it).unwrap()
+    };
+
+    let mut perm = {
+        let it = slice
+            .iter()
+            .map(&mut key_fn)
+            .map(Result::<usize, Never>::Ok);
+        accumulated_counter2permutation(&mut counter, it, slice.len()).unwrap()
+    };
+
+    apply_permutation_cycles_copy(slice, &mut perm);
+}
+
+/// 병렬 scatter를 사용할 chunk 하나의 최소 길이. 이보다 짧은 chunk는 thread 생성 비용이 scatter보다 큽니다.
+#[cfg(feature = "parallel")]
+const PARALLEL_MIN_CHUNK: usize = 1 << 14;
+
+/// [`counting_sort_direct`]의 scatter를 여러 thread에서 동시에 수행합니다. `parallel` feature가 필요합니다.
+///
+/// slice를 thread 수만큼의 chunk로 나누고, 각 thread가 자신의 chunk에 대한 histogram을 계산합니다.
+/// 이후 키 순서로, 같은 키 안에서는 chunk 순서로 histogram의 누적합을 구해 각 chunk의 키별 시작 위치를 정합니다.
+/// 즉, chunk `c`에서 키 `k`를 가진 요소들은 `k`보다 작은 모든 키와, 앞선 chunk들의 키 `k` 요소들 다음에 위치합니다.
+/// 각 thread는 자신의 chunk를 앞에서부터 순회하며 시작 위치에 차례로 복사하므로,
+/// thread들은 출력 버퍼의 서로 겹치지 않는 위치에만 쓰고, 같은 키를 가진 요소들은 chunk 사이에서도 원래의 순서를 유지합니다.
+/// 따라서 결과는 [`counting_sort_direct`]와 항상 같습니다.
+///
+/// thread 수는 `std::thread::available_parallelism`과 chunk의 최소 길이로 정해지며,
+/// 한 개의 thread만 사용하게 되는 경우 [`counting_sort_direct`]를 그대로 호출합니다.
+/// chunk별 시작 위치를 위해 `thread 수 * (최대 키 + 1)`개의 `usize`를 추가로 할당합니다.
+/// key_fn은 각 요소에 대해 두 번씩 호출됩니다.
+///
+/// # Panics
+/// - key_fn이 반환하는 키 값이 `usize::MAX`인 경우.
+/// - 특정 키 값의 개수 또는 누적 개수가 `usize::MAX`를 초과하는 경우.
+/// - key_fn이 두 번의 호출에서 다른 키를 반환하는 경우.
+///
+/// # Examples
+/// ```
+/// use counting_sort::counting_sort_direct_parallel;
+///
+/// let mut arr: Vec<(u8, usize)> = (0..100_000).map(|i| ((i % 7) as u8, i)).collect();
+/// let mut expected = arr.clone();
+/// expected.sort_by_key(|x| x.0);
+/// counting_sort_direct_parallel(&mut arr, |x| x.0 as usize);
+/// assert_eq!(arr, expected);
+/// ```
+#[cfg(feature = "parallel")]
+pub fn counting_sort_direct_parallel<T, F>(slice: &mut [T], key_fn: F)
+where
+    T: Copy + Send + Sync,
+    F: Fn(&T) -> usize + Sync,
+{
+    let threads = std::thread::available_parallelism()
+        .map_or(1, usize::from)
+        .min(slice.len() / PARALLEL_MIN_CHUNK);
+    if threads <= 1 {
+        counting_sort_direct(slice, key_fn);
+    } else {
+        parallel_scatter(slice, &key_fn, threads);
+    }
+}
+
+/// 출력 버퍼를 여러 thread에서 공유하기 위한 pointer.
+/// 각 thread는 서로 겹치지 않는 위치에만 쓰므로 공유해도 data race가 없습니다.
+#[cfg(feature = "parallel")]
+struct ScatterPtr<T>(*mut T);
+
+#[cfg(feature = "parallel")]
+unsafe impl<T: Send> Send for ScatterPtr<T> {}
+#[cfg(feature = "parallel")]
+unsafe impl<T: Send> Sync for ScatterPtr<T> {}
+
+#[cfg(feature = "parallel")]
+impl<T> ScatterPtr<T> {
+    /// closure가 field가 아닌 `ScatterPtr` 전체를 capture하도록 method로 접근합니다.
+    fn get(&self) -> *mut T {
+        self.0
+    }
+}
+
+/// slice를 `threads`개의 chunk로 나누어 [`counting_sort_direct_parallel`]의 병렬 stable scatter를 수행합니다.
+#[cfg(feature = "parallel")]
+fn parallel_scatter<T, F>(slice: &mut [T], key_fn: &F, threads: usize)
+where
+    T: Copy + Send + Sync,
+    F: Fn(&T) -> usize + Sync,
+{
+    if slice.len() <= 1 {
+        return;
+    }
+    let chunk_len = slice.len().div_ceil(threads);
+
+    // chunk별 histogram
+    let mut counters: Vec<Vec<usize>> = std::thread::scope(|s| {
+        let handles: Vec<_> = slice
+            .chunks(chunk_len)
+            .map(|chunk| {
+                s.spawn(move || {
+                    let it = chunk.iter().map(key_fn).map(Result::<usize, Never>::Ok);
+                    get_counter(it).unwrap()
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .map(|h| h.join().unwrap_or_else(|e| std::panic::resume_unwind(e)))
+            .collect()
+    });
+
+    // 키 순서, 같은 키 안에서는 chunk 순서로 누적하여 histogram을 chunk별 시작 위치로 바꾼다.
+    // ends[c][k]는 chunk c의 키 k 요소들이 끝나는 위치
+    let keys = counters.iter().map(Vec::len).max().unwrap_or(0);
+    for counter in counters.iter_mut() {
+        counter.resize(keys, 0);
+    }
+    let mut ends = counters.clone();
+    let mut pos = 0usize;
+    for key in 0..keys {
+        for (counter, end) in counters.iter_mut().zip(ends.iter_mut()) {
+            let count = counter[key];
+            counter[key] = pos;
+            pos = pos.checked_add(count).unwrap();
+            end[key] = pos;
+        }
+    }
+    debug_assert_eq!(pos, slice.len());
+
+    let mut buffer = slice.to_vec();
+    let out = ScatterPtr(buffer.as_mut_ptr());
+    std::thread::scope(|s| {
+        let mut handles = Vec::with_capacity(threads);
+        for ((chunk, mut offsets), ends) in slice.chunks(chunk_len).zip(counters).zip(ends) {
+            let out = &out;
+            handles.push(s.spawn(move || {
+                for x in chunk {
+                    let key = key_fn(x);
+                    // key_fn이 첫 번째 pass와 다른 키를 반환하면, 다른 chunk나 키의 범위를 침범하기 전에 panic한다.
+                    assert!(
+                        key < ends.len() && offsets[key] < ends[key],
+                        "counting_sort_direct_parallel: key was not seen in the histogram pass"
+                    );
+                    // SAFETY: offsets[key]는 이 chunk의 키 key에 배정된 범위 [시작 위치, ends[key]) 안에 있으며,
+                    // 각 범위는 하나의 thread에만 배정되므로 다른 thread와 같은 위치에 쓰지 않는다.
+                    unsafe {
+                        out.get().add(offsets[key]).write(*x);
+                    }
+                    offsets[key] += 1;
+                }
+            }));
+        }
+        // panic한 thread의 메시지를 그대로 전달한다.
+        for h in handles {
+            h.join().unwrap_or_else(|e| std::panic::resume_unwind(e));
+        }
+    });
+    slice.copy_from_slice(&buffer);
+}
+
+/// 키를 기준으로 slice를 stable counting sort하고, 정렬에 사용된 순열을 반환합니다.
+///
+/// 반환값 `perm`에 대해 정렬 후의 `slice[j]`는 정렬 전의 `slice[perm[j]]`입니다.
+/// 즉, `perm`을 원래의 순서에 적용하면 정렬된 순서가 되므로, 같은 길이의 병렬 배열을
+/// `perm.iter().map(|&i| other[i])`와 같이 같은 순서로 재배치할 수 있습니다.
+/// 이는 [`counting_sort_ranks_by_key`]가 반환하는 순위의 역순열입니다.
+/// key_fn은 각 요소에 대해 두 번씩 호출됩니다.
+///
+/// # Panics
+/// - key_fn이 반환하는 키 값이 `usize::MAX`인 경우.
+/// - 특정 키 값의 개수 또는 누적 개수가 `usize::MAX`를 초과하는 경우.
+///
+/// # Examples
+/// ```
+/// use counting_sort::counting_sort_by_key_with_perm;
+///
+/// let mut keys = [3usize, 1, 2, 1];
+/// let names = ["d", "a", "c", "b"];
+/// let perm = counting_sort_by_key_with_perm(&mut keys, |x| *x);
+/// assert_eq!(keys, [1, 1, 2, 3]);
+/// assert_eq!(perm, vec![1, 3, 2, 0]);
+///
+/// let names: Vec<&str> = perm.iter().map(|&i| names[i]).collect();
+/// assert_eq!(names, ["a", "b", "c", "d"]);
+/// ```
+pub fn counting_sort_by_key_with_perm<T, F>(slice: &mut [T], key_fn: F) -> Vec<usize>
+where
+    F: FnMut(&T) -> usize,
+{
+    let mut ranks = counting_sort_ranks_by_key(slice, key_fn);
+
+    let mut perm = vec![0; ranks.len()];
+    for (idx, &rank) in ranks.iter().enumerate() {
+        perm[rank] = idx;
+    }
+
+    apply_permutation(slice, &mut ranks);
+    perm
+}
+
+/// 반복자의 요소들을 키 기준으로 stable counting sort하여 새로운 `Vec`으로 반환합니다.
+///
+/// 반복자의 길이를 미리 알 필요가 없으며, 요소와 키를 한 번에 버퍼링한 후
+/// 히스토그램을 만들고 정렬된 위치로 재배치합니다.
+/// key_fn은 각 요소에 대해 한 번씩만 호출됩니다.
+///
+/// # Panics
+/// - key_fn이 반환하는 키 값이 `usize::MAX`인 경우.
+/// - 특정 키 값의 개수 또는 누적 개수가 `usize::MAX`를 초과하는 경우.
+///
+/// # Examples
+/// ```
+/// use counting_sort::counting_sort_collect_by_key;
+///
+/// let words = ["sort", "a", "counting", "by"].into_iter();
+/// let sorted = counting_sort_collect_by_key(words, |w| w.len());
+/// assert_eq!(sorted, vec!["a", "by", "sort", "counting"]);
+/// ```
+pub fn counting_sort_collect_by_key<T, I, F>(iter: I, mut key_fn: F) -> Vec<T>
+where
+    I: Iterator<Item = T>,
+    F: FnMut(&T) -> usize,
+{
+    let (mut buffer, keys): (Vec<T>, Vec<usize>) = iter
+        .map(|item| {
+            let key = key_fn(&item);
+            (item, key)
+        })
+        .unzip();
+
+    let mut counter = {
+        let it = keys.iter().cloned().map(Result::<usize, Never>::Ok);
+        get_accumulated_counter(it).unwrap()
+    };
+    let mut perm = {
+        let it = keys.iter().cloned().map(Result::<usize, Never>::Ok);
+        accumulated_counter2permutation(&mut counter, it, buffer.len()).unwrap()
+    };
+
+    apply_permutation(&mut buffer, &mut perm);
+    buffer
+}
+
+/// 주어진 아이템 반복자로부터 키를 추출하여 각 키의 등장 횟수를 세어 반환합니다.
 ///
 /// # Parameters
 /// - `it`: `Result<usize, E>` 타입을 반환하는 아이템 반복자입니다. 각 `usize` 값은 정렬할 요소의 키입니다.
 ///
 /// # Returns
-/// - 성공 시: 각 인덱스가 키를 나타내고, 해당 인덱스의 값이 해당 키까지의 누적 등장 횟수인 `Vec<usize>`를 반환합니다.
+/// - 성공 시: 각 인덱스가 키를 나타내고, 해당 인덱스의 값이 해당 키의 등장 횟수인 `Vec<usize>`를 반환합니다.
+///   길이는 `최대 키 + 1`이며, 반복자가 비어있는 경우 빈 `Vec`입니다.
 /// - 실패 시: 반복자에서 발생한 첫 번째 에러 `E`를 반환합니다.
 ///
 /// # Panics
 /// - 반복자에서 추출된 키 값이 `usize::MAX`인 경우.
 /// - 특정 키의 등장 횟수가 `usize::MAX`를 초과하는 경우.
-/// - 누적 등장 횟수 계산 중 `usize` 오버플로우가 발생하는 경우.
-fn get_accumulated_counter<E, I>(it: I) -> Result<Vec<usize>, E>
+fn get_counter<E, I>(it: I) -> Result<Vec<usize>, E>
 where
     I: Iterator<Item = Result<usize, E>>,
 {
     let mut counter: Vec<usize> = Vec::new();
-    let mut max_key = 0;
+    let mut max_key = None;
 
     for item in it {
         let key = item?;
         if counter.len() <= key {
+            #[cfg(test)]
+            tests::RESIZE_CNT.with(|cnt| cnt.set(cnt.get() + 1));
             counter.resize(key.checked_add(1).unwrap().next_power_of_two(), 0);
         }
         counter[key] = counter[key].checked_add(1).unwrap();
-        max_key = max_key.max(key);
+        max_key = max_key.max(Some(key));
     }
 
-    for i in 1..=max_key {
+    // resize는 2의 거듭제곱으로 늘리므로, max_key 뒤에는 등장하지 않은 키만 남아있어야 한다.
+    // 따라서 truncate 후의 histogram은 마지막 원소가 0이 아닌 길이 max_key + 1이다.
+    let len = max_key.map_or(0, |key| key + 1);
+    debug_assert!(
+        counter[len..].iter().all(|&count| count == 0) && counter[..len].last() != Some(&0),
+        "get_counter: max_key does not match the last non-empty bucket of the histogram"
+    );
+    counter.truncate(len);
+    Ok(counter)
+}
+
+/// 주어진 아이템 반복자로부터 키를 추출하여 카운터 배열을 생성하고,
+/// 각 키의 누적 등장 횟수를 계산하여 반환합니다.
+///
+/// # Parameters
+/// - `it`: `Result<usize, E>` 타입을 반환하는 아이템 반복자입니다. 각 `usize` 값은 정렬할 요소의 키입니다.
+///
+/// # Returns
+/// - 성공 시: 각 인덱스가 키를 나타내고, 해당 인덱스의 값이 해당 키까지의 누적 등장 횟수인 `Vec<usize>`를 반환합니다.
+/// - 실패 시: 반복자에서 발생한 첫 번째 에러 `E`를 반환합니다.
+///
+/// # Panics
+/// - 반복자에서 추출된 키 값이 `usize::MAX`인 경우.
+/// - 특정 키의 등장 횟수가 `usize::MAX`를 초과하는 경우.
+/// - 누적 등장 횟수 계산 중 `usize` 오버플로우가 발생하는 경우.
+fn get_accumulated_counter<E, I>(it: I) -> Result<Vec<usize>, E>
+where
+    I: Iterator<Item = Result<usize, E>>,
+{
+    let mut counter = get_counter(it)?;
+
+    for i in 1..counter.len() {
         counter[i] = counter[i].checked_add(counter[i - 1]).unwrap();
     }
 
@@ -231,6 +915,10 @@ where
 }
 
 /// 누적 카운터 배열과 아이템 반복자로부터 안정 정렬을 위한 순열(permutation) 배열을 생성합니다.
+///
+/// `counter`는 첫 번째 pass에서 발견한 최대 키까지, 즉 길이 `max_key + 1`로 만들어져 있으므로
+/// 두 번째 pass에서는 resize 없이 그대로 사용합니다.
+/// 두 pass에서 같은 키가 계산되어야 하므로, 키가 `counter`의 범위를 벗어나면 debug 빌드에서 panic이 발생합니다.
 fn accumulated_counter2permutation<E, I>(
     counter: &mut [usize],
     it: I,
@@ -242,6 +930,10 @@ where
     let mut perm = vec![0; len];
     for (idx, item) in it.enumerate().rev() {
         let key = item?;
+        debug_assert!(
+            key < counter.len(),
+            "accumulated_counter2permutation: key was not seen in the histogram pass"
+        );
         counter[key] -= 1;
         perm[idx] = counter[key];
     }
@@ -250,6 +942,12 @@ where
 
 /// 주어진 순열에 따라 슬라이스의 요소들을 제자리에서 재배치합니다 (swap 사용).
 /// 이 함수는 `T`가 `Copy` 트레잇을 구현하지 않은 경우에 사용됩니다.
+/// `perm[i]`는 `src[i]`가 이동할 목적지 인덱스이며, 결과적으로 `src[perm[i]]`에 원래의 `src[i]`가 위치합니다.
+///
+/// # Note
+/// 각 인덱스 `i`에서 `perm[i] == i`가 될 때까지 cycle을 따라 swap합니다.
+/// swap마다 원소 하나가 목적지에 확정되고 `perm[j] == j`가 되므로, 모든 cycle은 정확히 한 번씩 소비되며
+/// 함수 종료 시 `perm`은 항등 순열이 됩니다.
 fn apply_permutation<T>(src: &mut [T], perm: &mut [usize]) {
     for i in 0..src.len() {
         while perm[i] != i {
@@ -273,6 +971,34 @@ where
     }
 }
 
+/// 주어진 순열에 따라 슬라이스의 요소들을 제자리에서 재배치합니다 (cycle을 따라 복사 사용).
+/// 이 함수는 `T`가 `Copy` 트레잇을 구현한 경우에, 복사본 없이 재배치하기 위해 사용됩니다.
+/// `perm[i]`는 `src[i]`가 이동할 목적지 인덱스입니다.
+///
+/// # Note
+/// 각 cycle의 시작 요소를 복사해 들고 목적지로 옮기면서, 목적지에 있던 요소를 다시 들고 다음 목적지로 이동합니다.
+/// cycle이 시작 인덱스로 돌아오면 들고 있던 요소를 시작 인덱스에 씁니다.
+/// 지나간 인덱스는 `perm[j] = j`로 표시하므로 모든 cycle은 정확히 한 번씩 소비되며,
+/// 함수 종료 시 `perm`은 항등 순열이 됩니다.
+fn apply_permutation_cycles_copy<T>(src: &mut [T], perm: &mut [usize])
+where
+    T: Copy,
+{
+    for start in 0..src.len() {
+        if perm[start] == start {
+            continue;
+        }
+        let mut carried = src[start];
+        let mut dst = perm[start];
+        perm[start] = start;
+        while dst != start {
+            carried = std::mem::replace(&mut src[dst], carried);
+            dst = std::mem::replace(&mut perm[dst], dst);
+        }
+        src[start] = carried;
+    }
+}
+
 impl<T> CountingSort for &mut [T]
 where
     T: Into<usize> + Copy,
@@ -375,9 +1101,39 @@ impl<T> CountingSortByKeyCached<T> for &mut [T] {
     }
 }
 
+impl<T> CountingSortByKeys<T> for &mut [T] {
+    fn counting_sort_by_keys_2<F1, F2>(self, k1: F1, k2: F2)
+    where
+        F1: FnMut(&T) -> usize,
+        F2: FnMut(&T) -> usize,
+    {
+        // 덜 중요한 키부터 정렬합니다.
+        self.counting_sort_by_key(k2);
+        self.counting_sort_by_key(k1);
+    }
+
+    fn counting_sort_by_keys_3<F1, F2, F3>(self, k1: F1, k2: F2, k3: F3)
+    where
+        F1: FnMut(&T) -> usize,
+        F2: FnMut(&T) -> usize,
+        F3: FnMut(&T) -> usize,
+    {
+        // 덜 중요한 키부터 정렬합니다.
+        self.counting_sort_by_key(k3);
+        self.counting_sort_by_key(k2);
+        self.counting_sort_by_key(k1);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::cell::Cell;
+
+    thread_local! {
+        /// number of histogram resize in the current thread
+        pub static RESIZE_CNT: Cell<usize> = const { Cell::new(0) };
+    }
 
     #[test]
     fn test_counting_sort() {
@@ -693,4 +1449,463 @@ mod tests {
             "Array should be unchanged on negative i64 error"
         );
     }
+
+    // apply_permutation 테스트: 결과는 src[perm^-1[i]]와 같아야 함
+    fn check_apply_permutation<T: Clone + PartialEq + std::fmt::Debug>(
+        src: Vec<T>,
+        perm: Vec<usize>,
+    ) {
+        let mut inverse = vec![0; perm.len()];
+        for (i, &p) in perm.iter().enumerate() {
+            inverse[p] = i;
+        }
+        let expected: Vec<T> = (0..src.len()).map(|i| src[inverse[i]].clone()).collect();
+
+        let mut actual = src;
+        let mut perm = perm;
+        apply_permutation(&mut actual, &mut perm);
+        assert_eq!(actual, expected);
+        assert!(perm.iter().enumerate().all(|(i, &p)| i == p));
+    }
+
+    #[test]
+    fn test_apply_permutation_identity() {
+        check_apply_permutation((0..10).collect::<Vec<i32>>(), (0..10).collect());
+    }
+
+    #[test]
+    fn test_apply_permutation_full_cycle() {
+        // 0 -> 1 -> 2 -> ... -> 9 -> 0
+        let perm: Vec<usize> = (0..10).map(|i| (i + 1) % 10).collect();
+        check_apply_permutation((0..10).collect::<Vec<i32>>(), perm.clone());
+
+        // 0 -> 9 -> 8 -> ... -> 1 -> 0
+        let perm: Vec<usize> = (0..10).map(|i| (i + 9) % 10).collect();
+        check_apply_permutation((0..10).collect::<Vec<i32>>(), perm);
+    }
+
+    #[test]
+    fn test_apply_permutation_disjoint_cycles() {
+        // (0 3 5) (1 2) (4) (6 8 7 9)
+        let perm = vec![3, 2, 1, 5, 4, 0, 8, 9, 7, 6];
+        check_apply_permutation((0..10).collect::<Vec<i32>>(), perm);
+    }
+
+    #[test]
+    fn test_apply_permutation_string() {
+        let src: Vec<String> = ["a", "b", "c", "d", "e", "f"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        check_apply_permutation(src.clone(), vec![0, 1, 2, 3, 4, 5]);
+        check_apply_permutation(src.clone(), vec![1, 2, 3, 4, 5, 0]);
+        check_apply_permutation(src, vec![2, 0, 1, 3, 5, 4]);
+    }
+
+    #[test]
+    fn test_apply_permutation_zst() {
+        check_apply_permutation(vec![(); 6], vec![1, 2, 3, 4, 5, 0]);
+        check_apply_permutation(vec![(); 6], vec![2, 0, 1, 3, 5, 4]);
+    }
+
+    // 여러 키에 대한 사전식 정렬 테스트
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    struct Task {
+        category: usize,
+        priority: usize,
+        name: &'static str,
+    }
+
+    #[test]
+    fn test_counting_sort_by_keys_2() {
+        let task = |category, priority, name| Task {
+            category,
+            priority,
+            name,
+        };
+        let mut tasks = vec![
+            task(2, 1, "a"),
+            task(0, 3, "b"),
+            task(1, 0, "c"),
+            task(0, 1, "d"),
+            task(2, 0, "e"),
+            task(1, 0, "f"),
+            task(0, 3, "g"),
+            task(2, 1, "h"),
+        ];
+        let mut expected = tasks.clone();
+        expected.sort_by_key(|t| (t.category, t.priority)); // stable
+
+        tasks.counting_sort_by_keys_2(|t| t.category, |t| t.priority);
+        assert_eq!(tasks, expected);
+        let names: Vec<&str> = tasks.iter().map(|t| t.name).collect();
+        assert_eq!(names, ["d", "b", "g", "c", "f", "e", "a", "h"]);
+    }
+
+    #[test]
+    fn test_counting_sort_by_keys_3() {
+        let mut arr: Vec<(usize, usize, usize, usize)> = (0..200)
+            .map(|i| ((i * 7) % 3, (i * 11) % 5, (i * 13) % 4, i))
+            .collect();
+        let mut expected = arr.clone();
+        expected.sort_by_key(|t| (t.0, t.1, t.2));
+
+        arr.counting_sort_by_keys_3(|t| t.0, |t| t.1, |t| t.2);
+        assert_eq!(arr, expected);
+    }
+
+    #[test]
+    fn test_key_histogram() {
+        // 키 0, 2, 5만 존재하고 1, 3, 4는 없음
+        let arr = [5usize, 0, 2, 2, 5, 0, 5, 2, 2];
+        assert_eq!(key_histogram(&arr, |x| *x), vec![2, 0, 4, 0, 0, 3]);
+
+        let empty: [usize; 0] = [];
+        assert!(key_histogram(&empty, |x| *x).is_empty());
+
+        let zeros = [0usize; 7];
+        assert_eq!(key_histogram(&zeros, |x| *x), vec![7]);
+    }
+
+    #[test]
+    fn test_counting_sort_ranks_by_key() {
+        let arr: Vec<(usize, usize)> = (0..200).map(|i| ((i * 37) % 11, i)).collect();
+        let rank = counting_sort_ranks_by_key(&arr, |x| x.0);
+
+        // rank는 유효한 순열
+        let mut seen = vec![false; arr.len()];
+        for &r in &rank {
+            assert!(!seen[r]);
+            seen[r] = true;
+        }
+
+        // rank에 따라 재배치하면 stable하게 정렬됨
+        let mut reordered = vec![(0, 0); arr.len()];
+        for (i, &r) in rank.iter().enumerate() {
+            reordered[r] = arr[i];
+        }
+        let mut expected = arr.clone();
+        expected.sort_by_key(|x| x.0);
+        assert_eq!(reordered, expected);
+
+        let empty: [usize; 0] = [];
+        assert!(counting_sort_ranks_by_key(&empty, |x| *x).is_empty());
+    }
+
+    #[test]
+    fn test_counting_sort_collect_by_key() {
+        let sorted = counting_sort_collect_by_key((0..100).map(|i| 99 - i), |x| *x);
+        assert_eq!(sorted, (0..100).collect::<Vec<usize>>());
+
+        // 길이를 알 수 없는 반복자, stable
+        let it = (0..300usize)
+            .filter(|i| i % 3 != 0)
+            .map(|i| ((i * 37) % 11, i));
+        let mut expected: Vec<(usize, usize)> = it.clone().collect();
+        expected.sort_by_key(|x| x.0);
+        assert_eq!(counting_sort_collect_by_key(it, |x| x.0), expected);
+
+        let empty = counting_sort_collect_by_key(std::iter::empty::<String>(), |s| s.len());
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "key was not seen in the histogram pass")]
+    fn test_counting_sort_by_key_inconsistent_key_fn() {
+        let mut arr = [3usize, 1, 2];
+        // 두 번째 pass에서 더 큰 키를 반환
+        let mut calls = 0;
+        arr.counting_sort_by_key(|x| {
+            calls += 1;
+            match calls > 3 {
+                true => *x + 10,
+                false => *x,
+            }
+        });
+    }
+
+    #[test]
+    fn test_counting_sort_by_key_desc() {
+        // 중복 키, 두 번째 값은 원래 순서
+        let mut arr: Vec<(usize, usize)> = (0..300).map(|i| ((i * 37) % 11, i)).collect();
+        let mut expected = arr.clone();
+        expected.sort_by_key(|x| std::cmp::Reverse(x.0));
+
+        counting_sort_by_key_desc(&mut arr, |x| x.0);
+        assert_eq!(arr, expected);
+
+        // 오름차순 결과를 뒤집은 것과는 다름
+        let mut reversed = expected.clone();
+        reversed.sort_by_key(|x| x.0);
+        reversed.reverse();
+        assert_ne!(arr, reversed);
+
+        let mut empty: [usize; 0] = [];
+        counting_sort_by_key_desc(&mut empty, |x| *x);
+    }
+
+    #[test]
+    fn test_counting_sort_by_key_with_perm() {
+        // 중복 키를 가진 키 배열과, 같은 길이의 병렬 배열
+        let keys: Vec<usize> = (0..500).map(|i| (i * 7919) % 13).collect();
+        let values: Vec<String> = (0..500).map(|i| format!("v{i}")).collect();
+
+        let mut sorted_keys = keys.clone();
+        let perm = counting_sort_by_key_with_perm(&mut sorted_keys, |x| *x);
+        let reordered: Vec<String> = perm.iter().map(|&i| values[i].clone()).collect();
+
+        // 쌍을 함께 stable 정렬한 결과와 같음
+        let mut pairs: Vec<(usize, String)> = keys.iter().cloned().zip(values).collect();
+        pairs.sort_by_key(|p| p.0);
+        let (expected_keys, expected_values): (Vec<usize>, Vec<String>) = pairs.into_iter().unzip();
+        assert_eq!(sorted_keys, expected_keys);
+        assert_eq!(reordered, expected_values);
+
+        // 순위의 역순열
+        let ranks = counting_sort_ranks_by_key(&keys, |x| *x);
+        assert!((0..keys.len()).all(|i| perm[ranks[i]] == i));
+
+        let mut empty: [usize; 0] = [];
+        assert!(counting_sort_by_key_with_perm(&mut empty, |x| *x).is_empty());
+    }
+
+    #[test]
+    fn test_counting_sort_direct() {
+        // 중복 키, 두 번째 값은 원래 순서
+        let mut arr: Vec<(u16, usize)> = (0..2000).map(|i| (((i * 7919) % 97) as u16, i)).collect();
+        let mut expected = arr.clone();
+        expected.sort_by_key(|x| x.0);
+
+        counting_sort_direct(&mut arr, |x| x.0 as usize);
+        assert_eq!(arr, expected);
+
+        // 순열 기반 구현과 같은 결과
+        let mut direct: Vec<u8> = (0..1000).map(|i| ((i * 31) % 256) as u8).collect();
+        let mut by_key = direct.clone();
+        counting_sort_direct(&mut direct, |x| *x as usize);
+        by_key.counting_sort_by_key(|x| *x as usize);
+        assert_eq!(direct, by_key);
+
+        let mut empty: [u8; 0] = [];
+        counting_sort_direct(&mut empty, |x| *x as usize);
+        let mut single = [7u8];
+        counting_sort_direct(&mut single, |x| *x as usize);
+        assert_eq!(single, [7]);
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_counting_sort_direct_parallel() {
+        // 1M개의 (u16 키, 원래 위치), 중복 키가 많음
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+
+        let seed: u64 = 42;
+        let mut rng = StdRng::seed_from_u64(seed);
+        let source: Vec<(u16, usize)> = (0..1_000_000).map(|i| (rng.random(), i)).collect();
+        let mut expected = source.clone();
+        counting_sort_direct(&mut expected, |x| x.0 as usize);
+        assert!(expected.is_sorted());
+
+        let mut arr = source.clone();
+        counting_sort_direct_parallel(&mut arr, |x| x.0 as usize);
+        assert_eq!(arr, expected);
+
+        // chunk 경계가 같은 키 사이에 오도록 thread 수를 직접 지정
+        for threads in [1, 2, 3, 8, 64] {
+            let mut arr = source.clone();
+            parallel_scatter(&mut arr, &|x: &(u16, usize)| x.0 as usize, threads);
+            assert_eq!(arr, expected);
+        }
+
+        // chunk보다 thread가 많은 짧은 입력
+        let mut short: Vec<(u16, usize)> = source[..5].to_vec();
+        let mut short_expected = short.clone();
+        short_expected.sort_by_key(|x| x.0);
+        parallel_scatter(&mut short, &|x: &(u16, usize)| x.0 as usize, 8);
+        assert_eq!(short, short_expected);
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    #[should_panic(expected = "key was not seen in the histogram pass")]
+    fn test_counting_sort_direct_parallel_inconsistent_key() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        // 두 번째 pass에서 키가 바뀜
+        let calls = AtomicUsize::new(0);
+        let len = 1000;
+        let mut arr: Vec<usize> = (0..len).map(|i| i % 10).collect();
+        parallel_scatter(
+            &mut arr,
+            &|x: &usize| {
+                if calls.fetch_add(1, Ordering::Relaxed) < len {
+                    *x
+                } else {
+                    0
+                }
+            },
+            4,
+        );
+    }
+
+    #[test]
+    fn test_counting_sort_u16() {
+        let mut arr: Vec<u16> = (0..5000u32)
+            .map(|i| (i * 7919 % 65536) as u16)
+            .chain([0, u16::MAX, 0, u16::MAX])
+            .collect();
+        let mut expected = arr.clone();
+        expected.sort();
+        counting_sort_u16(&mut arr);
+        assert_eq!(arr, expected);
+        assert_eq!((arr[0], arr[arr.len() - 1]), (0, u16::MAX));
+
+        let mut empty: [u16; 0] = [];
+        counting_sort_u16(&mut empty);
+        let mut single = [u16::MAX];
+        counting_sort_u16(&mut single);
+        assert_eq!(single, [u16::MAX]);
+    }
+
+    #[test]
+    fn test_counting_sort_by_key_u16() {
+        // 중복 키, 두 번째 값은 원래 순서
+        let mut arr: Vec<(u16, String)> = (0..3000u32)
+            .map(|i| ((i * 40503 % 65536) as u16 / 64 * 64, i.to_string()))
+            .chain([(u16::MAX, "max".to_string()), (0, "zero".to_string())])
+            .collect();
+        let mut expected = arr.clone();
+        expected.sort_by_key(|x| x.0);
+
+        let mut calls = 0;
+        counting_sort_by_key_u16(&mut arr, |x| {
+            calls += 1;
+            x.0
+        });
+        assert_eq!(arr, expected);
+        assert_eq!(calls, arr.len());
+        assert_eq!(arr.last().unwrap().1, "max");
+    }
+
+    #[test]
+    fn test_counting_sort_u8_by_key() {
+        // 중복 키, 두 번째 값은 원래 순서
+        let mut arr: Vec<(u8, String)> = (0..3000u32)
+            .map(|i| ((i * 40503 % 256) as u8 / 8 * 8, i.to_string()))
+            .chain([(u8::MAX, "max".to_string()), (0, "zero".to_string())])
+            .collect();
+        let mut expected = arr.clone();
+        expected.sort_by_key(|x| x.0);
+
+        let mut calls = 0;
+        counting_sort_u8_by_key(&mut arr, |x| {
+            calls += 1;
+            x.0
+        });
+        assert_eq!(arr, expected);
+        assert_eq!(calls, arr.len());
+        assert_eq!(arr.last().unwrap().1, "max");
+        // 키 0 중 원래 마지막에 있던 원소는 0의 그룹에서도 마지막
+        let zeros: Vec<&str> = arr
+            .iter()
+            .take_while(|x| x.0 == 0)
+            .map(|x| x.1.as_str())
+            .collect();
+        assert_eq!(zeros.last(), Some(&"zero"));
+
+        let mut empty: [u8; 0] = [];
+        counting_sort_u8_by_key(&mut empty, |x| *x);
+        let mut single = [u8::MAX];
+        counting_sort_u8_by_key(&mut single, |x| *x);
+        assert_eq!(single, [u8::MAX]);
+    }
+
+    #[test]
+    fn test_counting_sort_or_fallback_by_key() {
+        // 좁은 범위: min만큼 offset된 histogram으로 counting sort
+        let mut dense: Vec<(usize, usize)> = (0..1000)
+            .map(|i| (1_000_000 + (i * 7919) % 500, i))
+            .collect();
+        let mut expected = dense.clone();
+        expected.sort_by_key(|x| x.0);
+        RESIZE_CNT.with(|cnt| cnt.set(0));
+        counting_sort_or_fallback_by_key(&mut dense, |x| x.0, 1000);
+        assert!(RESIZE_CNT.with(|cnt| cnt.get()) > 0);
+        assert_eq!(dense, expected);
+
+        // 넓은 범위: histogram을 만들지 않고 merge sort
+        let mut sparse: Vec<(usize, usize)> = (0..1000)
+            .map(|i| ((i * 7919) % 500 * 2_000_000, i))
+            .collect();
+        let mut expected = sparse.clone();
+        expected.sort_by_key(|x| x.0);
+        RESIZE_CNT.with(|cnt| cnt.set(0));
+        counting_sort_or_fallback_by_key(&mut sparse, |x| x.0, 1000);
+        assert_eq!(RESIZE_CNT.with(|cnt| cnt.get()), 0);
+        assert_eq!(sparse, expected);
+
+        // 경계: 범위가 정확히 max_allowed_range이면 counting sort
+        let mut edge = [(9usize, 'a'), (0, 'b'), (9, 'c')];
+        RESIZE_CNT.with(|cnt| cnt.set(0));
+        counting_sort_or_fallback_by_key(&mut edge, |x| x.0, 10);
+        assert!(RESIZE_CNT.with(|cnt| cnt.get()) > 0);
+        assert_eq!(edge, [(0, 'b'), (9, 'a'), (9, 'c')]);
+
+        let mut empty: [usize; 0] = [];
+        counting_sort_or_fallback_by_key(&mut empty, |x| *x, 0);
+    }
+
+    #[test]
+    fn test_counting_sort_by_key_inplace() {
+        // 큰 Copy 타입, 두 번째 값은 원래 순서
+        let mut arr: Vec<(usize, [u64; 8])> = (0..1000u64)
+            .map(|i| ((i * 7919 % 97) as usize, [i; 8]))
+            .collect();
+        let mut expected = arr.clone();
+        expected.sort_by_key(|x| x.0);
+
+        let mut direct = arr.clone();
+        counting_sort_direct(&mut direct, |x| x.0);
+        counting_sort_by_key_inplace(&mut arr, |x| x.0);
+        assert_eq!(arr, expected);
+        assert_eq!(arr, direct);
+
+        let mut arr = [(2u8, 'a'), (0, 'b'), (2, 'c'), (1, 'd'), (0, 'e')];
+        counting_sort_by_key_inplace(&mut arr, |x| x.0 as usize);
+        assert_eq!(arr, [(0, 'b'), (0, 'e'), (1, 'd'), (2, 'a'), (2, 'c')]);
+
+        let mut empty: [u32; 0] = [];
+        counting_sort_by_key_inplace(&mut empty, |x| *x as usize);
+
+        // 긴 cycle과 고정점이 섞인 입력, swap 기반 구현과 같은 결과
+        let mut arr: Vec<(usize, u32)> = (0..5000u32)
+            .map(|i| {
+                (
+                    if i % 3 == 0 {
+                        i as usize
+                    } else {
+                        (i * 31 % 257) as usize
+                    },
+                    i,
+                )
+            })
+            .collect();
+        let mut by_swap = arr.clone();
+        by_swap.counting_sort_by_key(|x| x.0);
+        counting_sort_by_key_inplace(&mut arr, |x| x.0);
+        assert_eq!(arr, by_swap);
+        assert!(arr.is_sorted_by_key(|x| x.0));
+    }
+
+    #[test]
+    fn test_apply_permutation_cycles_copy() {
+        // (0 2 4)(1 3) cycle과 고정점 5
+        let mut src = ['a', 'b', 'c', 'd', 'e', 'f'];
+        let mut perm = [2, 3, 4, 1, 0, 5];
+        apply_permutation_cycles_copy(&mut src, &mut perm);
+        assert_eq!(src, ['e', 'd', 'a', 'b', 'c', 'f']);
+        assert_eq!(perm, [0, 1, 2, 3, 4, 5]);
+    }
 }
@@ -2,7 +2,9 @@
 //! Implementation of intro-sort algorithm.
 use heap_on_slice::max_heap;
 use insertion_sort::insertion_sort_by;
-use quick_sort::ternary_partition_by;
+use quick_sort::{
+    choose_pivot_by, split_three, ternary_partition_by, ternary_partition_regions_by,
+};
 
 /// # Description
 /// Sorts the given slice in-place using a intro‑sort algorithm.
@@ -28,6 +30,31 @@ pub fn intro_sort<T: Ord>(slice: &mut [T]) {
     intro_sort_by(slice, T::cmp)
 }
 
+/// # Description
+/// Sorts the given slice in-place using a intro‑sort algorithm in descending order.
+/// Same as `intro_sort_by` with `|a, b| b.cmp(a)`, without spelling out `Reverse`.
+///
+/// # Type Parameters
+/// - `T`: The element type. Must implement `Ord`.
+///
+/// # Parameters
+/// - `slice`: The mutable slice to sort.
+///
+/// # Panics
+/// Panics if calculating partition indices overflows (only for very large slices).
+/// Panics if the implementation of Ord panics.
+///
+/// # Examples
+/// ```
+/// use intro_sort::intro_sort_desc;
+/// let mut v = vec![3, 1, 4, 1, 5];
+/// intro_sort_desc(&mut v);
+/// assert_eq!(v, vec![5, 4, 3, 1, 1]);
+/// ```
+pub fn intro_sort_desc<T: Ord>(slice: &mut [T]) {
+    intro_sort_by(slice, |a, b| b.cmp(a));
+}
+
 /// # Description
 /// Sorts the given slice in-place using a intro‑sort algorithm
 /// whith comparator.
@@ -43,6 +70,18 @@ pub fn intro_sort<T: Ord>(slice: &mut [T]) {
 /// # Panics
 /// Panics if calculating partition indices overflows (only for very large slices).
 /// Panics if the implementation of 'comp' panics.
+/// In debug builds, panics if `comp` is not a consistent total order.
+///
+/// # Partial order
+/// `comp` must be a total order. For data with only a partial order,
+/// such as floats with NaN, use [`intro_sort_partial`] instead.
+///
+/// # Performance
+/// Time complexity(worst) : O(n log n)
+/// The pivots are the first and the last element, without randomization,
+/// so a crafted input (e.g. by McIlroy's adversary) can defeat every partition.
+/// Such input still takes O(n log n), since the recursion deeper than `2 * log2(n)`
+/// falls back to heap sort. The same input always takes the same path.
 ///
 /// # Examples
 /// ```
@@ -62,32 +101,549 @@ where
     let max_depth = (usize::ilog2(len)) << 1;
 
     let mut comp = comp;
-    intro_recurse_sort_by(slice, &mut comp, max_depth);
+    intro_recurse_sort_by(slice, &mut comp, max_depth, true, true);
+
+    debug_assert!(
+        slice.is_sorted_by(|a, b| comp(a, b).is_le()),
+        "intro_sort_by: comparator is not a consistent total order"
+    );
+}
+
+/// # Description
+/// Sorts the given slice in-place using a intro‑sort algorithm,
+/// with a single insertion sort pass at the end instead of one for each small subarray.
+///
+/// # Type Parameters
+/// - `T`: The element type. Must implement `Ord`.
+///
+/// # Parameters
+/// - `slice`: The mutable slice to sort.
+///
+/// # Panics
+/// Panics if calculating partition indices overflows (only for very large slices).
+/// Panics if the implementation of Ord panics.
+///
+/// # Examples
+/// ```
+/// use intro_sort::intro_sort_final_insertion;
+/// let mut v = vec![3, 1, 4, 1, 5];
+/// intro_sort_final_insertion(&mut v);
+/// assert_eq!(v, vec![1, 1, 3, 4, 5]);
+/// ```
+pub fn intro_sort_final_insertion<T: Ord>(slice: &mut [T]) {
+    intro_sort_final_insertion_by(slice, T::cmp)
+}
+
+/// # Description
+/// Sorts the given slice in-place using a intro‑sort algorithm
+/// whith comparator, with a single insertion sort pass at the end.
+///
+/// # Final insertion pass
+/// The partitioning stops at the same threshold as [`intro_sort_by`],
+/// but the small subarrays are left unsorted.
+/// Every element is then in its final subarray, less than the threshold away from its final position,
+/// so one insertion sort over the whole slice finishes it in O(n) time.
+/// The pass walks the slice from front to back once, instead of jumping between small subarrays
+/// scattered by the recursion.
+/// With the threshold of 16, the subarrays are already in the cache right after their partition,
+/// so it is not faster than [`intro_sort_by`] on random integers;
+/// compare them with the `intro_sort_small_subarrays` benchmark of the `yt42` crate for your data.
+///
+/// # Type Parameters
+/// - `T`: The element type.
+/// - `F`: The comparator type. Must implement 'FnMut' trait.
+///
+/// # Parameters
+/// - `slice`: The mutable slice to sort.
+/// - `comp`: The callable object to compare two &T data.
+///
+/// # Panics
+/// Panics if calculating partition indices overflows (only for very large slices).
+/// Panics if the implementation of 'comp' panics.
+/// In debug builds, panics if `comp` is not a consistent total order.
+///
+/// # Examples
+/// ```
+/// use intro_sort::intro_sort_final_insertion_by;
+/// let mut v = vec![3, 1, 4, 1, 5];
+/// intro_sort_final_insertion_by(&mut v, |a: &i32, b: &i32| b.cmp(a));
+/// assert_eq!(v, vec![5, 4, 3, 1, 1]);
+/// ```
+pub fn intro_sort_final_insertion_by<T, F>(slice: &mut [T], mut comp: F)
+where
+    F: FnMut(&T, &T) -> std::cmp::Ordering,
+{
+    let len = slice.len();
+    if len == 0 {
+        return;
+    }
+    let max_depth = (usize::ilog2(len)) << 1;
+
+    intro_recurse_sort_by(slice, &mut comp, max_depth, true, false);
+    insertion_sort_by(&mut *slice, &mut comp);
+
+    debug_assert!(
+        slice.is_sorted_by(|a, b| comp(a, b).is_le()),
+        "intro_sort_final_insertion_by: comparator is not a consistent total order"
+    );
+}
+
+/// # Description
+/// Sorts the given slice in-place using a intro‑sort algorithm
+/// with partial comparator, e.g. `f64::partial_cmp`.
+///
+/// # Incomparable pairs
+/// If `comp` returns `None`, the pair is treated as `Equal`, so they are not swapped with each other.
+/// Since this `Equal` is not transitive, an incomparable element(e.g. NaN) may separate comparable ones,
+/// and the order among the comparable elements is not guaranteed across it.
+/// Still, the defined behavior is:
+/// - The slice is a permutation of the input, and the function never panics by the comparator itself.
+/// - The layout is deterministic, the same input always produces the same output.
+/// - If every pair is comparable, the slice is sorted as [`intro_sort_by`] does.
+///
+/// # Type Parameters
+/// - `T`: The element type.
+/// - `F`: The comparator type. Must implement 'FnMut' trait.
+///
+/// # Parameters
+/// - `slice`: The mutable slice to sort.
+/// - `comp`: The callable object to compare two &T data, partially.
+///
+/// # Panics
+/// Panics if calculating partition indices overflows (only for very large slices).
+/// Panics if the implementation of 'comp' panics.
+///
+/// # Examples
+/// ```
+/// use intro_sort::intro_sort_partial;
+/// let mut v = vec![3.0, 1.0, 4.0, 1.5, 5.0];
+/// intro_sort_partial(&mut v, f64::partial_cmp);
+/// assert_eq!(v, vec![1.0, 1.5, 3.0, 4.0, 5.0]);
+/// ```
+pub fn intro_sort_partial<T, F>(slice: &mut [T], mut comp: F)
+where
+    F: FnMut(&T, &T) -> Option<std::cmp::Ordering>,
+{
+    let len = slice.len();
+    if len == 0 {
+        return;
+    }
+    let max_depth = (usize::ilog2(len)) << 1;
+
+    // no consistency check, incomparable pairs break transitivity
+    let mut total_comp = |a: &T, b: &T| comp(a, b).unwrap_or(std::cmp::Ordering::Equal);
+    intro_recurse_sort_by(slice, &mut total_comp, max_depth, true, true);
+}
+
+/// # Description
+/// Error returned by [`intro_sort_with_budget`] when the comparison budget is exhausted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BudgetExceeded;
+
+impl std::fmt::Display for BudgetExceeded {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "comparison budget exceeded")
+    }
+}
+
+impl std::error::Error for BudgetExceeded {}
+
+/// # Description
+/// Sorts the given slice in-place using a intro‑sort algorithm
+/// with comparator, calling it at most `max_comparisons` times.
+///
+/// Once the budget is exhausted, `comp` is not called anymore and every remaining comparison
+/// is treated as equal, so the sort finishes quickly without the untrusted comparator.
+/// In that case the slice is left as a permutation of the input in unspecified order.
+///
+/// # Type Parameters
+/// - `T`: The element type.
+/// - `F`: The comparator type. Must implement 'FnMut' trait.
+///
+/// # Parameters
+/// - `slice`: The mutable slice to sort.
+/// - `comp`: The callable object to compare two &T data.
+/// - `max_comparisons`: The maximum number of calls to `comp`.
+///
+/// # Errors
+/// Returns [`BudgetExceeded`] if sorting needs more than `max_comparisons` comparisons.
+///
+/// # Panics
+/// Panics if calculating partition indices overflows (only for very large slices).
+/// Panics if the implementation of 'comp' panics.
+///
+/// # Examples
+/// ```
+/// use intro_sort::{intro_sort_with_budget, BudgetExceeded};
+/// let mut v = vec![3, 1, 4, 1, 5];
+/// assert_eq!(intro_sort_with_budget(&mut v, i32::cmp, 100), Ok(()));
+/// assert_eq!(v, vec![1, 1, 3, 4, 5]);
+///
+/// let mut v: Vec<i32> = (0..1000).rev().collect();
+/// assert_eq!(intro_sort_with_budget(&mut v, i32::cmp, 10), Err(BudgetExceeded));
+/// ```
+pub fn intro_sort_with_budget<T, F>(
+    slice: &mut [T],
+    mut comp: F,
+    max_comparisons: usize,
+) -> Result<(), BudgetExceeded>
+where
+    F: FnMut(&T, &T) -> std::cmp::Ordering,
+{
+    let len = slice.len();
+    if len == 0 {
+        return Ok(());
+    }
+    let max_depth = (usize::ilog2(len)) << 1;
+
+    let mut remaining = max_comparisons;
+    let mut exceeded = false;
+    let mut counted_comp = |a: &T, b: &T| {
+        if remaining == 0 {
+            exceeded = true;
+            return std::cmp::Ordering::Equal;
+        }
+        remaining -= 1;
+        comp(a, b)
+    };
+    intro_recurse_sort_by(slice, &mut counted_comp, max_depth, true, true);
+
+    match exceeded {
+        true => Err(BudgetExceeded),
+        false => Ok(()),
+    }
 }
 
-fn intro_recurse_sort_by<T, F>(slice: &mut [T], comp: &mut F, max_depth: u32)
+/// # Description
+/// Error returned by [`intro_sort_checked_by`] when the output is not sorted by the comparator.
+/// `index` is the first position where `comp(&slice[index], &slice[index + 1])` is `Greater`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnsortedError {
+    pub index: usize,
+}
+
+impl std::fmt::Display for UnsortedError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "slice is not sorted at index {}", self.index)
+    }
+}
+
+impl std::error::Error for UnsortedError {}
+
+/// # Description
+/// Sorts the given slice in-place using a intro‑sort algorithm
+/// with comparator, and verifies the result.
+///
+/// Unlike [`intro_sort_by`], an inconsistent comparator is reported by the return value
+/// in every build, instead of the debug-only assertion.
+/// The verification costs `len - 1` more comparisons.
+///
+/// # Type Parameters
+/// - `T`: The element type.
+/// - `F`: The comparator type. Must implement 'FnMut' trait.
+///
+/// # Parameters
+/// - `slice`: The mutable slice to sort.
+/// - `comp`: The callable object to compare two &T data.
+///
+/// # Errors
+/// Returns [`UnsortedError`] with the first offending index if the slice is not sorted by `comp`,
+/// which means `comp` is not a consistent total order.
+/// The slice is still a permutation of the input.
+///
+/// # Panics
+/// Panics if calculating partition indices overflows (only for very large slices).
+/// Panics if the implementation of 'comp' panics.
+///
+/// # Examples
+/// ```
+/// use intro_sort::intro_sort_checked_by;
+/// let mut v = vec![3, 1, 4, 1, 5];
+/// assert_eq!(intro_sort_checked_by(&mut v, i32::cmp), Ok(()));
+/// assert_eq!(v, vec![1, 1, 3, 4, 5]);
+/// ```
+pub fn intro_sort_checked_by<T, F>(slice: &mut [T], mut comp: F) -> Result<(), UnsortedError>
 where
     F: FnMut(&T, &T) -> std::cmp::Ordering,
 {
-    if slice.len() < 16 {
-        return insertion_sort_by(slice, comp);
+    let len = slice.len();
+    if len == 0 {
+        return Ok(());
+    }
+    let max_depth = (usize::ilog2(len)) << 1;
+    intro_recurse_sort_by(slice, &mut comp, max_depth, true, true);
+
+    match slice
+        .windows(2)
+        .position(|pair| comp(&pair[0], &pair[1]).is_gt())
+    {
+        Some(index) => Err(UnsortedError { index }),
+        None => Ok(()),
+    }
+}
+
+/// # Description
+/// Reorders the given slice so that `slice[k]` is the element which would be at `k` if the slice were sorted,
+/// using an iterative intro‑select algorithm.
+/// Every element of `slice[..k]` is less or equal, and every element of `slice[k + 1..]` is greater or equal.
+///
+/// # Type Parameters
+/// - `T`: The element type. Must implement `Ord`.
+///
+/// # Parameters
+/// - `slice`: The mutable slice to reorder.
+/// - `k`: The index of the element to select.
+///
+/// # Panics
+/// Panics if `k` is not less than the length of the slice.
+/// Panics if the implementation of Ord panics.
+///
+/// # Examples
+/// ```
+/// use intro_sort::intro_select_iterative;
+/// let mut v = vec![9, 1, 8, 2, 7, 3];
+/// intro_select_iterative(&mut v, 2);
+/// assert_eq!(v[2], 3);
+/// assert!(v[..2].iter().all(|&x| x <= 3));
+/// assert!(v[3..].iter().all(|&x| x >= 3));
+/// ```
+pub fn intro_select_iterative<T: Ord>(slice: &mut [T], k: usize) {
+    intro_select_iterative_by(slice, k, T::cmp)
+}
+
+/// # Description
+/// Reorders the given slice so that `slice[k]` is the element which would be at `k` if the slice were sorted,
+/// using an iterative intro‑select algorithm whith comparator.
+///
+/// The active range around `k` is narrowed by a loop, with the three-way partition around
+/// the median of three or the ninther, so the main loop takes O(1) stack.
+/// After `2 * log2(n)` partitions, the pivot is chosen by the median of medians,
+/// which guarantees that each partition removes at least 3/10 of the range.
+/// Finding the median of medians selects among a fifth of the range,
+/// so the fallback takes O(log n) stack at most.
+///
+/// # Type Parameters
+/// - `T`: The element type.
+/// - `F`: The comparator type. Must implement 'FnMut' trait.
+///
+/// # Parameters
+/// - `slice`: The mutable slice to reorder.
+/// - `k`: The index of the element to select.
+/// - `comp`: The callable object to compare two &T data.
+///
+/// # Panics
+/// Panics if `k` is not less than the length of the slice.
+/// Panics if the implementation of 'comp' panics.
+///
+/// # Performance
+/// Time complexity(worst) : O(n)
+/// Space complexity(worst) : O(log n) stack, O(1) before the fallback
+///
+/// # Examples
+/// ```
+/// use intro_sort::intro_select_iterative_by;
+/// let mut v = vec![9, 1, 8, 2, 7, 3];
+/// // the second largest
+/// intro_select_iterative_by(&mut v, 1, |a: &i32, b: &i32| b.cmp(a));
+/// assert_eq!(v[1], 8);
+/// ```
+pub fn intro_select_iterative_by<T, F>(slice: &mut [T], k: usize, mut comp: F)
+where
+    F: FnMut(&T, &T) -> std::cmp::Ordering,
+{
+    let len = slice.len();
+    assert!(
+        k < len,
+        "intro_select_iterative_by: k must be less than the length of the slice"
+    );
+    let max_depth = (usize::ilog2(len)) << 1;
+    intro_select_by(slice, k, &mut comp, max_depth);
+}
+
+/// # Description
+/// Iterative part of intro select.
+/// Narrows `[lo, hi)` around `k` until it is short enough for insertion sort,
+/// or `k` falls in the range of elements equal to the pivot.
+fn intro_select_by<T, F>(slice: &mut [T], k: usize, comp: &mut F, mut max_depth: u32)
+where
+    F: FnMut(&T, &T) -> std::cmp::Ordering,
+{
+    let (mut lo, mut hi) = (0, slice.len());
+    loop {
+        let range = &mut slice[lo..hi];
+        if range.len() < 16 {
+            insertion_sort_by(range, comp);
+            return;
+        }
+
+        let pivot = match max_depth {
+            0 => median_of_medians_by(range, comp),
+            _ => {
+                max_depth -= 1;
+                choose_pivot_by(range, comp)
+            }
+        };
+        range.swap(0, pivot);
+        let (less, equal, _) = ternary_partition_regions_by(range, comp);
+
+        let k = k - lo;
+        if k < less.end {
+            hi = lo + less.end;
+        } else if k < equal.end {
+            return;
+        } else {
+            lo += equal.end;
+        }
+    }
+}
+
+/// # Description
+/// Returns the index of the median of medians of groups of five, for the pivot of intro select.
+/// The median of each group is moved to the front, and the median among them is selected in place.
+/// At least 3/10 of the slice is on each side of the returned element.
+fn median_of_medians_by<T, F>(slice: &mut [T], comp: &mut F) -> usize
+where
+    F: FnMut(&T, &T) -> std::cmp::Ordering,
+{
+    #[cfg(test)]
+    tests::MEDIAN_OF_MEDIANS_CNT.with(|cnt| cnt.set(cnt.get() + 1));
+
+    let groups = slice.len() / 5;
+    for group in 0..groups {
+        let begin = group * 5;
+        insertion_sort_by(&mut slice[begin..begin + 5], &mut *comp);
+        // the front is made of the medians of the previous groups, so it is not overwritten
+        slice.swap(group, begin + 2);
+    }
+    intro_select_by(&mut slice[..groups], groups / 2, comp, 0);
+    groups / 2
+}
+
+/// # Description
+/// Recursive part of intro sort, with pattern-defeating ideas of pdqsort.
+/// - If the previous partition was balanced, the slice may be already sorted.
+///   Try partial insertion sort first, and short-circuit if it succeeds.
+/// - If the partition is highly unbalanced, the pivot selection is defeated by the pattern of input.
+///   Break the pattern by swapping a few elements in each part before the recursion.
+/// - If the recursion is too deep anyway, fallback to heap sort.
+///
+/// If `sort_small` is false, slices shorter than the threshold are left unsorted,
+/// and the caller must finish them with a single insertion sort over the whole slice.
+fn intro_recurse_sort_by<T, F>(
+    slice: &mut [T],
+    comp: &mut F,
+    max_depth: u32,
+    was_balanced: bool,
+    sort_small: bool,
+) where
+    F: FnMut(&T, &T) -> std::cmp::Ordering,
+{
+    let len = slice.len();
+    if len < 16 {
+        if sort_small {
+            insertion_sort_by(slice, comp);
+        }
+        return;
     } else if max_depth == 0 {
+        #[cfg(test)]
+        tests::HEAP_SORT_CNT.with(|cnt| cnt.set(cnt.get() + 1));
         return max_heap::heap_sort_by(slice, comp);
     }
 
+    // already sorted, or nearly sorted
+    if was_balanced && partial_insertion_sort_by(slice, comp) {
+        return;
+    }
+
     // quick sort
     // partition
     let (pivot1, pivot2) = ternary_partition_by(slice, comp);
+    let (left, mid, right) = split_three(slice, pivot1 - 1, pivot2);
+
+    // unbalanced if the other two parts are less than 1/8 of the slice
+    let is_balanced = left.len().max(mid.len()).max(right.len()) < len - len / 8;
+    if !is_balanced {
+        break_patterns(left);
+        break_patterns(mid);
+        break_patterns(right);
+    }
 
     // recurse
-    intro_recurse_sort_by(&mut slice[0..pivot1 - 1], comp, max_depth - 1);
-    intro_recurse_sort_by(&mut slice[pivot1..pivot2], comp, max_depth - 1);
-    intro_recurse_sort_by(&mut slice[pivot2 + 1..], comp, max_depth - 1);
+    intro_recurse_sort_by(left, comp, max_depth - 1, is_balanced, sort_small);
+    intro_recurse_sort_by(mid, comp, max_depth - 1, is_balanced, sort_small);
+    intro_recurse_sort_by(right, comp, max_depth - 1, is_balanced, sort_small);
+}
+
+/// # Description
+/// Sorts the slice by insertion sort, but gives up if there are too many misplaced elements.
+/// Returns true if the slice is sorted.
+///
+/// # Performance
+/// Time complexity(worst) : O(n)
+/// At most MAX_STEPS adjacent inversions are fixed, each of them shifts O(n) elements.
+fn partial_insertion_sort_by<T, F>(slice: &mut [T], comp: &mut F) -> bool
+where
+    F: FnMut(&T, &T) -> std::cmp::Ordering,
+{
+    // maximum number of adjacent out-of-order pairs that will get shifted
+    const MAX_STEPS: usize = 5;
+    // if the slice is shorter than this, don't shift any elements
+    const SHORTEST_SHIFTING: usize = 50;
+
+    let len = slice.len();
+    let mut i = 1;
+    for _ in 0..MAX_STEPS {
+        // find the next adjacent out-of-order pair
+        while i < len && comp(&slice[i - 1], &slice[i]).is_le() {
+            i += 1;
+        }
+        if i == len {
+            return true;
+        }
+        if len < SHORTEST_SHIFTING {
+            return false;
+        }
+
+        // swap the pair, and shift each of them to the right place
+        slice.swap(i - 1, i);
+        let mut j = i - 1;
+        while j > 0 && comp(&slice[j - 1], &slice[j]).is_gt() {
+            slice.swap(j - 1, j);
+            j -= 1;
+        }
+        let mut j = i;
+        while j + 1 < len && comp(&slice[j], &slice[j + 1]).is_gt() {
+            slice.swap(j, j + 1);
+            j += 1;
+        }
+    }
+    false
+}
+
+/// # Description
+/// Scatters some elements around in a deterministic way, to break the pattern of the input.
+/// The partition uses the first and the last element as pivots,
+/// so they are swapped with the elements at 1/4 and 3/4 of the slice.
+fn break_patterns<T>(slice: &mut [T]) {
+    let len = slice.len();
+    if len < 16 {
+        return;
+    }
+    let quarter = len / 4;
+    slice.swap(0, quarter);
+    slice.swap(len - 1, len - quarter);
+    if len > 128 {
+        slice.swap(1, quarter + 1);
+        slice.swap(2, quarter + 2);
+        slice.swap(len - 2, len - quarter - 1);
+        slice.swap(len - 3, len - quarter - 2);
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::*;
+    use std::cell::Cell;
     use std::cmp::Reverse;
 
     use rand::distr::StandardUniform;
@@ -119,4 +675,476 @@ mod tests {
 
         assert!(vec.is_sorted_by(|&a, &b| { a > b }));
     }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn test_intro_sort_by_inconsistent_comparator() {
+        test_data::check_inconsistent_comparator(intro_sort_by);
+    }
+
+    thread_local! {
+        /// number of heap sort fallback in the current thread
+        pub static HEAP_SORT_CNT: Cell<usize> = const { Cell::new(0) };
+        /// number of median of medians pivot in the current thread
+        pub static MEDIAN_OF_MEDIANS_CNT: Cell<usize> = const { Cell::new(0) };
+    }
+
+    #[test]
+    fn test_intro_sort_heap_sort_fallback() {
+        // recursion depth limit is already reached, so heap sort must be used.
+        let seed: u64 = 42;
+        let rng = StdRng::seed_from_u64(seed);
+
+        let mut vec: Vec<i32> = rng.sample_iter(StandardUniform).take(TEST_SIZE).collect();
+
+        HEAP_SORT_CNT.with(|cnt| cnt.set(0));
+        intro_recurse_sort_by(&mut vec, &mut i32::cmp, 0, false, true);
+
+        assert!(vec.is_sorted());
+        assert_eq!(HEAP_SORT_CNT.with(|cnt| cnt.get()), 1);
+    }
+
+    #[test]
+    fn test_intro_sort_worst_case_bound() {
+        // both pivots are the min and max of the slice on sorted input,
+        // so each partition only removes two elements and the recursion depth grows linearly.
+        // without the short-circuit and the heap sort fallback, this needs O(n^2) comparisons.
+        for len in [TEST_SIZE, TEST_SIZE + 1] {
+            let mut sorted: Vec<i32> = (0..len as i32).collect();
+            let mut with_outlier = sorted.clone();
+            with_outlier.swap(len / 2, len / 2 + 1);
+
+            for vec in [&mut sorted, &mut with_outlier] {
+                let mut comp_cnt = 0usize;
+                intro_sort_by(vec, |a: &i32, b: &i32| {
+                    comp_cnt += 1;
+                    a.cmp(b)
+                });
+
+                assert!(vec.is_sorted());
+                let log_len = usize::ilog2(len) as usize;
+                assert!(comp_cnt < 8 * len * log_len);
+            }
+        }
+    }
+
+    /// McIlroy's adversary ("A Killer Adversary for Quicksort"), adapted to the dual pivot partition.
+    /// Sorts indices with a comparator which decides the values lazily, and returns the decided values.
+    /// Undecided elements are "gas", bigger than every decided ("solid") element.
+    /// When two gas elements meet, the one which is not being partitioned is decided,
+    /// so the second pivot becomes small and the rest of the slice stays gas on one side.
+    /// Since intro sort is deterministic, sorting the returned values takes the same path.
+    /// Returns the values and the number of comparisons taken without the depth limit.
+    fn quick_sort_killer(len: usize) -> (Vec<i32>, usize) {
+        let gas = len;
+        let mut val = vec![gas; len];
+        // the first elements are decided in descending order up front,
+        // so partial insertion sort finds too many inversions and gives up
+        const DESCENDING_PREFIX: usize = 12;
+        for (i, v) in val.iter_mut().take(DESCENDING_PREFIX).enumerate() {
+            *v = DESCENDING_PREFIX - i - 1;
+        }
+        let mut solid = DESCENDING_PREFIX;
+        // the gas element last compared against a solid one, which is being partitioned
+        let mut candidate = usize::MAX;
+        let mut comp_cnt = 0usize;
+
+        // without the depth limit, the adversary keeps control until the end.
+        // intro_sort_by takes the same path on the returned values until it reaches the limit.
+        let mut ids: Vec<usize> = (0..len).collect();
+        let mut comp = |&x: &usize, &y: &usize| {
+            comp_cnt += 1;
+            if val[x] == gas && val[y] == gas {
+                let frozen = if x == candidate { y } else { x };
+                val[frozen] = solid;
+                solid += 1;
+            }
+            if val[x] == gas {
+                candidate = x;
+            } else if val[y] == gas {
+                candidate = y;
+            }
+            val[x].cmp(&val[y])
+        };
+        intro_recurse_sort_by(&mut ids, &mut comp, u32::MAX, true, true);
+        (val.into_iter().map(|v| v as i32).collect(), comp_cnt)
+    }
+
+    #[test]
+    fn test_intro_sort_adversarial() {
+        // the worst case of intro sort is O(n log n), not O(n^2), because of the heap sort fallback
+        let len = TEST_SIZE;
+        let (mut vec, unlimited_comp_cnt) = quick_sort_killer(len);
+        let mut expected = vec.clone();
+        expected.sort();
+
+        // without the depth limit, the input takes O(n^2) comparisons
+        assert!(unlimited_comp_cnt > len * len / 8);
+
+        HEAP_SORT_CNT.with(|cnt| cnt.set(0));
+        let mut comp_cnt = 0usize;
+        intro_sort_by(&mut vec, |a: &i32, b: &i32| {
+            comp_cnt += 1;
+            a.cmp(b)
+        });
+        assert_eq!(vec, expected);
+        // the pivots are defeated until the recursion depth limit
+        assert!(HEAP_SORT_CNT.with(|cnt| cnt.get()) > 0);
+        let log_len = usize::ilog2(len) as usize;
+        assert!(comp_cnt < 8 * len * log_len);
+    }
+
+    fn check_selected(vec: &[i32], expected: &[i32], k: usize) {
+        assert_eq!(vec[k], expected[k]);
+        assert!(vec[..k].iter().all(|x| *x <= vec[k]));
+        assert!(vec[k + 1..].iter().all(|x| *x >= vec[k]));
+    }
+
+    #[test]
+    fn test_intro_select_iterative() {
+        let seed: u64 = 42;
+        let mut rng = StdRng::seed_from_u64(seed);
+
+        for len in [1, 2, 15, 16, 17, 100, TEST_SIZE] {
+            // random, and many duplicates
+            let random: Vec<i32> = (0..len).map(|_| rng.random()).collect();
+            let duplicates: Vec<i32> = (0..len).map(|_| rng.random_range(0..8)).collect();
+            for source in [random, duplicates] {
+                let mut expected = source.clone();
+                expected.sort();
+                for k in [0, len / 3, len / 2, len - 1] {
+                    let mut vec = source.clone();
+                    intro_select_iterative(&mut vec, k);
+                    check_selected(&vec, &expected, k);
+                }
+            }
+        }
+
+        // custom comparator
+        let mut vec: Vec<i32> = (0..100).collect();
+        intro_select_iterative_by(&mut vec, 10, |a: &i32, b: &i32| b.cmp(a));
+        assert_eq!(vec[10], 89);
+    }
+
+    #[test]
+    #[should_panic(expected = "k must be less than the length of the slice")]
+    fn test_intro_select_iterative_out_of_range() {
+        intro_select_iterative(&mut [1, 2, 3], 3);
+    }
+
+    #[test]
+    fn test_intro_select_median_of_medians() {
+        // recursion depth limit is already reached, so median of medians must be used.
+        let seed: u64 = 42;
+        let rng = StdRng::seed_from_u64(seed);
+        let source: Vec<i32> = rng.sample_iter(StandardUniform).take(TEST_SIZE).collect();
+        let mut expected = source.clone();
+        expected.sort();
+
+        for k in [0, 1234, TEST_SIZE / 2, TEST_SIZE - 1] {
+            let mut vec = source.clone();
+            MEDIAN_OF_MEDIANS_CNT.with(|cnt| cnt.set(0));
+            let mut comp_cnt = 0usize;
+            intro_select_by(
+                &mut vec,
+                k,
+                &mut |a: &i32, b: &i32| {
+                    comp_cnt += 1;
+                    a.cmp(b)
+                },
+                0,
+            );
+            check_selected(&vec, &expected, k);
+            assert!(MEDIAN_OF_MEDIANS_CNT.with(|cnt| cnt.get()) > 0);
+            // linear, with the large constant of median of medians
+            assert!(comp_cnt < 30 * TEST_SIZE);
+        }
+    }
+
+    /// McIlroy's adversary against intro select, as in `quick_sort_killer`.
+    /// Returns the values decided while selecting `k` without the depth limit.
+    fn quick_select_killer(len: usize, k: usize) -> (Vec<i32>, usize) {
+        let gas = len;
+        let mut val = vec![gas; len];
+        let mut solid = 0;
+        let mut candidate = usize::MAX;
+        let mut comp_cnt = 0usize;
+
+        let mut ids: Vec<usize> = (0..len).collect();
+        let mut comp = |&x: &usize, &y: &usize| {
+            comp_cnt += 1;
+            if val[x] == gas && val[y] == gas {
+                let frozen = if x == candidate { x } else { y };
+                val[frozen] = solid;
+                solid += 1;
+            }
+            if val[x] == gas {
+                candidate = x;
+            } else if val[y] == gas {
+                candidate = y;
+            }
+            val[x].cmp(&val[y])
+        };
+        intro_select_by(&mut ids, k, &mut comp, u32::MAX);
+        (val.into_iter().map(|v| v as i32).collect(), comp_cnt)
+    }
+
+    #[test]
+    fn test_intro_select_adversarial() {
+        let len = TEST_SIZE;
+        let k = len / 2;
+        // without the depth limit, each partition removes only a few elements,
+        // which is O(n^2) comparisons, and as deep as O(n) for a recursive quick select
+        let (source, unlimited_comp_cnt) = quick_select_killer(len, k);
+        assert!(unlimited_comp_cnt > len * len / 32);
+        let mut expected = source.clone();
+        expected.sort();
+
+        let mut vec = source;
+        MEDIAN_OF_MEDIANS_CNT.with(|cnt| cnt.set(0));
+        let mut comp_cnt = 0usize;
+        intro_select_iterative_by(&mut vec, k, |a: &i32, b: &i32| {
+            comp_cnt += 1;
+            a.cmp(b)
+        });
+        check_selected(&vec, &expected, k);
+        assert!(MEDIAN_OF_MEDIANS_CNT.with(|cnt| cnt.get()) > 0);
+        assert!(comp_cnt < 50 * len);
+
+        // large patterned input
+        let len = 1_000_000;
+        let patterns: Vec<Vec<i32>> = vec![
+            (0..len as i32).collect(),
+            (0..len as i32).rev().collect(),
+            (0..len as i32).map(|x| x.min(len as i32 - x)).collect(),
+            (0..len as i32).map(|x| x % 64).collect(),
+        ];
+        for source in patterns {
+            let mut expected = source.clone();
+            expected.sort();
+            for k in [0, len / 2, len - 1] {
+                let mut vec = source.clone();
+                intro_select_iterative(&mut vec, k);
+                check_selected(&vec, &expected, k);
+            }
+        }
+    }
+
+    #[test]
+    fn test_intro_sort_patterns() {
+        test_data::check_sort(intro_sort);
+        test_data::check_sort(|slice| intro_sort_by(slice, |a, b| a.cmp(b)));
+    }
+
+    #[test]
+    fn test_partial_insertion_sort() {
+        // nearly sorted, fixed by a few shifts
+        let mut vec: Vec<i32> = (0..100).collect();
+        vec.swap(10, 20);
+        vec.swap(50, 90);
+        assert!(partial_insertion_sort_by(&mut vec, &mut i32::cmp));
+        assert!(vec.is_sorted());
+
+        // too many misplaced elements, gives up
+        let mut vec: Vec<i32> = (0..100).rev().collect();
+        assert!(!partial_insertion_sort_by(&mut vec, &mut i32::cmp));
+
+        // too short to shift
+        let mut vec: Vec<i32> = vec![1, 0, 2];
+        assert!(!partial_insertion_sort_by(&mut vec, &mut i32::cmp));
+        assert_eq!(vec, vec![1, 0, 2]);
+    }
+
+    #[test]
+    fn test_break_patterns() {
+        let mut vec: Vec<i32> = (0..200).collect();
+        break_patterns(&mut vec);
+        assert!(!vec.is_sorted());
+        vec.sort();
+        assert_eq!(vec, (0..200).collect::<Vec<i32>>());
+    }
+
+    #[test]
+    fn test_intro_sort_extreme_pivots() {
+        // both pivots are equal, every element goes to the left part
+        let mut all_equal = vec![7; 1000];
+        intro_sort(&mut all_equal);
+        assert_eq!(all_equal, vec![7; 1000]);
+
+        // pivot 1 stays at index 0, and pivot 2 stays at index len - 1
+        let mut extremes: Vec<i32> = (0..1000).map(|x| (x * 37) % 10).collect();
+        extremes[0] = -1;
+        extremes[999] = 100;
+        let mut expected = extremes.clone();
+        expected.sort();
+        intro_sort(&mut extremes);
+        assert_eq!(extremes, expected);
+    }
+
+    #[test]
+    fn test_intro_sort_with_budget() {
+        let seed: u64 = 42;
+        let rng = StdRng::seed_from_u64(seed);
+        let vec: Vec<i32> = rng.sample_iter(StandardUniform).take(TEST_SIZE).collect();
+
+        // enough budget
+        let mut sorted = vec.clone();
+        assert_eq!(
+            intro_sort_with_budget(&mut sorted, i32::cmp, usize::MAX),
+            Ok(())
+        );
+        assert!(sorted.is_sorted());
+
+        // tiny budget, the comparator is called at most 100 times
+        let mut strings: Vec<String> = vec.iter().map(|x| x.to_string()).collect();
+        let mut calls = 0;
+        let result = intro_sort_with_budget(
+            &mut strings,
+            |a, b| {
+                calls += 1;
+                a.cmp(b)
+            },
+            100,
+        );
+        assert_eq!(result, Err(BudgetExceeded));
+        assert_eq!(calls, 100);
+
+        // still a permutation of the input
+        let mut expected: Vec<String> = vec.iter().map(|x| x.to_string()).collect();
+        expected.sort();
+        strings.sort();
+        assert_eq!(strings, expected);
+    }
+
+    #[test]
+    fn test_intro_sort_checked_by() {
+        let seed: u64 = 42;
+        let rng = StdRng::seed_from_u64(seed);
+        let mut vec: Vec<i32> = rng.sample_iter(StandardUniform).take(TEST_SIZE).collect();
+        assert_eq!(intro_sort_checked_by(&mut vec, i32::cmp), Ok(()));
+        assert!(vec.is_sorted());
+
+        // every distinct pair is greater in both directions, not a total order
+        let mut vec: Vec<i32> = (0..100).map(|x| (x * 37) % 100).collect();
+        let result = intro_sort_checked_by(&mut vec, |a, b| match a == b {
+            true => std::cmp::Ordering::Equal,
+            false => std::cmp::Ordering::Greater,
+        });
+        let Err(UnsortedError { index }) = result else {
+            panic!("inconsistent comparator is not detected");
+        };
+        assert_eq!(index, 0);
+
+        // still a permutation of the input
+        vec.sort();
+        assert_eq!(vec, (0..100).collect::<Vec<i32>>());
+    }
+
+    // NaN != NaN, so compare bit patterns
+    fn to_bits(slice: &[f64]) -> Vec<u64> {
+        slice.iter().map(|x| x.to_bits()).collect()
+    }
+
+    #[test]
+    fn test_intro_sort_partial() {
+        // all comparable
+        let seed: u64 = 42;
+        let rng = StdRng::seed_from_u64(seed);
+        let mut vec: Vec<f64> = rng.sample_iter(StandardUniform).take(TEST_SIZE).collect();
+        intro_sort_partial(&mut vec, f64::partial_cmp);
+        assert!(vec.is_sorted());
+
+        // NaN is not swapped with any element
+        let mut vec = vec![3.0, f64::NAN, 1.0, 2.0];
+        intro_sort_partial(&mut vec, f64::partial_cmp);
+        assert_eq!(vec[0], 3.0);
+        assert!(vec[1].is_nan());
+        assert_eq!(vec[2..], [1.0, 2.0]);
+
+        // deterministic layout, and a permutation of the input
+        let input: Vec<f64> = (0..1000)
+            .map(|x| match x % 97 {
+                0 => f64::NAN,
+                r => ((r * 31) % 97) as f64,
+            })
+            .collect();
+        let mut first = input.clone();
+        let mut second = input.clone();
+        intro_sort_partial(&mut first, f64::partial_cmp);
+        intro_sort_partial(&mut second, f64::partial_cmp);
+        assert_eq!(to_bits(&first), to_bits(&second));
+
+        let mut sorted_input = to_bits(&input);
+        let mut sorted_first = to_bits(&first);
+        sorted_input.sort();
+        sorted_first.sort();
+        assert_eq!(sorted_input, sorted_first);
+    }
+
+    #[test]
+    fn test_intro_sort_by_stateful_comparator() {
+        use std::cmp::Ordering;
+        use std::collections::HashMap;
+
+        // shuffled permutation of 0..len, so the recursion reaches all three parts
+        let len = 2000;
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut vec: Vec<u32> = (0..len).collect();
+        for i in (1..vec.len()).rev() {
+            vec.swap(i, rng.random_range(0..=i));
+        }
+
+        // memoization cache, must be shared by every recursive call
+        let mut cache: HashMap<(u32, u32), Ordering> = HashMap::new();
+        let mut calls = 0usize;
+        intro_sort_by(&mut vec, |a: &u32, b: &u32| {
+            calls += 1;
+            *cache.entry((*a, *b)).or_insert_with(|| a.cmp(b))
+        });
+
+        assert_eq!(vec, (0..len).collect::<Vec<u32>>());
+        assert!(cache.len() <= calls);
+        // pairs within the lowest, middle and highest values are compared only in deep recursion
+        // of the left, middle and right parts, and they all reached the same cache.
+        let within = |lo: u32, hi: u32| {
+            cache
+                .keys()
+                .any(|&(a, b)| a != b && (lo..hi).contains(&a) && (lo..hi).contains(&b))
+        };
+        assert!(within(0, len / 10));
+        assert!(within(len * 9 / 20, len * 11 / 20));
+        assert!(within(len * 9 / 10, len));
+    }
+
+    #[test]
+    fn test_intro_sort_desc() {
+        let mut vec: Vec<i32> = (0..1000).map(|i| (i * 7919) % 251 - 100).collect();
+        let mut expected = vec.clone();
+        expected.sort_by(|a, b| b.cmp(a));
+        intro_sort_desc(&mut vec);
+        assert_eq!(vec, expected);
+
+        let mut empty: Vec<i32> = vec![];
+        intro_sort_desc(&mut empty);
+    }
+
+    #[test]
+    fn test_intro_sort_final_insertion() {
+        test_data::check_sort(intro_sort_final_insertion);
+
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut vec: Vec<i32> = (0..TEST_SIZE).map(|_| rng.random()).collect();
+        let mut expected = vec.clone();
+        expected.sort_by(|a, b| b.cmp(a));
+        intro_sort_final_insertion_by(&mut vec, |a: &i32, b: &i32| b.cmp(a));
+        assert_eq!(vec, expected);
+
+        // the heap sort fallback leaves sorted subarrays, the final pass must keep them
+        let mut vec: Vec<i32> = (0..TEST_SIZE).map(|_| rng.random_range(0..100)).collect();
+        let mut expected = vec.clone();
+        expected.sort();
+        intro_recurse_sort_by(&mut vec, &mut i32::cmp, 1, false, false);
+        insertion_sort_by(&mut vec, i32::cmp);
+        assert_eq!(vec, expected);
+    }
 }
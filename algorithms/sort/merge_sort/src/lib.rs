@@ -1,7 +1,6 @@
 //! # Description
 //! Implementation of merge-sort algorithm.
 
-use std::alloc::{Layout, alloc, dealloc};
 use std::ptr::{copy_nonoverlapping, write};
 
 /// # Description
@@ -19,9 +18,10 @@ use std::ptr::{copy_nonoverlapping, write};
 ///
 /// # Safety
 /// This function is safe because it restore all of data at once.
-/// Despite of the panic, there are no occurrence of duplicated ownership.
-///
-/// But, this function allocates internal memory, so there can be a leak.
+/// Elements are only bitwise copied to the internal buffer during a pass,
+/// and written back to the slice after the whole pass.
+/// So if the comparison panics, the slice still owns every element exactly once,
+/// in the order of the previous pass, and the buffer is freed without dropping any element.
 ///
 /// # Examples
 /// ```
@@ -31,7 +31,66 @@ use std::ptr::{copy_nonoverlapping, write};
 /// assert_eq!(v, vec![1, 1, 3, 4, 5]);
 /// ```
 pub fn merge_sort<T: Ord>(slice: &mut [T]) {
-	merge_sort_by(slice, T::cmp);
+    merge_sort_by(slice, T::cmp);
+}
+
+/// # Description
+/// Sorts the given slice stable using a non-recursive merge‑sort algorithm in descending order.
+/// Same as `merge_sort_by` with `|a, b| b.cmp(a)`, without spelling out `Reverse`.
+/// Equal elements keep their original order, as in ascending order.
+///
+/// # Type Parameters
+/// - `T`: The element type. Must implement `Ord`.
+///
+/// # Parameters
+/// - `slice`: The mutable slice to sort.
+///
+/// # Panics
+/// Panics if calculating partition indices overflows (only for very large slices).
+/// Panics if the implementation of Ord panics.
+///
+/// # Examples
+/// ```
+/// use merge_sort::*;
+/// let mut v = vec![3, 1, 4, 1, 5];
+/// merge_sort_desc(&mut v);
+/// assert_eq!(v, vec![5, 4, 3, 1, 1]);
+/// ```
+pub fn merge_sort_desc<T: Ord>(slice: &mut [T]) {
+    merge_sort_by(slice, |a, b| b.cmp(a));
+}
+
+/// # Description
+/// Sorts the given slice stable using a non-recursive merge‑sort algorithm in descending order of the key.
+/// Same as `merge_sort_by` with `|a, b| key_fn(b).cmp(&key_fn(a))`.
+/// Elements with equal keys keep their original order, unlike sorting ascending and reversing the result.
+///
+/// # Type Parameters
+/// - `T`: The element type.
+/// - `K`: The key type. Must implement `Ord`.
+/// - `F`: The key extraction function type. Must implement 'FnMut' trait.
+///
+/// # Parameters
+/// - `slice`: The mutable slice to sort.
+/// - `key_fn`: The callable object to extract the key of &T data. It is called twice per comparison.
+///
+/// # Panics
+/// Panics if calculating partition indices overflows (only for very large slices).
+/// Panics if the implementation of `key_fn` or Ord of K panics.
+///
+/// # Examples
+/// ```
+/// use merge_sort::*;
+/// let mut v = vec![(1, 'a'), (3, 'b'), (1, 'c'), (3, 'd')];
+/// merge_sort_by_key_desc(&mut v, |x| x.0);
+/// assert_eq!(v, vec![(3, 'b'), (3, 'd'), (1, 'a'), (1, 'c')]);
+/// ```
+pub fn merge_sort_by_key_desc<T, K, F>(slice: &mut [T], mut key_fn: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    merge_sort_by(slice, |a, b| key_fn(b).cmp(&key_fn(a)));
 }
 
 /// # Description
@@ -48,12 +107,20 @@ pub fn merge_sort<T: Ord>(slice: &mut [T]) {
 /// # Panics
 /// Panics if calculating partition indices overflows (only for very large slices).
 /// Panics if the implementation of Ord panics.
+/// In debug builds, panics if `comp` is not a consistent total order.
+///
+/// # Already ordered runs
+/// Before merging two adjacent runs, the last element of the left run is compared with
+/// the first element of the right run. If it is not greater, the runs are already in order
+/// and they are copied without merging, so sorted or nearly sorted input takes
+/// about one comparison per pair of runs.
 ///
 /// # Safety
 /// This function is safe because it restore all of data at once.
-/// Despite of the panic, there are no occurence of duplicated ownership.
-///
-/// But, this function allocates internal memeory, so there can be a leak.
+/// Elements are only bitwise copied to the internal buffer during a pass,
+/// and written back to the slice after the whole pass.
+/// So if the comparison panics, the slice still owns every element exactly once,
+/// in the order of the previous pass, and the buffer is freed without dropping any element.
 ///
 /// # Examples
 /// ```
@@ -63,6 +130,104 @@ pub fn merge_sort<T: Ord>(slice: &mut [T]) {
 /// assert_eq!(v, vec![1, 1, 3, 4, 5]);
 /// ```
 pub fn merge_sort_by<T, F>(slice: &mut [T], mut comp: F)
+where
+    F: FnMut(&T, &T) -> std::cmp::Ordering,
+{
+    merge_sort_moves_by(slice, &mut comp);
+
+    debug_assert!(
+        slice.is_sorted_by(|a, b| comp(a, b).is_le()),
+        "merge_sort_by: comparator is not a consistent total order"
+    );
+}
+
+/// # Description
+/// Sorts the given slice stable by counting the number of element moves.
+///
+/// Each pass writes every merged element to the buffer once and back to the slice once,
+/// whether the runs were merged or only copied because they were already in order.
+/// The tail run without a pair is left in place, and is not counted.
+/// So the count depends only on the length, about `2 * len * ceil(log2(len))`.
+///
+/// # Type Parameters
+/// - `T`: The element type. Must implement `Ord`.
+///
+/// # Parameters
+/// - `slice`: The mutable slice to sort.
+///
+/// # Returns
+/// The number of element writes to the buffer and to the slice.
+///
+/// # Panics
+/// Panics if the implementation of Ord panics.
+///
+/// # Examples
+/// ```
+/// use merge_sort::*;
+/// let mut v = vec![3, 1, 4, 1];
+/// // two passes, each moves 4 elements to the buffer and back
+/// assert_eq!(merge_sort_counting_moves(&mut v), 16);
+/// assert_eq!(v, vec![1, 1, 3, 4]);
+/// ```
+pub fn merge_sort_counting_moves<T: Ord>(slice: &mut [T]) -> usize {
+    merge_sort_moves_by(slice, &mut T::cmp)
+}
+
+/// # Description
+/// Sorts the given slice stable with comparator, and returns the number of comparisons.
+///
+/// This is the same sort as `merge_sort_by`, and every call of `comp` during the sort is counted,
+/// including the check of each pair of runs whether they are already in order.
+/// So it takes at most about `n log2 n` comparisons, and exactly `n - 1` for already sorted input,
+/// since every pair of runs is found in order by one comparison and copied without merging.
+/// The comparisons of the debug build check after the sort are not counted.
+///
+/// # Type Parameters
+/// - `T`: The element type.
+/// - `F`: type of comparator. Must implement 'FnMut'
+///
+/// # Parameters
+/// - `slice`: The mutable slice to sort.
+/// - `comp`: The callable object to compare two data of type T.
+///
+/// # Returns
+/// The number of calls of `comp` during the sort.
+///
+/// # Panics
+/// Panics if the comparator panics.
+/// In debug builds, panics if `comp` is not a consistent total order.
+///
+/// # Examples
+/// ```
+/// use merge_sort::*;
+/// let mut v = vec![4, 3, 2, 1];
+/// assert_eq!(merge_sort_by_counting_comparisons(&mut v, |a, b| a.cmp(b)), 7);
+/// assert_eq!(v, vec![1, 2, 3, 4]);
+///
+/// // one comparison per pair of runs
+/// assert_eq!(merge_sort_by_counting_comparisons(&mut v, |a, b| a.cmp(b)), 3);
+/// ```
+pub fn merge_sort_by_counting_comparisons<T, F>(slice: &mut [T], mut comp: F) -> u64
+where
+    F: FnMut(&T, &T) -> std::cmp::Ordering,
+{
+    let mut comparisons = 0u64;
+    merge_sort_moves_by(slice, &mut |a: &T, b: &T| {
+        comparisons += 1;
+        comp(a, b)
+    });
+
+    debug_assert!(
+        slice.is_sorted_by(|a, b| comp(a, b).is_le()),
+        "merge_sort_by_counting_comparisons: comparator is not a consistent total order"
+    );
+    comparisons
+}
+
+/// # Description
+/// Actual implementation of merge_sort_by.
+/// Returns the number of element writes to the buffer and to the slice.
+fn merge_sort_moves_by<T, F>(slice: &mut [T], comp: &mut F) -> usize
 where
     F: FnMut(&T, &T) -> std::cmp::Ordering,
 {
@@ -70,19 +235,14 @@ where
     let len = slice.len();
     if len <= 1 {
         // already sorted
-        return;
+        return 0;
     }
+    let mut moves = 0;
 
     // buffer allocation
-    let merge_buffer;
-    let layout = Layout::array::<T>(len).unwrap();
-    unsafe {
-        merge_buffer = alloc(layout) as *mut T;
-    }
-    if merge_buffer.is_null() {
-        // allocation failed
-        panic!();
-    }
+    // the Vec keeps length 0, so it only frees the memory when it drops, even on panic
+    let mut buffer: Vec<T> = Vec::with_capacity(len);
+    let merge_buffer = buffer.as_mut_ptr();
 
     // merge sort, non-recursive
     let mut seg_size = 1;
@@ -98,80 +258,1250 @@ where
                 break;
             }
 
-            // merge two seg
-            let mut l = begin;
-            let mut r = mid;
-            // merge left and right to the cache
-            for i in begin..end {
+            if comp(&slice[mid - 1], &slice[mid]).is_le() {
+                // already in order, is_le keeps equal boundary elements in place for stability
                 unsafe {
-                    let next_val =
-                        match r == end || (l != mid && (comp(&slice[l], &slice[r]).is_le())) {
-                            true => {
-                                let tmp = (&slice[l] as *const T).read();
-                                l += 1;
-                                tmp
-                            }
-                            false => {
-                                let tmp = (&slice[r] as *const T).read();
-                                r += 1;
-                                tmp
-                            }
-                        };
-                    write(merge_buffer.add(i), next_val);
+                    copy_nonoverlapping(
+                        &slice[begin] as *const T,
+                        merge_buffer.add(begin),
+                        end - begin,
+                    );
+                }
+            } else {
+                // merge left and right to the cache
+                unsafe {
+                    gallop_merge_into(
+                        &slice[begin..end],
+                        mid - begin,
+                        merge_buffer.add(begin),
+                        comp,
+                    );
                 }
             }
             merge_start_pos += seg_size << 1;
         }
 
         // write back ordered seg from cache
+        let merged = merge_start_pos.min(len);
         unsafe {
-            copy_nonoverlapping(
-                merge_buffer,
-                &mut slice[0] as *mut T,
-                merge_start_pos.min(len),
-            );
+            copy_nonoverlapping(merge_buffer, &mut slice[0] as *mut T, merged);
         }
-        seg_size = seg_size << 1;
+        // to the buffer, and back to the slice
+        moves += merged * 2;
+        seg_size <<= 1;
     }
 
-    unsafe {
-        dealloc(merge_buffer as *mut u8, layout);
+    drop(buffer);
+    moves
+}
+
+/// number of consecutive elements taken from one run before galloping
+const MIN_GALLOP: usize = 7;
+
+/// # Description
+/// Finds the length of the longest prefix of `run` satisfying `pred`,
+/// by exponential search followed by binary search.
+/// `pred` must be true for a prefix and false for the rest.
+/// Takes O(log k) comparisons, where k is the result.
+fn gallop<T, P>(run: &[T], mut pred: P) -> usize
+where
+    P: FnMut(&T) -> bool,
+{
+    let mut hi = 1;
+    while hi <= run.len() && pred(&run[hi - 1]) {
+        hi <<= 1;
     }
+    // run[..hi / 2] satisfies pred
+    let lo = hi >> 1;
+    let hi = hi.min(run.len());
+    lo + run[lo..hi].partition_point(pred)
 }
 
-#[cfg(test)]
-mod tests {
+/// # Description
+/// Merges sorted `slice[..mid]` and `slice[mid..]` stable into `dst[..slice.len()]`.
+/// Elements are moved one at a time, but once one run wins MIN_GALLOP times in a row,
+/// the whole block of winning elements is found by galloping and copied at once.
+/// Nearly sorted or low entropy input has long blocks, which are copied without per element branches.
+///
+/// # Safety
+/// `dst` must be valid for writes of `slice.len()` elements and must not overlap `slice`.
+/// Elements are bitwise copied, so the caller must treat either `slice` or `dst` as the owner.
+unsafe fn gallop_merge_into<T, F>(slice: &[T], mid: usize, dst: *mut T, comp: &mut F)
+where
+    F: FnMut(&T, &T) -> std::cmp::Ordering,
+{
+    let end = slice.len();
+    let src = slice.as_ptr();
+    let (mut l, mut r, mut out) = (0, mid, 0);
+    let (mut l_wins, mut r_wins) = (0, 0);
+    unsafe {
+        while l < mid && r < end {
+            if comp(&slice[l], &slice[r]).is_le() {
+                write(dst.add(out), src.add(l).read());
+                l += 1;
+                l_wins += 1;
+                r_wins = 0;
+            } else {
+                write(dst.add(out), src.add(r).read());
+                r += 1;
+                r_wins += 1;
+                l_wins = 0;
+            }
+            out += 1;
 
-    use crate::*;
-    use std::cmp::Reverse;
+            if l_wins >= MIN_GALLOP && r < end {
+                // left elements not greater than slice[r] go first, for stability
+                let block = gallop(&slice[l..mid], |x| comp(x, &slice[r]).is_le());
+                copy_nonoverlapping(src.add(l), dst.add(out), block);
+                l += block;
+                out += block;
+                l_wins = 0;
+            } else if r_wins >= MIN_GALLOP && l < mid {
+                // right elements less than slice[l] go first, for stability
+                let block = gallop(&slice[r..end], |x| comp(x, &slice[l]).is_lt());
+                copy_nonoverlapping(src.add(r), dst.add(out), block);
+                r += block;
+                out += block;
+                r_wins = 0;
+            }
+        }
+        // rest of one run
+        copy_nonoverlapping(src.add(l), dst.add(out), mid - l);
+        out += mid - l;
+        copy_nonoverlapping(src.add(r), dst.add(out), end - r);
+    }
+}
 
-    use rand::distr::StandardUniform;
-    use rand::rngs::StdRng;
-    use rand::{Rng, SeedableRng};
+/// # Description
+/// Sorts the given slice stable using a natural merge-sort algorithm, which exploits presortedness.
+///
+/// # Type Parameters
+/// - `T`: The element type. Must implement `Ord`.
+///
+/// # Parameters
+/// - `slice`: The mutable slice to sort.
+///
+/// # Panics
+/// Panics if the implementation of Ord panics.
+///
+/// # Examples
+/// ```
+/// use merge_sort::*;
+/// let mut v = vec![1, 2, 3, 9, 8, 7, 4, 5, 6];
+/// natural_merge_sort(&mut v);
+/// assert_eq!(v, vec![1, 2, 3, 4, 5, 6, 7, 8, 9]);
+/// ```
+pub fn natural_merge_sort<T: Ord>(slice: &mut [T]) {
+    natural_merge_sort_by(slice, T::cmp);
+}
 
-    const TEST_SIZE: usize = 10_000;
+/// # Description
+/// Sorts the given slice stable using a natural merge-sort algorithm with comparator.
+///
+/// It first splits the slice into natural runs, which are maximal non-descending
+/// or strictly descending sequences. Descending runs are reversed in place, which keeps stableness
+/// because they contain no equal elements. Then adjacent runs are merged bottom-up by [`merge_halves_by`].
+///
+/// Therefore, the number of merge passes is `ceil(log2(runs))` instead of `ceil(log2(len))`.
+/// Already sorted (or reversed) input costs only `len - 1` comparisons and no merge.
+///
+/// # Type Parameters
+/// - `T`: The element type.
+/// - `F`: type of comparator. Must implement 'FnMut'
+///
+/// # Parameters
+/// - `slice`: The mutable slice to sort.
+/// - `comp`: The callable object to compare two data of type T.
+///
+/// # Panics
+/// Panics if the implementation of comp panics.
+/// In debug builds, panics if `comp` is not a consistent total order.
+///
+/// # Safety
+/// Runs are merged by [`merge_halves_by`], so there are no occurrence of duplicated ownership
+/// despite of the panic.
+///
+/// # Examples
+/// ```
+/// use merge_sort::*;
+/// let mut v = vec![1, 2, 3, 9, 8, 7, 4, 5, 6];
+/// natural_merge_sort_by(&mut v, |a, b| b.cmp(a));
+/// assert_eq!(v, vec![9, 8, 7, 6, 5, 4, 3, 2, 1]);
+/// ```
+pub fn natural_merge_sort_by<T, F>(slice: &mut [T], mut comp: F)
+where
+    F: FnMut(&T, &T) -> std::cmp::Ordering,
+{
+    let len = slice.len();
+    if len <= 1 {
+        // already sorted
+        return;
+    }
 
-    #[test]
-    fn test_merge_sort() {
-        let seed: u64 = 42;
-        let rng = StdRng::seed_from_u64(seed);
+    // run detection, runs[i]..runs[i + 1] is a sorted run
+    let mut runs = vec![0usize];
+    let mut start = 0;
+    while start < len {
+        let mut end = start + 1;
+        if end < len && comp(&slice[start], &slice[end]).is_gt() {
+            // strictly descending run
+            while end + 1 < len && comp(&slice[end], &slice[end + 1]).is_gt() {
+                end += 1;
+            }
+            end += 1;
+            slice[start..end].reverse();
+        } else {
+            // non-descending run, the first pair is already compared
+            end = (end + 1).min(len);
+            while end < len && comp(&slice[end - 1], &slice[end]).is_le() {
+                end += 1;
+            }
+        }
+        runs.push(end);
+        start = end;
+    }
 
-        let mut vec: Vec<i32> = rng.sample_iter(StandardUniform).take(TEST_SIZE).collect();
+    // merge adjacent runs, bottom-up
+    while runs.len() > 2 {
+        let mut merged = Vec::with_capacity(runs.len() / 2 + 1);
+        for pair in runs.windows(3).step_by(2) {
+            let (begin, mid, end) = (pair[0], pair[1], pair[2]);
+            merge_halves_by(&mut slice[begin..end], mid - begin, &mut comp);
+            merged.push(begin);
+        }
+        if runs.len() % 2 == 0 {
+            // odd number of runs, the last one is not merged in this pass
+            merged.push(runs[runs.len() - 2]);
+        }
+        merged.push(len);
+        runs = merged;
+    }
 
-        merge_sort(&mut vec);
+    debug_assert!(
+        slice.is_sorted_by(|a, b| comp(a, b).is_le()),
+        "natural_merge_sort_by: comparator is not a consistent total order"
+    );
+}
 
-        assert!(vec.is_sorted());
+/// # Description
+/// Sorts the given slice stable using a non-recursive merge‑sort algorithm with a half-size buffer.
+///
+/// # Type Parameters
+/// - `T`: The element type. Must implement `Ord`.
+///
+/// # Parameters
+/// - `slice`: The mutable slice to sort.
+///
+/// # Panics
+/// Panics if the implementation of Ord panics.
+///
+/// # Examples
+/// ```
+/// use merge_sort::*;
+/// let mut v = vec![3, 1, 4, 1, 5];
+/// merge_sort_half_buffer(&mut v);
+/// assert_eq!(v, vec![1, 1, 3, 4, 5]);
+/// ```
+pub fn merge_sort_half_buffer<T: Ord>(slice: &mut [T]) {
+    merge_sort_half_buffer_by(slice, T::cmp);
+}
+
+/// # Description
+/// Sorts the given slice stable using a non-recursive merge‑sort algorithm with comparator and a half-size buffer.
+///
+/// [`merge_sort_by`] merges into a buffer of `len` elements and copies the whole pass back.
+/// Instead, this function moves only the smaller run of each merge to the buffer,
+/// and merges it with the other run in place, like [`merge_halves_by`].
+/// The smaller run has at most `len / 2` elements, so the extra memory is halved.
+/// The buffer is allocated once and reused for every merge.
+///
+/// # Type Parameters
+/// - `T`: The element type.
+/// - `F`: type of comparator. Must implement 'FnMut'
+///
+/// # Parameters
+/// - `slice`: The mutable slice to sort.
+/// - `comp`: The callable object to compare two data of type T.
+///
+/// # Panics
+/// Panics if the implementation of comp panics.
+/// In debug builds, panics if `comp` is not a consistent total order.
+///
+/// # Safety
+/// If the comparison panics, the elements left in the buffer are written back to the slice.
+/// Therefore, there are no occurrence of duplicated ownership.
+///
+/// # Examples
+/// ```
+/// use merge_sort::*;
+/// let mut v = vec![3, 1, 4, 1, 5];
+/// merge_sort_half_buffer_by(&mut v, |a, b| b.cmp(a));
+/// assert_eq!(v, vec![5, 4, 3, 1, 1]);
+/// ```
+pub fn merge_sort_half_buffer_by<T, F>(slice: &mut [T], mut comp: F)
+where
+    F: FnMut(&T, &T) -> std::cmp::Ordering,
+{
+    let len = slice.len();
+    if len <= 1 {
+        // already sorted
+        return;
     }
 
-    #[test]
-    fn test_merge_sort_by() {
-        let seed: u64 = 42;
-        let rng = StdRng::seed_from_u64(seed);
+    // the smaller run of a merge is at most len / 2
+    let mut buffer: Vec<T> = Vec::with_capacity(len / 2);
 
-        let mut vec: Vec<i32> = rng.sample_iter(StandardUniform).take(TEST_SIZE).collect();
+    // merge sort, non-recursive
+    let mut seg_size = 1;
+    while seg_size < len {
+        let mut begin = 0;
+        while begin + seg_size < len {
+            let end = (begin + 2 * seg_size).min(len);
+            let run = &mut slice[begin..end];
+            if comp(&run[seg_size - 1], &run[seg_size]).is_gt() {
+                merge_with_buffer(run, seg_size, &mut buffer, &mut comp);
+            }
+            begin = end;
+        }
+        seg_size <<= 1;
+    }
 
-        merge_sort_by(&mut vec, |a: &i32, b: &i32| Reverse(a).cmp(&Reverse(b)));
+    debug_assert!(
+        slice.is_sorted_by(|a, b| comp(a, b).is_le()),
+        "merge_sort_half_buffer_by: comparator is not a consistent total order"
+    );
+}
 
-        assert!(vec.is_sorted_by(|&a, &b| { a > b }));
+/// # Description
+/// Merges the given slice stable, which is a concatenation of two sorted runs `[0, mid)` and `[mid, len)`.
+///
+/// # Type Parameters
+/// - `T`: The element type. Must implement `Ord`.
+///
+/// # Parameters
+/// - `slice`: The mutable slice to merge.
+/// - `mid`: The start position of the second sorted run.
+///
+/// # Panics
+/// Panics if `mid > slice.len()`.
+/// Panics if the implementation of Ord panics.
+///
+/// # Safety
+/// Only the smaller run is moved to the internal buffer, so it allocates `min(mid, len - mid)` elements.
+/// If the comparison panics, the elements left in the buffer are written back to the slice.
+/// Therefore, there are no occurrence of duplicated ownership.
+///
+/// # Examples
+/// ```
+/// use merge_sort::*;
+/// let mut v = vec![1, 4, 7, 2, 3, 5, 6];
+/// merge_halves(&mut v, 3);
+/// assert_eq!(v, vec![1, 2, 3, 4, 5, 6, 7]);
+/// ```
+pub fn merge_halves<T: Ord>(slice: &mut [T], mid: usize) {
+    merge_halves_by(slice, mid, T::cmp);
+}
+
+/// # Description
+/// Merges the given slice stable with comparator, which is a concatenation of two sorted runs `[0, mid)` and `[mid, len)`.
+///
+/// # Type Parameters
+/// - `T`: The element type.
+/// - `F`: type of comparator. Must implement 'FnMut'
+///
+/// # Parameters
+/// - `slice`: The mutable slice to merge.
+/// - `mid`: The start position of the second sorted run.
+/// - `comp`: The callable object to compare two data of type T.
+///
+/// # Panics
+/// Panics if `mid > slice.len()`.
+/// Panics if the implementation of comp panics.
+///
+/// # Safety
+/// Only the smaller run is moved to the internal buffer, so it allocates `min(mid, len - mid)` elements.
+/// If the comparison panics, the elements left in the buffer are written back to the slice.
+/// Therefore, there are no occurrence of duplicated ownership.
+///
+/// # Examples
+/// ```
+/// use merge_sort::*;
+/// let mut v = vec![7, 4, 1, 6, 5, 3, 2];
+/// merge_halves_by(&mut v, 3, |a, b| b.cmp(a));
+/// assert_eq!(v, vec![7, 6, 5, 4, 3, 2, 1]);
+/// ```
+pub fn merge_halves_by<T, F>(slice: &mut [T], mid: usize, mut comp: F)
+where
+    F: FnMut(&T, &T) -> std::cmp::Ordering,
+{
+    let len = slice.len();
+    assert!(mid <= len, "merge_halves_by: mid is out of range");
+    if mid == 0 || mid == len || comp(&slice[mid - 1], &slice[mid]).is_le() {
+        // already sorted
+        return;
+    }
+
+    // buffer allocation, only for the smaller run
+    let mut buffer: Vec<T> = Vec::with_capacity(mid.min(len - mid));
+    merge_with_buffer(slice, mid, &mut buffer, &mut comp);
+}
+
+/// # Description
+/// Merges two sorted runs `[0, mid)` and `[mid, len)` of the slice stable,
+/// moving the smaller run to the given buffer.
+/// The buffer is used only as a raw storage, its length remains 0.
+///
+/// # Panics
+/// Panics if the capacity of the buffer is smaller than `min(mid, len - mid)`.
+fn merge_with_buffer<T, F>(slice: &mut [T], mid: usize, buffer: &mut Vec<T>, comp: &mut F)
+where
+    F: FnMut(&T, &T) -> std::cmp::Ordering,
+{
+    let len = slice.len();
+    assert!(
+        mid.min(len - mid) <= buffer.capacity(),
+        "merge_with_buffer: buffer is too small"
+    );
+    let buf = buffer.as_mut_ptr();
+    let v = slice.as_mut_ptr();
+
+    unsafe {
+        if mid <= len - mid {
+            // move left run to the buffer, and merge from the front
+            copy_nonoverlapping(v, buf, mid);
+            let mut hole = MergeHole {
+                start: buf,
+                end: buf.add(mid),
+                dest: v,
+            };
+            let mut right = v.add(mid);
+            let right_end = v.add(len);
+            while hole.start < hole.end && right < right_end {
+                // take left one if equal, to keep stableness
+                let next = if comp(&*right, &*hole.start).is_lt() {
+                    right = right.add(1);
+                    right.sub(1)
+                } else {
+                    hole.start = hole.start.add(1);
+                    hole.start.sub(1)
+                };
+                copy_nonoverlapping(next, hole.dest, 1);
+                hole.dest = hole.dest.add(1);
+            }
+            // left over in the buffer is written back by the hole
+        } else {
+            // move right run to the buffer, and merge from the back
+            copy_nonoverlapping(v.add(mid), buf, len - mid);
+            let mut hole = MergeHole {
+                start: buf,
+                end: buf.add(len - mid),
+                dest: v.add(mid),
+            };
+            let mut out = v.add(len);
+            while v < hole.dest && hole.start < hole.end {
+                out = out.sub(1);
+                let left = hole.dest.sub(1);
+                let right = hole.end.sub(1);
+                // take right one if equal, to keep stableness
+                if comp(&*right, &*left).is_lt() {
+                    copy_nonoverlapping(left, out, 1);
+                    hole.dest = left;
+                } else {
+                    copy_nonoverlapping(right, out, 1);
+                    hole.end = right;
+                }
+            }
+            // left over in the buffer is written back by the hole
+        }
+    }
+}
+
+/// # Description
+/// Partitions the given slice stable, so that all elements satisfying `pred` precede the others.
+/// The relative order of the elements is preserved in both groups.
+///
+/// It is implemented as an in-place merge: each half is partitioned recursively,
+/// then the false group of the left half and the true group of the right half are swapped by rotation.
+/// It takes O(n log n) moves and calls `pred` exactly once for each element, without allocation.
+///
+/// # Type Parameters
+/// - `T`: The element type.
+/// - `P`: type of predicate. Must implement 'FnMut'
+///
+/// # Parameters
+/// - `slice`: The mutable slice to partition.
+/// - `pred`: The callable object to test each data of type T.
+///
+/// # Returns
+/// The boundary index, which is the number of elements satisfying `pred`.
+///
+/// # Panics
+/// Panics if the implementation of pred panics.
+/// In that case, every element remains in the slice, but the order is unspecified.
+///
+/// # Examples
+/// ```
+/// use merge_sort::*;
+/// let mut v = vec![1, 2, 3, 4, 5, 6, 7];
+/// let mid = stable_partition(&mut v, |x| x % 2 == 0);
+/// assert_eq!(mid, 3);
+/// assert_eq!(v, vec![2, 4, 6, 1, 3, 5, 7]);
+/// ```
+pub fn stable_partition<T, P>(slice: &mut [T], mut pred: P) -> usize
+where
+    P: FnMut(&T) -> bool,
+{
+    stable_partition_rec(slice, &mut pred)
+}
+
+fn stable_partition_rec<T, P>(slice: &mut [T], pred: &mut P) -> usize
+where
+    P: FnMut(&T) -> bool,
+{
+    let len = slice.len();
+    match len {
+        0 => return 0,
+        1 => return pred(&slice[0]) as usize,
+        _ => {}
+    }
+
+    let mid = len / 2;
+    let left_true = stable_partition_rec(&mut slice[..mid], pred);
+    let right_true = stable_partition_rec(&mut slice[mid..], pred);
+
+    // [left true | left false | right true | right false]
+    // -> [left true | right true | left false | right false]
+    slice[left_true..mid + right_true].rotate_left(mid - left_true);
+    left_true + right_true
+}
+
+/// # Description
+/// Sorts the indices of the given slice stable using merge sort with comparator,
+/// without moving the elements.
+/// The k-th returned index is the original position of the element which comes k-th in the sorted order.
+/// Useful when the elements are heavy to move, since only `usize` indices are moved during the sort.
+/// Use [`apply_index_permutation`] to reorder the slice afterwards.
+///
+/// # Type Parameters
+/// - `T`: The element type.
+/// - `F`: type of comparator. Must implement 'FnMut'
+///
+/// # Parameters
+/// - `slice`: The slice whose indices are sorted.
+/// - `comp`: The callable object to compare two data of type T.
+///
+/// # Panics
+/// Panics if the implementation of 'comp' panics.
+/// In debug builds, panics if `comp` is not a consistent total order.
+///
+/// # Examples
+/// ```
+/// use merge_sort::*;
+/// let v = vec!["ccc", "a", "bb", "d"];
+/// let indices = merge_sort_indices_by(&v, |a, b| a.len().cmp(&b.len()));
+/// assert_eq!(indices, vec![1, 3, 2, 0]);
+/// ```
+pub fn merge_sort_indices_by<T, F>(slice: &[T], mut comp: F) -> Vec<usize>
+where
+    F: FnMut(&T, &T) -> std::cmp::Ordering,
+{
+    let mut indices: Vec<usize> = (0..slice.len()).collect();
+    merge_sort_by(&mut indices, |&i, &j| comp(&slice[i], &slice[j]));
+    indices
+}
+
+/// # Description
+/// Sorts the given Vec stable by the key, and maps each element to `U` while the last merge emits it.
+/// Both halves are sorted by `merge_sort_by`, and the last merge moves each element out of `input`
+/// into `project_fn`, so the result is written once without a separate map pass over the sorted Vec.
+/// The result is the same as sorting by the key stable and mapping the elements in order.
+///
+/// # Type Parameters
+/// - `T`: The input element type.
+/// - `U`: The output element type.
+/// - `K`: The key type. Must implement `Ord`.
+/// - `KF`: type of key extraction function. Must implement 'FnMut'
+/// - `PF`: type of projection function. Must implement 'FnMut'
+///
+/// # Parameters
+/// - `input`: The Vec to sort, consumed by the projection.
+/// - `key_fn`: The callable object to extract the key of &T data. It is called twice per comparison.
+/// - `project_fn`: The callable object to map T to U, called once per element in the sorted order.
+///
+/// # Panics
+/// Panics if the implementation of `key_fn`, `project_fn` or Ord of K panics.
+/// On panic, the elements not yet projected are dropped, and no element is dropped twice.
+///
+/// # Examples
+/// ```
+/// use merge_sort::*;
+/// let records = vec![("carol", 3), ("alice", 1), ("bob", 3), ("dave", 2)];
+/// let names = merge_sort_project(records, |r| r.1, |r| r.0);
+/// assert_eq!(names, vec!["alice", "dave", "carol", "bob"]);
+/// ```
+pub fn merge_sort_project<T, U, K, KF, PF>(
+    mut input: Vec<T>,
+    mut key_fn: KF,
+    mut project_fn: PF,
+) -> Vec<U>
+where
+    K: Ord,
+    KF: FnMut(&T) -> K,
+    PF: FnMut(T) -> U,
+{
+    let len = input.len();
+    let mid = len / 2;
+    {
+        let (left, right) = input.split_at_mut(mid);
+        merge_sort_by(left, |a, b| key_fn(a).cmp(&key_fn(b)));
+        merge_sort_by(right, |a, b| key_fn(a).cmp(&key_fn(b)));
+    }
+
+    /// owner of the elements not yet moved out, `[l, mid)` and `[r, len)` of `src`
+    struct Remaining<T> {
+        src: *mut T,
+        l: usize,
+        mid: usize,
+        r: usize,
+        len: usize,
+    }
+    impl<T> Drop for Remaining<T> {
+        fn drop(&mut self) {
+            // only reached with elements left on panic, since the merge moves out all of them
+            unsafe {
+                std::ptr::drop_in_place(std::ptr::slice_from_raw_parts_mut(
+                    self.src.add(self.l),
+                    self.mid - self.l,
+                ));
+                std::ptr::drop_in_place(std::ptr::slice_from_raw_parts_mut(
+                    self.src.add(self.r),
+                    self.len - self.r,
+                ));
+            }
+        }
+    }
+
+    let mut output = Vec::with_capacity(len);
+    // the Vec keeps only the memory from now on, the elements are owned by `rest`
+    let mut rest = Remaining {
+        src: input.as_mut_ptr(),
+        l: 0,
+        mid,
+        r: mid,
+        len,
+    };
+    unsafe {
+        input.set_len(0);
+        while rest.l < mid || rest.r < len {
+            // left first on equal keys, for stability
+            let take_left = rest.r == len
+                || (rest.l < mid
+                    && key_fn(&*rest.src.add(rest.l)) <= key_fn(&*rest.src.add(rest.r)));
+            let elem = match take_left {
+                true => {
+                    rest.l += 1;
+                    rest.src.add(rest.l - 1).read()
+                }
+                false => {
+                    rest.r += 1;
+                    rest.src.add(rest.r - 1).read()
+                }
+            };
+            output.push(project_fn(elem));
+        }
+    }
+    output
+}
+
+/// # Description
+/// Reorders the slice in place so that `slice[k]` becomes the original `slice[indices[k]]`.
+/// Each element is moved along the cycles of the permutation, with a single swap per element.
+/// Applying the result of [`merge_sort_indices_by`] gives the same order as a stable sort.
+///
+/// # Type Parameters
+/// - `T`: The element type.
+///
+/// # Parameters
+/// - `slice`: The mutable slice to reorder.
+/// - `indices`: The permutation of `0..slice.len()`.
+///
+/// # Panics
+/// Panics if `indices` is not a permutation of `0..slice.len()`.
+///
+/// # Examples
+/// ```
+/// use merge_sort::*;
+/// let mut v = vec!["ccc", "a", "bb", "d"];
+/// apply_index_permutation(&mut v, &[1, 3, 2, 0]);
+/// assert_eq!(v, vec!["a", "d", "bb", "ccc"]);
+/// ```
+pub fn apply_index_permutation<T>(slice: &mut [T], indices: &[usize]) {
+    let len = slice.len();
+    let mut seen = vec![false; len];
+    assert!(
+        indices.len() == len
+            && indices
+                .iter()
+                .all(|&i| i < len && !std::mem::replace(&mut seen[i], true)),
+        "apply_index_permutation: indices is not a permutation of the slice"
+    );
+
+    // perm[j] is the source of position j, and becomes j once position j is filled
+    let mut perm = indices.to_vec();
+    for start in 0..len {
+        let mut j = start;
+        while perm[j] != j {
+            let k = perm[j];
+            perm[j] = j;
+            if k == start {
+                // the element from start is already moved to j
+                break;
+            }
+            slice.swap(j, k);
+            j = k;
+        }
+    }
+}
+
+/// # Description
+/// Range of the buffer `[start, end)` which is not written back to the slice yet.
+/// When it drops, it copies the range to `dest`, even if the comparator panics.
+struct MergeHole<T> {
+    start: *mut T,
+    end: *mut T,
+    dest: *mut T,
+}
+
+impl<T> Drop for MergeHole<T> {
+    fn drop(&mut self) {
+        unsafe {
+            let len = self.end.offset_from(self.start) as usize;
+            copy_nonoverlapping(self.start, self.dest, len);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::*;
+    use std::cmp::Reverse;
+
+    use rand::distr::StandardUniform;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    const TEST_SIZE: usize = 10_000;
+
+    #[test]
+    fn test_merge_sort() {
+        let seed: u64 = 42;
+        let rng = StdRng::seed_from_u64(seed);
+
+        let mut vec: Vec<i32> = rng.sample_iter(StandardUniform).take(TEST_SIZE).collect();
+
+        merge_sort(&mut vec);
+
+        assert!(vec.is_sorted());
+    }
+
+    #[test]
+    fn test_merge_sort_by() {
+        let seed: u64 = 42;
+        let rng = StdRng::seed_from_u64(seed);
+
+        let mut vec: Vec<i32> = rng.sample_iter(StandardUniform).take(TEST_SIZE).collect();
+
+        merge_sort_by(&mut vec, |a: &i32, b: &i32| Reverse(a).cmp(&Reverse(b)));
+
+        assert!(vec.is_sorted_by(|&a, &b| { a > b }));
+    }
+
+    #[test]
+    fn test_merge_sort_patterns() {
+        test_data::check_sort(merge_sort);
+        test_data::check_sort(natural_merge_sort);
+        test_data::check_sort(merge_sort_half_buffer);
+        test_data::check_stable_sort(|slice| merge_sort_by(slice, |a, b| a.0.cmp(&b.0)));
+        test_data::check_stable_sort(|slice| natural_merge_sort_by(slice, |a, b| a.0.cmp(&b.0)));
+        test_data::check_stable_sort(|slice| {
+            merge_sort_half_buffer_by(slice, |a, b| a.0.cmp(&b.0))
+        });
+    }
+
+    #[test]
+    fn test_merge_sort_by_panic_safety() {
+        use std::cell::Cell;
+        use std::panic::{AssertUnwindSafe, catch_unwind};
+        use test_data::DropCounter;
+
+        for panic_at in [1, 100, 500, 1000] {
+            // shuffled input, takes more than 1000 comparisons
+            let drops = Cell::new(0);
+            let mut vec: Vec<DropCounter> = (0..200)
+                .map(|i| DropCounter {
+                    value: (i * 73) % 200,
+                    drops: &drops,
+                })
+                .collect();
+
+            // panics in the middle of a pass
+            let mut calls = 0;
+            let result = catch_unwind(AssertUnwindSafe(|| {
+                merge_sort_by(&mut vec, |a, b| {
+                    calls += 1;
+                    if calls == panic_at {
+                        panic!("comparator panic");
+                    }
+                    a.value.cmp(&b.value)
+                });
+            }));
+            assert!(result.is_err());
+
+            // nothing is lost or dropped by the sort
+            assert_eq!(drops.get(), 0);
+            let mut values: Vec<i32> = vec.iter().map(|e| e.value).collect();
+            values.sort();
+            assert_eq!(values, (0..200).collect::<Vec<i32>>());
+
+            drop(vec);
+            assert_eq!(drops.get(), 200);
+        }
+    }
+
+    #[test]
+    fn test_merge_sort_zero_sized() {
+        let mut vec = vec![(); 100];
+        merge_sort(&mut vec);
+        assert_eq!(vec.len(), 100);
+    }
+
+    #[test]
+    fn test_gallop() {
+        let run = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+        for k in 0..=run.len() {
+            assert_eq!(gallop(&run, |x| *x <= k), k);
+        }
+        assert_eq!(gallop(&[] as &[i32], |_| true), 0);
+    }
+
+    #[test]
+    fn test_merge_sort_gallop_stable() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        // nearly sorted, long blocks from one run
+        let mut nearly: Vec<(usize, usize)> = (0..TEST_SIZE).map(|i| (i / 3, i)).collect();
+        for _ in 0..TEST_SIZE / 100 {
+            let i = rng.random_range(0..TEST_SIZE);
+            let j = rng.random_range(0..TEST_SIZE);
+            nearly.swap(i, j);
+        }
+        // low entropy, many equal keys
+        let low_entropy: Vec<(u8, usize)> = (0..TEST_SIZE)
+            .map(|i| (rng.random_range(0..4), i))
+            .collect();
+
+        let mut expected = nearly.clone();
+        expected.sort_by_key(|x| x.0);
+        merge_sort_by(&mut nearly, |a, b| a.0.cmp(&b.0));
+        assert_eq!(nearly, expected);
+
+        let mut vec = low_entropy.clone();
+        let mut expected = low_entropy;
+        expected.sort_by_key(|x| x.0);
+        merge_sort_by(&mut vec, |a, b| a.0.cmp(&b.0));
+        assert_eq!(vec, expected);
+    }
+
+    #[test]
+    fn test_merge_sort_skip_ordered_runs() {
+        // sorted input, each merge is skipped by one comparison
+        let mut vec: Vec<usize> = (0..TEST_SIZE).collect();
+        let mut cnt = 0;
+        merge_sort_by(&mut vec, |a, b| {
+            cnt += 1;
+            a.cmp(b)
+        });
+        assert!(vec.is_sorted());
+        // less than TEST_SIZE for the merges, plus TEST_SIZE - 1 for the debug check
+        assert!(cnt < 2 * TEST_SIZE);
+
+        // equal keys at every run boundary must not be reordered
+        let mut vec: Vec<(u8, usize)> = (0..TEST_SIZE).map(|i| ((i / 1000) as u8, i)).collect();
+        let expected = vec.clone();
+        merge_sort_by(&mut vec, |a, b| a.0.cmp(&b.0));
+        assert_eq!(vec, expected);
+
+        let mut vec: Vec<(u8, usize)> = (0..TEST_SIZE).map(|i| (0, i)).collect();
+        let expected = vec.clone();
+        merge_sort_by(&mut vec, |a, b| a.0.cmp(&b.0));
+        assert_eq!(vec, expected);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn test_merge_sort_by_inconsistent_comparator() {
+        test_data::check_inconsistent_comparator(merge_sort_by);
+    }
+
+    #[test]
+    fn test_merge_halves() {
+        let mut vec: Vec<i32> = vec![1, 3, 5, 7, 9, 0, 2, 4, 6, 8];
+        merge_halves(&mut vec, 5);
+        assert_eq!(vec, (0..10).collect::<Vec<i32>>());
+
+        // empty run
+        let mut vec: Vec<i32> = vec![3, 1, 2];
+        merge_halves(&mut vec, 3);
+        assert_eq!(vec, vec![3, 1, 2]);
+        merge_halves(&mut vec[1..], 0);
+        assert_eq!(vec, vec![3, 1, 2]);
+    }
+
+    #[test]
+    fn test_merge_halves_uneven() {
+        let seed: u64 = 42;
+        let mut rng = StdRng::seed_from_u64(seed);
+
+        for mid in [1, 7, 500, 993, 999] {
+            let mut vec: Vec<i32> = (&mut rng).sample_iter(StandardUniform).take(1000).collect();
+            vec[..mid].sort();
+            vec[mid..].sort();
+            let mut expected = vec.clone();
+            expected.sort();
+
+            merge_halves(&mut vec, mid);
+            assert_eq!(vec, expected);
+        }
+    }
+
+    #[test]
+    fn test_merge_halves_by_stable() {
+        // sorted by length only
+        let left_small = ["a", "b", "dd", "ee", "c", "h", "ff", "ii", "jj", "ggg"];
+        let mut str_slice = left_small;
+        merge_halves_by(&mut str_slice, 4, |a, b| a.len().cmp(&b.len()));
+        assert_eq!(
+            str_slice,
+            ["a", "b", "c", "h", "dd", "ee", "ff", "ii", "jj", "ggg"]
+        );
+
+        let right_small = ["a", "c", "dd", "ff", "ggg", "jjj", "kkk", "b", "ee", "hhh"];
+        let mut str_slice = right_small;
+        merge_halves_by(&mut str_slice, 7, |a, b| a.len().cmp(&b.len()));
+        assert_eq!(
+            str_slice,
+            ["a", "c", "b", "dd", "ff", "ee", "ggg", "jjj", "kkk", "hhh"]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_merge_halves_out_of_range() {
+        let mut vec: Vec<i32> = vec![1, 2, 3];
+        merge_halves(&mut vec, 4);
+    }
+
+    #[test]
+    fn test_stable_partition() {
+        let mut rng = StdRng::seed_from_u64(5);
+        for len in [0usize, 1, 2, 3, 10, 101, 1000] {
+            let v: Vec<(u8, usize)> = (0..len).map(|i| (rng.random::<u8>(), i)).collect();
+            let pred = |x: &(u8, usize)| x.0.is_multiple_of(3);
+
+            let mut partitioned = v.clone();
+            let mid = stable_partition(&mut partitioned, pred);
+
+            // both groups keep the original relative order
+            let (expected_true, expected_false): (Vec<_>, Vec<_>) =
+                v.iter().copied().partition(pred);
+            assert_eq!(mid, expected_true.len());
+            assert_eq!(&partitioned[..mid], expected_true.as_slice());
+            assert_eq!(&partitioned[mid..], expected_false.as_slice());
+        }
+    }
+
+    #[test]
+    fn test_stable_partition_all_same() {
+        let mut v: Vec<usize> = (0..50).collect();
+        assert_eq!(stable_partition(&mut v, |_| true), 50);
+        assert_eq!(v, (0..50).collect::<Vec<_>>());
+        assert_eq!(stable_partition(&mut v, |_| false), 0);
+        assert_eq!(v, (0..50).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_natural_merge_sort() {
+        let mut rng = StdRng::seed_from_u64(11);
+        for len in [0usize, 1, 2, 3, 4, 5, 17, 100, 1000] {
+            let mut v: Vec<(u8, usize)> = (0..len).map(|i| (rng.random::<u8>() % 16, i)).collect();
+            let mut expected = v.clone();
+            expected.sort_by_key(|x| x.0); // stable
+
+            natural_merge_sort_by(&mut v, |a, b| a.0.cmp(&b.0));
+            assert_eq!(v, expected);
+        }
+
+        // runs of mixed direction, including equal elements
+        let mut v = vec![1, 2, 2, 3, 9, 7, 5, 5, 3, 4, 8, 0, 6, 6, 6];
+        let mut expected = v.clone();
+        expected.sort();
+        natural_merge_sort(&mut v);
+        assert_eq!(v, expected);
+    }
+
+    #[test]
+    fn test_natural_merge_sort_one_pass() {
+        // sorted and reversed input need only the run detection pass
+        let sorted: Vec<u32> = (0..1000).collect();
+        for input in [sorted.clone(), sorted.iter().rev().copied().collect()] {
+            let mut v = input;
+            let mut count = 0;
+            natural_merge_sort_by(&mut v, |a, b| {
+                count += 1;
+                a.cmp(b)
+            });
+            assert_eq!(v, sorted);
+            // run detection compares each adjacent pair once
+            // with debug_assertions, the final is_sorted check compares each adjacent pair once more,
+            // and the counter includes those calls
+            #[cfg(not(debug_assertions))]
+            assert_eq!(count, sorted.len() - 1);
+            #[cfg(debug_assertions)]
+            assert_eq!(count, 2 * (sorted.len() - 1));
+        }
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn test_natural_merge_sort_by_inconsistent_comparator() {
+        test_data::check_inconsistent_comparator(natural_merge_sort_by);
+    }
+
+    #[test]
+    fn test_merge_sort_half_buffer() {
+        let mut rng = StdRng::seed_from_u64(17);
+        for len in [0usize, 1, 2, 3, 5, 8, 33, 100, 1000] {
+            let v: Vec<(u8, usize)> = (0..len).map(|i| (rng.random::<u8>() % 16, i)).collect();
+
+            let mut full = v.clone();
+            merge_sort_by(&mut full, |a, b| a.0.cmp(&b.0));
+            let mut half = v.clone();
+            merge_sort_half_buffer_by(&mut half, |a, b| a.0.cmp(&b.0));
+
+            // stable, so the result is the same as the full-buffer version
+            assert_eq!(half, full);
+            assert!(half.is_sorted());
+        }
+
+        let mut v: Vec<String> = (0..100).rev().map(|x| x.to_string()).collect();
+        let mut expected = v.clone();
+        expected.sort();
+        merge_sort_half_buffer(&mut v);
+        assert_eq!(v, expected);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn test_merge_sort_half_buffer_by_inconsistent_comparator() {
+        test_data::check_inconsistent_comparator(merge_sort_half_buffer_by);
+    }
+
+    #[test]
+    fn test_merge_sort_indices_by() {
+        #[derive(Debug, Clone, PartialEq)]
+        struct Large {
+            key: u8,
+            payload: [u64; 32],
+        }
+
+        let mut rng = StdRng::seed_from_u64(42);
+        let vec: Vec<Large> = (0..1000)
+            .map(|i| Large {
+                key: rng.random_range(0..16),
+                payload: [i; 32],
+            })
+            .collect();
+
+        let indices = merge_sort_indices_by(&vec, |a, b| a.key.cmp(&b.key));
+        let mut by_index = vec.clone();
+        apply_index_permutation(&mut by_index, &indices);
+
+        // same as direct stable sort
+        let mut expected = vec;
+        merge_sort_by(&mut expected, |a, b| a.key.cmp(&b.key));
+        assert_eq!(by_index, expected);
+
+        assert!(merge_sort_indices_by(&[] as &[i32], i32::cmp).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "indices is not a permutation of the slice")]
+    fn test_apply_index_permutation_invalid() {
+        let mut vec = vec![1, 2, 3];
+        apply_index_permutation(&mut vec, &[0, 0, 2]);
+    }
+
+    #[test]
+    fn test_merge_sort_desc_stable() {
+        test_data::check_stable_sort_desc(merge_sort_desc);
+    }
+
+    #[test]
+    fn test_merge_sort_counting_moves() {
+        // 5 elements: 4 + 4 + 5 elements merged in three passes
+        let mut reversed = vec![5, 4, 3, 2, 1];
+        assert_eq!(merge_sort_counting_moves(&mut reversed), 26);
+        assert_eq!(reversed, vec![1, 2, 3, 4, 5]);
+
+        // already ordered runs are copied, not merged, but still moved
+        let mut sorted = vec![1, 2, 3, 4, 5];
+        assert_eq!(merge_sort_counting_moves(&mut sorted), 26);
+        assert_eq!(sorted, vec![1, 2, 3, 4, 5]);
+
+        let seed: u64 = 42;
+        let rng = StdRng::seed_from_u64(seed);
+        let mut vec: Vec<i32> = rng.sample_iter(StandardUniform).take(1024).collect();
+        let mut expected = vec.clone();
+        expected.sort();
+        assert_eq!(merge_sort_counting_moves(&mut vec), 2 * 1024 * 10);
+        assert_eq!(vec, expected);
+
+        assert_eq!(merge_sort_counting_moves::<i32>(&mut []), 0);
+    }
+
+    #[test]
+    fn test_merge_sort_by_counting_comparisons() {
+        // pass 1: (5, 4) and (3, 2) take a check and a merge step each, 1 is left alone
+        // pass 2: [4, 5] and [2, 3] take a check and two merge steps
+        // pass 3: [2, 3, 4, 5] and [1] take a check and one merge step
+        let mut reversed = vec![5, 4, 3, 2, 1];
+        assert_eq!(
+            merge_sort_by_counting_comparisons(&mut reversed, i32::cmp),
+            9
+        );
+        assert_eq!(reversed, vec![1, 2, 3, 4, 5]);
+
+        // already sorted input takes one comparison per pair of runs
+        let mut sorted: Vec<i32> = (0..1024).collect();
+        assert_eq!(
+            merge_sort_by_counting_comparisons(&mut sorted, i32::cmp),
+            1023
+        );
+        assert!(sorted.is_sorted());
+
+        // random input stays under n log2 n, far above the sorted case
+        let seed: u64 = 42;
+        let rng = StdRng::seed_from_u64(seed);
+        let mut vec: Vec<i32> = rng.sample_iter(StandardUniform).take(1024).collect();
+        let comparisons = merge_sort_by_counting_comparisons(&mut vec, i32::cmp);
+        assert!(vec.is_sorted());
+        assert!(comparisons <= 1024 * 10);
+        assert!(comparisons > 1023 * 5);
+
+        assert_eq!(
+            merge_sort_by_counting_comparisons(&mut [] as &mut [i32], i32::cmp),
+            0
+        );
+    }
+
+    #[test]
+    fn test_merge_sort_by_key_desc_stable() {
+        // many duplicate keys, the index tells the original order
+        let mut vec: Vec<(u32, usize)> = (0..1000).map(|i| ((i as u32 * 7919) % 17, i)).collect();
+        merge_sort_by_key_desc(&mut vec, |x| x.0);
+
+        assert!(vec.is_sorted_by(|a, b| a.0 >= b.0));
+        // within a group of equal keys, the original order is kept
+        assert!(
+            vec.windows(2)
+                .filter(|w| w[0].0 == w[1].0)
+                .all(|w| w[0].1 < w[1].1)
+        );
+
+        // reversing an ascending sort puts the equal keys in reverse order
+        let mut reversed: Vec<(u32, usize)> =
+            (0..1000).map(|i| ((i as u32 * 7919) % 17, i)).collect();
+        reversed.sort_by_key(|x| x.0);
+        reversed.reverse();
+        assert_ne!(vec, reversed);
+    }
+
+    #[test]
+    fn test_merge_sort_project() {
+        #[derive(Debug, Clone)]
+        struct Record {
+            id: usize,
+            score: u32,
+            name: String,
+        }
+
+        let records: Vec<Record> = (0..1000)
+            .map(|id| Record {
+                id,
+                score: (id as u32 * 7919) % 37,
+                name: format!("record {id}"),
+            })
+            .collect();
+
+        // same as sort then map
+        let mut expected = records.clone();
+        expected.sort_by_key(|r| r.score);
+        let expected_scores: Vec<u32> = expected.iter().map(|r| r.score).collect();
+        let expected_ids: Vec<usize> = expected.iter().map(|r| r.id).collect();
+
+        let scores = merge_sort_project(records.clone(), |r| r.score, |r| r.score);
+        assert_eq!(scores, expected_scores);
+
+        // stable, equal scores keep the original order of ids
+        let ids = merge_sort_project(records.clone(), |r| r.score, |r| r.id);
+        assert_eq!(ids, expected_ids);
+
+        // owned fields are moved out, not cloned
+        let expected_names: Vec<String> = expected.into_iter().map(|r| r.name).collect();
+        let names = merge_sort_project(records, |r| r.score, |r| r.name);
+        assert_eq!(names, expected_names);
+
+        let empty: Vec<u32> = merge_sort_project(Vec::<Record>::new(), |r| r.score, |r| r.score);
+        assert!(empty.is_empty());
+        let single = merge_sort_project(vec![(1, "one")], |x| x.0, |x| x.1);
+        assert_eq!(single, vec!["one"]);
+    }
+
+    #[test]
+    fn test_merge_sort_project_panic_safety() {
+        use std::cell::Cell;
+        use std::panic::{AssertUnwindSafe, catch_unwind};
+        use test_data::DropCounter;
+
+        for panic_at in [0, 1, 100, 199] {
+            let drops = Cell::new(0);
+            let vec: Vec<DropCounter> = (0..200)
+                .map(|i| DropCounter {
+                    value: (i * 73) % 200,
+                    drops: &drops,
+                })
+                .collect();
+
+            // panics in the middle of the last merge
+            let mut calls = 0;
+            let result = catch_unwind(AssertUnwindSafe(|| {
+                merge_sort_project(
+                    vec,
+                    |e| e.value,
+                    |e| {
+                        calls += 1;
+                        if calls > panic_at {
+                            panic!("projection panic");
+                        }
+                        e.value
+                    },
+                )
+            }));
+            assert!(result.is_err());
+
+            // every element is dropped exactly once, by the projection or by the unwinding
+            assert_eq!(drops.get(), 200);
+        }
     }
 }
@@ -0,0 +1,193 @@
+//! # Description
+//! Implementation of Batcher's odd-even merge sort.
+//!
+//! Odd-even merge sort is a sorting network, which is a fixed sequence of compare-exchanges.
+//! - Data-oblivious : the pairs of positions to compare-exchange depend only on the length of the slice,
+//!   not on the values. So it is suitable for oblivious contexts such as MPC, or as a building block
+//!   of constant-time sorting.
+//! - O(n log²n) compare-exchanges, slower than comparison sorts in the usual context.
+//! - In-place, no allocation.
+//! - Unstable : order of equal elements is not preserved.
+//!
+//! # Note on constant time
+//! The sequence of compare-exchanges is fixed, but each compare-exchange swaps by a branch on the result of the comparison.
+//! For side-channel resistance, the comparator and the swap of `T` should also be branch-free,
+//! which is out of scope of this generic implementation.
+//!
+//! # Small networks
+//! `sort2` to `sort5` sort slices of exactly 2 to 5 `Copy` elements with optimal networks.
+//! Unlike the sort above, their compare-exchanges select the minimum and the maximum without a branch.
+
+mod small_network;
+
+pub use crate::small_network::*;
+
+/// # Description
+/// Sorts the given slice in-place using Batcher's odd-even merge sort.
+///
+/// # Type Parameters
+/// - `T`: The element type. Must implement `Ord`.
+///
+/// # Parameters
+/// - `slice`: The mutable slice to sort.
+///
+/// # Panics
+/// Panics if the implementation of Ord panics.
+///
+/// # Examples
+/// ```
+/// use odd_even_merge_sort::batcher_odd_even_merge_sort;
+/// let mut v = vec![3, 1, 4, 1, 5];
+/// batcher_odd_even_merge_sort(&mut v);
+/// assert_eq!(v, vec![1, 1, 3, 4, 5]);
+/// ```
+pub fn batcher_odd_even_merge_sort<T: Ord>(slice: &mut [T]) {
+    batcher_odd_even_merge_sort_by(slice, T::cmp)
+}
+
+/// # Description
+/// Sorts the given slice in-place using Batcher's odd-even merge sort
+/// with comparator.
+///
+/// `comp` is called exactly once for each compare-exchange,
+/// and the number of compare-exchanges depends only on the length of the slice.
+///
+/// # Type Parameters
+/// - `T`: The element type.
+/// - `F`: The comparator type. Must implement 'FnMut' trait.
+///
+/// # Parameters
+/// - `slice`: The mutable slice to sort.
+/// - `comp`: The callable object to compare two &T data.
+///
+/// # Panics
+/// Panics if the implementation of 'comp' panics.
+///
+/// # Examples
+/// ```
+/// use odd_even_merge_sort::batcher_odd_even_merge_sort_by;
+/// let mut v = vec![3, 1, 4, 1, 5];
+/// batcher_odd_even_merge_sort_by(&mut v, |a : &i32, b : &i32|{ b.cmp(a) });
+/// assert_eq!(v, vec![5, 4, 3, 1, 1]);
+/// ```
+pub fn batcher_odd_even_merge_sort_by<T, F>(slice: &mut [T], mut comp: F)
+where
+    F: FnMut(&T, &T) -> std::cmp::Ordering,
+{
+    for_each_comparator(slice.len(), |i, j| {
+        if comp(&slice[i], &slice[j]).is_gt() {
+            slice.swap(i, j);
+        }
+    });
+}
+
+/// # Description
+/// Calls `compare_exchange(i, j)` with `i < j` for each comparator of the network, in order.
+///
+/// Iterative form of the network which works for arbitrary length, not only for powers of two.
+/// For each `p`, sorted runs of length `p` are merged into runs of length `2p`.
+/// Positions `i` and `i + k` are compared only if they are in the same run of length `2p`,
+/// so the comparators out of the slice are dropped.
+fn for_each_comparator<G>(len: usize, mut compare_exchange: G)
+where
+    G: FnMut(usize, usize),
+{
+    let mut p = 1;
+    while p < len {
+        let mut k = p;
+        while k >= 1 {
+            let mut j = k % p;
+            while j + k < len {
+                for i in 0..k.min(len - j - k) {
+                    if (i + j) / (2 * p) == (i + j + k) / (2 * p) {
+                        compare_exchange(i + j, i + j + k);
+                    }
+                }
+                j += 2 * k;
+            }
+            k >>= 1;
+        }
+        p <<= 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    use rand::distr::StandardUniform;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn test_odd_even_merge_sort() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for len in (0..70).chain([127, 128, 129, 1000]) {
+            let mut vec: Vec<i32> = (&mut rng).sample_iter(StandardUniform).take(len).collect();
+            let mut expected = vec.clone();
+            expected.sort();
+            batcher_odd_even_merge_sort(&mut vec);
+            assert_eq!(vec, expected);
+        }
+    }
+
+    #[test]
+    fn test_odd_even_merge_sort_worst_case() {
+        for len in [2, 3, 15, 16, 17, 100] {
+            let mut reversed: Vec<usize> = (0..len).rev().collect();
+            batcher_odd_even_merge_sort(&mut reversed);
+            assert_eq!(reversed, (0..len).collect::<Vec<_>>());
+
+            let mut all_equal = vec![7; len];
+            batcher_odd_even_merge_sort(&mut all_equal);
+            assert_eq!(all_equal, vec![7; len]);
+        }
+
+        // 0-1 principle, every binary input of length 10
+        for bits in 0u32..(1 << 10) {
+            let mut vec: Vec<u32> = (0..10).map(|i| (bits >> i) & 1).collect();
+            batcher_odd_even_merge_sort(&mut vec);
+            assert!(vec.is_sorted());
+        }
+    }
+
+    #[test]
+    fn test_odd_even_merge_sort_by() {
+        let mut vec: Vec<i32> = (0..100).collect();
+        batcher_odd_even_merge_sort_by(&mut vec, |a, b| b.cmp(a));
+        assert_eq!(vec, (0..100).rev().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_odd_even_merge_sort_oblivious() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for len in [0, 1, 2, 5, 16, 100, 1000] {
+            let inputs: [Vec<i32>; 4] = [
+                (0..len as i32).collect(),
+                (0..len as i32).rev().collect(),
+                vec![0; len],
+                (&mut rng).sample_iter(StandardUniform).take(len).collect(),
+            ];
+
+            // the sequence of compared pairs is the same for every input
+            let mut traces = inputs.map(|mut vec| {
+                let mut trace = Vec::new();
+                let base = vec.as_ptr() as usize;
+                batcher_odd_even_merge_sort_by(&mut vec, |a, b| {
+                    let pos = |x: &i32| (x as *const i32 as usize - base) / size_of::<i32>();
+                    trace.push((pos(a), pos(b)));
+                    a.cmp(b)
+                });
+                trace
+            });
+            let first = std::mem::take(&mut traces[0]);
+            assert!(traces[1..].iter().all(|trace| *trace == first));
+
+            // O(n log²n) compare-exchanges
+            if len > 1 {
+                let log = len.next_power_of_two().ilog2() as usize;
+                assert!(first.len() <= len * log * (log + 1) / 4);
+            }
+        }
+    }
+}
@@ -0,0 +1,290 @@
+//! # Description
+//! Optimal sorting networks for tiny fixed-size slices of 2 to 5 elements.
+//!
+//! Each network uses the minimal number of compare-exchanges for its size (1, 3, 5, 9),
+//! and the sequence does not depend on the values.
+//! Useful for sorting small records such as RGB triples, or for median-of-three/five pivot selection.
+//! Like the other networks of this crate, they are unstable.
+//!
+//! `sort2` to `sort5` take `T: Copy`, and each compare-exchange writes the minimum and the maximum
+//! selected by `std::hint::select_unpredictable`, so there is no branch on the result of the comparison.
+//! `sort3_at_by` and `sort5_at_by` accept any `T` for pivot selection, and swap by a branch instead.
+
+use std::hint::select_unpredictable;
+
+/// compare-exchanges of the optimal networks, `i < j` for each pair
+const NETWORK2: [(usize, usize); 1] = [(0, 1)];
+const NETWORK3: [(usize, usize); 3] = [(1, 2), (0, 2), (0, 1)];
+const NETWORK4: [(usize, usize); 5] = [(0, 1), (2, 3), (0, 2), (1, 3), (1, 2)];
+const NETWORK5: [(usize, usize); 9] = [
+    (0, 1),
+    (3, 4),
+    (2, 4),
+    (2, 3),
+    (1, 4),
+    (0, 3),
+    (0, 2),
+    (1, 3),
+    (1, 2),
+];
+
+/// # Description
+/// Applies the compare-exchanges of `network` in order to the elements of `slice` at `at`,
+/// so that wire `i` of the network is `slice[at[i]]`.
+/// Each compare-exchange swaps by a branch, so `T` does not need to be `Copy`.
+fn apply_network<T, F>(slice: &mut [T], at: &[usize], network: &[(usize, usize)], comp: &mut F)
+where
+    F: FnMut(&T, &T) -> std::cmp::Ordering,
+{
+    for &(i, j) in network {
+        let (i, j) = (at[i], at[j]);
+        if comp(&slice[i], &slice[j]).is_gt() {
+            slice.swap(i, j);
+        }
+    }
+}
+
+/// # Description
+/// Applies the compare-exchanges of `network` in order to the first elements of `slice`.
+/// Each compare-exchange copies both elements out, and writes back the minimum and the maximum
+/// selected by the comparison, without a branch.
+fn apply_network_branchless<T, F>(slice: &mut [T], network: &[(usize, usize)], comp: &mut F)
+where
+    T: Copy,
+    F: FnMut(&T, &T) -> std::cmp::Ordering,
+{
+    for &(i, j) in network {
+        let (a, b) = (slice[i], slice[j]);
+        let greater = comp(&a, &b).is_gt();
+        slice[i] = select_unpredictable(greater, b, a);
+        slice[j] = select_unpredictable(greater, a, b);
+    }
+}
+
+macro_rules! impl_small_sort {
+    ($name:ident, $name_by:ident, $len:literal, $network:ident, $count:literal, $input:literal, $asc:literal, $desc:literal) => {
+        #[doc = concat!("# Description\nSorts a slice of exactly ", $len, " elements with an optimal sorting network, without branches on the comparisons.\n\n")]
+        #[doc = "# Type Parameters\n- `T`: The element type. Must implement `Ord` and `Copy`.\n\n"]
+        #[doc = "# Parameters\n- `slice`: The mutable slice to sort.\n\n"]
+        #[doc = concat!("# Panics\nPanics if the length of the slice is not ", $len, ".\nPanics if the implementation of Ord panics.\n\n")]
+        #[doc = concat!("# Examples\n```\nuse odd_even_merge_sort::", stringify!($name), ";\nlet mut v = ", $input, ";\n", stringify!($name), "(&mut v);\nassert_eq!(v, ", $asc, ");\n```")]
+        pub fn $name<T: Ord + Copy>(slice: &mut [T]) {
+            $name_by(slice, T::cmp)
+        }
+
+        #[doc = concat!("# Description\nSorts a slice of exactly ", $len, " elements with an optimal sorting network, with comparator.\n")]
+        #[doc = concat!("`comp` is called exactly ", $count, " times, and the elements are moved without branches on its results.\n\n")]
+        #[doc = "# Type Parameters\n- `T`: The element type. Must implement `Copy`.\n- `F`: The comparator type. Must implement 'FnMut' trait.\n\n"]
+        #[doc = "# Parameters\n- `slice`: The mutable slice to sort.\n- `comp`: The callable object to compare two &T data.\n\n"]
+        #[doc = concat!("# Panics\nPanics if the length of the slice is not ", $len, ".\nPanics if the implementation of 'comp' panics.\n\n")]
+        #[doc = concat!("# Examples\n```\nuse odd_even_merge_sort::", stringify!($name_by), ";\nlet mut v = ", $input, ";\n", stringify!($name_by), "(&mut v, |a: &i32, b: &i32| b.cmp(a));\nassert_eq!(v, ", $desc, ");\n```")]
+        pub fn $name_by<T, F>(slice: &mut [T], mut comp: F)
+        where
+            T: Copy,
+            F: FnMut(&T, &T) -> std::cmp::Ordering,
+        {
+            assert_eq!(
+                slice.len(),
+                $len,
+                concat!(stringify!($name_by), ": slice length must be ", $len)
+            );
+            apply_network_branchless(slice, &$network, &mut comp);
+        }
+    };
+}
+
+impl_small_sort!(
+    sort2, sort2_by, 2, NETWORK2, 1, "[2, 1]", "[1, 2]", "[2, 1]"
+);
+impl_small_sort!(
+    sort3,
+    sort3_by,
+    3,
+    NETWORK3,
+    3,
+    "[2, 3, 1]",
+    "[1, 2, 3]",
+    "[3, 2, 1]"
+);
+impl_small_sort!(
+    sort4,
+    sort4_by,
+    4,
+    NETWORK4,
+    5,
+    "[3, 1, 4, 2]",
+    "[1, 2, 3, 4]",
+    "[4, 3, 2, 1]"
+);
+impl_small_sort!(
+    sort5,
+    sort5_by,
+    5,
+    NETWORK5,
+    9,
+    "[3, 1, 4, 5, 2]",
+    "[1, 2, 3, 4, 5]",
+    "[5, 4, 3, 2, 1]"
+);
+
+/// # Description
+/// Sorts the three elements at `indices` of the slice with the network of [`sort3_by`], for any `T`,
+/// so that `slice[indices[0]] <= slice[indices[1]] <= slice[indices[2]]` by comp.
+/// The elements do not need to be adjacent, e.g. the samples of a median-of-three pivot selection.
+/// After the call, `indices[1]` holds the median of the three.
+/// `comp` is called exactly 3 times.
+///
+/// # Type Parameters
+/// - `T`: The element type.
+/// - `F`: The comparator type. Must implement 'FnMut' trait.
+///
+/// # Parameters
+/// - `slice`: The mutable slice which contains the three elements.
+/// - `indices`: The distinct indices of the elements to sort.
+/// - `comp`: The callable object to compare two &T data.
+///
+/// # Panics
+/// Panics if any of the indices is out of bounds.
+/// Panics if the implementation of 'comp' panics.
+///
+/// # Examples
+/// ```
+/// use odd_even_merge_sort::sort3_at_by;
+/// let mut v = vec![3, 0, 2, 0, 1];
+/// sort3_at_by(&mut v, [0, 2, 4], i32::cmp);
+/// assert_eq!(v, vec![1, 0, 2, 0, 3]);
+/// ```
+pub fn sort3_at_by<T, F>(slice: &mut [T], indices: [usize; 3], mut comp: F)
+where
+    F: FnMut(&T, &T) -> std::cmp::Ordering,
+{
+    apply_network(slice, &indices, &NETWORK3, &mut comp);
+}
+
+/// # Description
+/// Sorts the five elements at `indices` of the slice with the network of [`sort5_by`], for any `T`,
+/// so that `slice[indices[0]] <= ... <= slice[indices[4]]` by comp.
+/// The elements do not need to be adjacent, e.g. the samples of a dual-pivot selection.
+/// `comp` is called exactly 9 times.
+///
+/// # Type Parameters
+/// - `T`: The element type.
+/// - `F`: The comparator type. Must implement 'FnMut' trait.
+///
+/// # Parameters
+/// - `slice`: The mutable slice which contains the five elements.
+/// - `indices`: The distinct indices of the elements to sort.
+/// - `comp`: The callable object to compare two &T data.
+///
+/// # Panics
+/// Panics if any of the indices is out of bounds.
+/// Panics if the implementation of 'comp' panics.
+///
+/// # Examples
+/// ```
+/// use odd_even_merge_sort::sort5_at_by;
+/// let mut v = vec![5, 0, 4, 0, 3, 0, 2, 0, 1];
+/// sort5_at_by(&mut v, [0, 2, 4, 6, 8], i32::cmp);
+/// assert_eq!(v, vec![1, 0, 2, 0, 3, 0, 4, 0, 5]);
+/// ```
+pub fn sort5_at_by<T, F>(slice: &mut [T], indices: [usize; 5], mut comp: F)
+where
+    F: FnMut(&T, &T) -> std::cmp::Ordering,
+{
+    apply_network(slice, &indices, &NETWORK5, &mut comp);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    /// every sequence of length `len` over `0..len`, which includes all permutations and duplicates
+    fn all_sequences(len: usize) -> Vec<Vec<u8>> {
+        let total = len.pow(len as u32);
+        (0..total)
+            .map(|mut code| {
+                (0..len)
+                    .map(|_| {
+                        let digit = (code % len) as u8;
+                        code /= len;
+                        digit
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_small_sort_exhaustive() {
+        let sorts: [fn(&mut [u8]); 4] = [sort2, sort3, sort4, sort5];
+        for (len, sort) in (2..).zip(sorts) {
+            for seq in all_sequences(len) {
+                let mut vec = seq.clone();
+                let mut expected = seq;
+                expected.sort();
+                sort(&mut vec);
+                assert_eq!(vec, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_small_sort_by_comparisons() {
+        let mut vec = [1, 5, 2, 4, 3];
+        let mut count = 0;
+        sort5_by(&mut vec, |a: &i32, b: &i32| {
+            count += 1;
+            b.cmp(a)
+        });
+        assert_eq!(vec, [5, 4, 3, 2, 1]);
+        assert_eq!(count, 9);
+    }
+
+    #[test]
+    fn test_sort3_at_by() {
+        // the elements at 1, 3 and 6 are sorted, the others are untouched
+        for seq in all_sequences(3) {
+            let mut vec = [9, seq[0], 9, seq[1], 9, 9, seq[2]];
+            let mut expected = seq.clone();
+            expected.sort();
+            let mut count = 0;
+            sort3_at_by(&mut vec, [1, 3, 6], |a: &u8, b: &u8| {
+                count += 1;
+                a.cmp(b)
+            });
+            assert_eq!(vec, [9, expected[0], 9, expected[1], 9, 9, expected[2]]);
+            assert_eq!(count, 3);
+        }
+    }
+
+    #[test]
+    fn test_sort5_at_by() {
+        // the elements at 0, 2, 3, 7 and 8 are sorted, the others are untouched
+        for seq in all_sequences(5) {
+            let mut vec = [seq[0], 9, seq[1], seq[2], 9, 9, 9, seq[3], seq[4]];
+            let mut expected = seq.clone();
+            expected.sort();
+            sort5_at_by(&mut vec, [0, 2, 3, 7, 8], u8::cmp);
+            assert_eq!(
+                vec,
+                [
+                    expected[0],
+                    9,
+                    expected[1],
+                    expected[2],
+                    9,
+                    9,
+                    9,
+                    expected[3],
+                    expected[4]
+                ]
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "sort3_by: slice length must be 3")]
+    fn test_small_sort_wrong_length() {
+        sort3(&mut [1, 2]);
+    }
+}
@@ -0,0 +1,181 @@
+use heap_on_slice::max_heap;
+
+use crate::binary_quick_sort::partition_by;
+use crate::pivot::choose_pivot_by;
+
+/// number of consecutive unbalanced partitions before falling back to heap sort
+const MAX_UNBALANCED: u32 = 4;
+
+/// # Description
+/// Sorts the given slice in-place using a quick-sort algorithm
+/// which falls back to heap sort when the partitions are chronically unbalanced.
+///
+/// # Type Parameters
+/// - `T`: The element type. Must implement `Ord`.
+///
+/// # Parameters
+/// - `slice`: The mutable slice to sort.
+///
+/// # Panics
+/// Panics if the implementation of Ord panics.
+///
+/// # Examples
+/// ```
+/// use quick_sort::adaptive_quick_sort;
+/// let mut v = vec![3, 1, 4, 1, 5];
+/// adaptive_quick_sort(&mut v);
+/// assert_eq!(v, vec![1, 1, 3, 4, 5]);
+/// ```
+pub fn adaptive_quick_sort<T: Ord>(slice: &mut [T]) {
+    adaptive_quick_sort_by(slice, T::cmp)
+}
+
+/// # Description
+/// Sorts the given slice in-place using a quick-sort algorithm
+/// which falls back to heap sort when the partitions are chronically unbalanced,
+/// with comparator.
+///
+/// The pivot is chosen by `choose_pivot_by`, the median of three or the ninther
+/// for slices longer than `NINTHER_THRESHOLD`.
+/// A partition is unbalanced if the smaller part is less than 1/8 of the slice.
+/// If MAX_UNBALANCED partitions in a row are unbalanced, the pivot selection is
+/// defeated by the input, so the rest of the slice is sorted by heap sort.
+/// Unlike intro sort, the fallback depends on the balance of the recent partitions,
+/// not on the total recursion depth, so bad pivots are caught earlier.
+///
+/// # Type Parameters
+/// - `T`: The element type.
+/// - `F`: The comparator type. Must implement 'FnMut' trait.
+///
+/// # Parameters
+/// - `slice`: The mutable slice to sort.
+/// - `comp`: The callable object to compare two &T data.
+///
+/// # Panics
+/// Panics if the implementation of 'comp' panics.
+/// In debug builds, panics if `comp` is not a consistent total order.
+///
+/// # Performance
+/// Time complexity(worst) : O(n log n)
+/// Every MAX_UNBALANCED levels, the slice shrinks to at most 7/8 or heap sort is used.
+///
+/// # Examples
+/// ```
+/// use quick_sort::adaptive_quick_sort_by;
+/// let mut v = vec![3, 1, 4, 1, 5];
+/// adaptive_quick_sort_by(&mut v, |a: &i32, b: &i32| b.cmp(a));
+/// assert_eq!(v, vec![5, 4, 3, 1, 1]);
+/// ```
+pub fn adaptive_quick_sort_by<T, F>(slice: &mut [T], mut comp: F)
+where
+    F: FnMut(&T, &T) -> std::cmp::Ordering,
+{
+    adaptive_recurse_sort_by(slice, &mut comp, 0);
+    debug_assert!(
+        slice.is_sorted_by(|a, b| comp(a, b).is_le()),
+        "adaptive_quick_sort_by: comparator is not a consistent total order"
+    );
+}
+
+/// # Description
+/// Recursive part of adaptive quick sort.
+/// `unbalanced` is the number of consecutive unbalanced partitions above this slice.
+fn adaptive_recurse_sort_by<T, F>(slice: &mut [T], comp: &mut F, unbalanced: u32)
+where
+    F: FnMut(&T, &T) -> std::cmp::Ordering,
+{
+    let len = slice.len();
+    if len <= 1 {
+        return;
+    } else if unbalanced >= MAX_UNBALANCED {
+        #[cfg(test)]
+        tests::HEAP_SORT_CNT.with(|cnt| cnt.set(cnt.get() + 1));
+        return max_heap::heap_sort_by(slice, comp);
+    }
+
+    // move the chosen pivot to the end, where partition_by takes it
+    let pivot = choose_pivot_by(slice, comp);
+    slice.swap(pivot, len - 1);
+
+    let pivot_pos = partition_by(slice, comp);
+    let (left, right) = slice.split_at_mut(pivot_pos);
+    let right = &mut right[1..];
+
+    let unbalanced = match left.len().min(right.len()) < len / 8 {
+        true => unbalanced + 1,
+        false => 0,
+    };
+    adaptive_recurse_sort_by(left, comp, unbalanced);
+    adaptive_recurse_sort_by(right, comp, unbalanced);
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::*;
+    use std::cell::Cell;
+
+    use rand::distr::StandardUniform;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    const TEST_SIZE: usize = 10_000;
+
+    thread_local! {
+        /// number of heap sort fallback in the current thread
+        pub static HEAP_SORT_CNT: Cell<usize> = const { Cell::new(0) };
+    }
+
+    #[test]
+    fn test_adaptive_quick_sort() {
+        let seed: u64 = 42;
+        let rng = StdRng::seed_from_u64(seed);
+
+        let mut vec: Vec<i32> = rng.sample_iter(StandardUniform).take(TEST_SIZE).collect();
+        let mut expected = vec.clone();
+        expected.sort();
+
+        adaptive_quick_sort(&mut vec);
+
+        assert_eq!(vec, expected);
+    }
+
+    #[test]
+    fn test_adaptive_quick_sort_unbalanced() {
+        // every element is equal to the pivot, so partition_by splits off a single element at every level.
+        // without the heap sort fallback, this needs O(n^2) comparisons and O(n) recursion depth.
+        // sorted and reversed inputs are split in half by the chosen pivot, without the fallback.
+        let len = 100_000;
+        let sorted: Vec<i32> = (0..len as i32).collect();
+        let reversed: Vec<i32> = sorted.iter().rev().copied().collect();
+        let all_equal = vec![7; len];
+
+        for (mut vec, fallback) in [(sorted, false), (reversed, false), (all_equal, true)] {
+            let mut expected = vec.clone();
+            expected.sort();
+
+            HEAP_SORT_CNT.with(|cnt| cnt.set(0));
+            let mut comp_cnt = 0usize;
+            adaptive_quick_sort_by(&mut vec, |a: &i32, b: &i32| {
+                comp_cnt += 1;
+                a.cmp(b)
+            });
+
+            assert_eq!(vec, expected);
+            assert_eq!(HEAP_SORT_CNT.with(|cnt| cnt.get()) > 0, fallback);
+            let log_len = usize::ilog2(len) as usize;
+            assert!(comp_cnt < 8 * len * log_len);
+        }
+    }
+
+    #[test]
+    fn test_adaptive_quick_sort_patterns() {
+        test_data::check_sort(adaptive_quick_sort);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn test_adaptive_quick_sort_by_inconsistent_comparator() {
+        test_data::check_inconsistent_comparator(adaptive_quick_sort_by);
+    }
+}
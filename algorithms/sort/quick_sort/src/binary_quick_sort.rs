@@ -1,3 +1,5 @@
+use crate::pivot::choose_pivot_by;
+
 /// # Description
 /// Sorts the given slice in-place using a basic partition quick‑sort algorithm.
 ///
@@ -23,7 +25,7 @@ pub fn binary_quick_sort<T: Ord>(slice: &mut [T]) {
 }
 
 /// partition slice with pivot at end by comp
-fn partition_by<T, F>(slice: &mut [T], comp: &mut F) -> usize
+pub(crate) fn partition_by<T, F>(slice: &mut [T], comp: &mut F) -> usize
 where
     F: FnMut(&T, &T) -> std::cmp::Ordering,
 {
@@ -49,6 +51,11 @@ where
         return;
     }
 
+    // move the chosen pivot to the end, where partition_by takes it
+    let pivot = choose_pivot_by(slice, comp);
+    let last = slice.len() - 1;
+    slice.swap(pivot, last);
+
     // partition
     let pivot_pos = partition_by(slice, comp);
 
@@ -60,6 +67,8 @@ where
 /// # Description
 /// Sorts the given slice in-place using a basic partition quick‑sort algorithm
 /// whith comparator.
+/// The pivot is chosen by `choose_pivot_by`, the median of three or the ninther
+/// for slices longer than `NINTHER_THRESHOLD`.
 ///
 /// # Type Parameters
 /// - `T`: The element type.
@@ -72,6 +81,7 @@ where
 /// # Panics
 /// Panics if calculating partition indices overflows (only for very large slices).
 /// Panics if the implementation of 'comp' panics.///
+/// In debug builds, panics if `comp` is not a consistent total order.
 ///
 /// # Examples
 /// ```
@@ -85,6 +95,10 @@ where
     F: FnMut(&T, &T) -> std::cmp::Ordering,
 {
     quick_sort_by_comp(slice, &mut comp);
+    debug_assert!(
+        slice.is_sorted_by(|a, b| comp(a, b).is_le()),
+        "binary_quick_sort_by: comparator is not a consistent total order"
+    );
 }
 
 #[cfg(test)]
@@ -152,4 +166,27 @@ mod tests {
 
         assert!(vec.is_sorted_by(|&a, &b| { a > b }));
     }
+
+    #[test]
+    fn test_binary_quick_sort_patterns() {
+        // the pivot at the end made these quadratic, with O(n) recursion depth
+        let len = 100_000;
+        let sorted: Vec<i32> = (0..len).collect();
+        let reversed: Vec<i32> = (0..len).rev().collect();
+        let pipe_organ: Vec<i32> = (0..len).map(|x| x.min(len - x)).collect();
+        let sawtooth: Vec<i32> = (0..len).map(|x| x % 64).collect();
+
+        for mut vec in [sorted, reversed, pipe_organ, sawtooth] {
+            let mut expected = vec.clone();
+            expected.sort();
+            binary_quick_sort(&mut vec);
+            assert_eq!(vec, expected);
+        }
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn test_binary_quick_sort_by_inconsistent_comparator() {
+        test_data::check_inconsistent_comparator(binary_quick_sort_by);
+    }
 }
@@ -0,0 +1,256 @@
+use crate::pivot::choose_pivot_by;
+
+/// # Description
+/// Partitions the given slice using Hoare's partition scheme.
+///
+/// # Type Parameters
+/// - `T`: The element type. Must implement `Ord`.
+///
+/// # Parameters
+/// - `slice`: The mutable slice to partition.
+///
+/// # Returns
+/// The split index `p`. See `hoare_partition_by`.
+///
+/// # Panics
+/// Panics if the implementation of Ord panics.
+///
+/// # Examples
+/// ```
+/// use quick_sort::hoare_partition;
+/// let mut v = vec![5, 1, 4, 2, 3];
+/// let p = hoare_partition(&mut v);
+/// assert!(v[..p].iter().all(|l| v[p..].iter().all(|r| l <= r)));
+/// ```
+pub fn hoare_partition<T: Ord>(slice: &mut [T]) -> usize {
+    hoare_partition_by(slice, T::cmp)
+}
+
+/// # Description
+/// Partitions the given slice using Hoare's partition scheme with comparator.
+/// The pivot is chosen by `choose_pivot_by`, the median of three or the ninther
+/// for slices longer than `NINTHER_THRESHOLD`.
+///
+/// Two indices scan from both ends toward each other, the left one stops at an element
+/// not less than the pivot and the right one stops at an element not greater than the pivot,
+/// then the two elements are swapped. Only misplaced pairs are swapped,
+/// so it does about three times fewer swaps than Lomuto's partition on average.
+///
+/// Unlike Lomuto's partition, the pivot does not end up in its final position.
+/// The result only guarantees that every element of `slice[..p]` is not greater than
+/// every element of `slice[p..]`, and the pivot may be in either part.
+/// So the recursion must cover both `slice[..p]` and `slice[p..]`, instead of skipping the pivot.
+///
+/// # Type Parameters
+/// - `T`: The element type.
+/// - `F`: The comparator type. Must implement 'FnMut' trait.
+///
+/// # Parameters
+/// - `slice`: The mutable slice to partition.
+/// - `comp`: The callable object to compare two &T data.
+///
+/// # Returns
+/// The split index `p`. If the slice has at least two elements, `0 < p < slice.len()`,
+/// so both parts are not empty. Otherwise, it returns 0.
+///
+/// # Panics
+/// Panics if the implementation of 'comp' panics.
+/// May panic if `comp` is not a consistent total order, since the scans rely on the pivot to stop.
+///
+/// # Examples
+/// ```
+/// use quick_sort::hoare_partition_by;
+/// let mut v = vec![5, 1, 4, 2, 3];
+/// let p = hoare_partition_by(&mut v, |a: &i32, b: &i32| b.cmp(a));
+/// assert!(v[..p].iter().all(|l| v[p..].iter().all(|r| l >= r)));
+/// ```
+pub fn hoare_partition_by<T, F>(slice: &mut [T], mut comp: F) -> usize
+where
+    F: FnMut(&T, &T) -> std::cmp::Ordering,
+{
+    let len = slice.len();
+    if len < 2 {
+        return 0;
+    }
+
+    // the pivot is moved by the swaps, so its position is tracked
+    let mut pivot = 0;
+    let chosen = choose_pivot_by(slice, &mut comp);
+    slice.swap(0, chosen);
+    let (mut i, mut j) = (0, len - 1);
+    loop {
+        // the pivot itself stops both scans, so they never run out of the slice
+        while comp(&slice[i], &slice[pivot]).is_lt() {
+            i += 1;
+        }
+        while comp(&slice[j], &slice[pivot]).is_gt() {
+            j -= 1;
+        }
+        if i >= j {
+            return j + 1;
+        }
+
+        slice.swap(i, j);
+        if pivot == i {
+            pivot = j;
+        } else if pivot == j {
+            pivot = i;
+        }
+        i += 1;
+        j -= 1;
+    }
+}
+
+/// # Description
+/// Sorts the given slice in-place using a quick‑sort algorithm with Hoare's partition.
+///
+/// # Type Parameters
+/// - `T`: The element type. Must implement `Ord`.
+///
+/// # Parameters
+/// - `slice`: The mutable slice to sort.
+///
+/// # Panics
+/// Panics if the implementation of Ord panics.
+///
+/// # Examples
+/// ```
+/// use quick_sort::hoare_quick_sort;
+/// let mut v = vec![3, 1, 4, 1, 5];
+/// hoare_quick_sort(&mut v);
+/// assert_eq!(v, vec![1, 1, 3, 4, 5]);
+/// ```
+pub fn hoare_quick_sort<T: Ord>(slice: &mut [T]) {
+    hoare_quick_sort_by(slice, T::cmp)
+}
+
+/// # Description
+/// Sorts the given slice in-place using a quick‑sort algorithm with Hoare's partition
+/// whith comparator.
+/// Recurses into the smaller part and loops on the larger one, so the stack depth is O(log n).
+///
+/// # Type Parameters
+/// - `T`: The element type.
+/// - `F`: The comparator type. Must implement 'FnMut' trait.
+///
+/// # Parameters
+/// - `slice`: The mutable slice to sort.
+/// - `comp`: The callable object to compare two &T data.
+///
+/// # Panics
+/// Panics if the implementation of 'comp' panics.
+/// In debug builds, panics if `comp` is not a consistent total order.
+///
+/// # Examples
+/// ```
+/// use quick_sort::hoare_quick_sort_by;
+/// let mut v = vec![3, 1, 4, 1, 5];
+/// hoare_quick_sort_by(&mut v, |a: &i32, b: &i32| b.cmp(a));
+/// assert_eq!(v, vec![5, 4, 3, 1, 1]);
+/// ```
+pub fn hoare_quick_sort_by<T, F>(slice: &mut [T], mut comp: F)
+where
+    F: FnMut(&T, &T) -> std::cmp::Ordering,
+{
+    hoare_quick_by(slice, &mut comp);
+    debug_assert!(
+        slice.is_sorted_by(|a, b| comp(a, b).is_le()),
+        "hoare_quick_sort_by: comparator is not a consistent total order"
+    );
+}
+
+fn hoare_quick_by<T, F>(mut slice: &mut [T], comp: &mut F)
+where
+    F: FnMut(&T, &T) -> std::cmp::Ordering,
+{
+    while slice.len() > 1 {
+        let p = hoare_partition_by(slice, &mut *comp);
+        // both parts are not empty, and the pivot is not excluded
+        let (left, right) = slice.split_at_mut(p);
+        let (smaller, larger) = match left.len() < right.len() {
+            true => (left, right),
+            false => (right, left),
+        };
+        hoare_quick_by(smaller, comp);
+        slice = larger;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    const TEST_SIZE: usize = 10_000;
+
+    fn is_bipartitioned(slice: &[i32], p: usize) -> bool {
+        let left_max = slice[..p].iter().max();
+        let right_min = slice[p..].iter().min();
+        match (left_max, right_min) {
+            (Some(l), Some(r)) => l <= r,
+            _ => true,
+        }
+    }
+
+    #[test]
+    fn test_hoare_partition() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        // every length up to 64, with few and many distinct values
+        for len in 0..64 {
+            for range in [2, 8, 1000] {
+                let mut vec: Vec<i32> = (0..len).map(|_| rng.random_range(0..range)).collect();
+                let mut expected = vec.clone();
+                let p = hoare_partition(&mut vec);
+
+                assert!(is_bipartitioned(&vec, p));
+                match len < 2 {
+                    true => assert_eq!(p, 0),
+                    false => assert!(0 < p && p < vec.len()),
+                }
+                // a permutation of the input
+                vec.sort();
+                expected.sort();
+                assert_eq!(vec, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_hoare_quick_sort() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let random: Vec<i32> = (0..TEST_SIZE).map(|_| rng.random()).collect();
+        let duplicates: Vec<i32> = (0..TEST_SIZE).map(|_| rng.random_range(0..4)).collect();
+        let sorted: Vec<i32> = (0..TEST_SIZE as i32).collect();
+        let reversed: Vec<i32> = (0..TEST_SIZE as i32).rev().collect();
+        // the largest element in the middle, quadratic with the middle element as the pivot
+        let pipe_organ: Vec<i32> = (0..TEST_SIZE as i32)
+            .map(|x| x.min(TEST_SIZE as i32 - x))
+            .collect();
+
+        for input in [random, duplicates, sorted, reversed, pipe_organ] {
+            let mut expected = input.clone();
+            expected.sort();
+
+            let mut vec = input.clone();
+            hoare_quick_sort(&mut vec);
+            assert_eq!(vec, expected);
+
+            let mut vec = input;
+            hoare_quick_sort_by(&mut vec, |a: &i32, b: &i32| b.cmp(a));
+            expected.reverse();
+            assert_eq!(vec, expected);
+        }
+
+        let mut empty: Vec<i32> = vec![];
+        hoare_quick_sort(&mut empty);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn test_hoare_quick_sort_by_inconsistent_comparator() {
+        test_data::check_inconsistent_comparator(hoare_quick_sort_by);
+    }
+}
@@ -1,7 +1,13 @@
 //! # Description
 //! Implementation of quick-sort algorithms.
+mod adaptive_quick_sort;
 mod binary_quick_sort;
+mod hoare_quick_sort;
+mod pivot;
 mod ternary_quick_sort;
 
+pub use crate::adaptive_quick_sort::*;
 pub use crate::binary_quick_sort::*;
+pub use crate::hoare_quick_sort::*;
+pub use crate::pivot::*;
 pub use crate::ternary_quick_sort::*;
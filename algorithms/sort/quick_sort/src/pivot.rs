@@ -0,0 +1,165 @@
+use odd_even_merge_sort::{sort3_at_by, sort5_at_by};
+
+/// # Description
+/// Length above which `choose_pivot_by` uses the ninther instead of the median of three.
+/// Below it, the extra six comparisons of the ninther are not paid back by a better split.
+pub const NINTHER_THRESHOLD: usize = 128;
+
+/// # Description
+/// Chooses a pivot of the slice and returns its index.
+/// - `len <= NINTHER_THRESHOLD` : the median of the first, middle and last elements.
+/// - `len > NINTHER_THRESHOLD` : the ninther, the median of the medians of three
+///   around 1/4, 1/2 and 3/4 of the slice.
+///
+/// The medians are found by `odd_even_merge_sort::sort3_at_by`, so the sampled elements are reordered among themselves.
+/// The median of three is defeated by inputs like pipe organ, which put the largest element in the middle,
+/// while the ninther needs five of the nine samples to be on the same side.
+/// A slice shorter than three has no median, its middle index is returned.
+///
+/// # Type Parameters
+/// - `T`: The element type.
+/// - `F`: The comparator type. Must implement 'FnMut' trait.
+///
+/// # Parameters
+/// - `slice`: The mutable slice to choose a pivot from.
+/// - `comp`: The callable object to compare two &T data.
+///
+/// # Panics
+/// Panics if the implementation of 'comp' panics.
+///
+/// # Examples
+/// ```
+/// use quick_sort::choose_pivot_by;
+/// let mut v = vec![5, 1, 9, 3, 7];
+/// let p = choose_pivot_by(&mut v, &mut i32::cmp);
+/// assert_eq!(v[p], 7);
+/// ```
+pub fn choose_pivot_by<T, F>(slice: &mut [T], comp: &mut F) -> usize
+where
+    F: FnMut(&T, &T) -> std::cmp::Ordering,
+{
+    let len = slice.len();
+    let mid = len / 2;
+    if len < 3 {
+        return mid;
+    }
+
+    if len > NINTHER_THRESHOLD {
+        let (quarter, three_quarters) = (len / 4, len / 4 * 3);
+        sort3_at_by(slice, [quarter - 1, quarter, quarter + 1], &mut *comp);
+        sort3_at_by(slice, [mid - 1, mid, mid + 1], &mut *comp);
+        sort3_at_by(
+            slice,
+            [three_quarters - 1, three_quarters, three_quarters + 1],
+            &mut *comp,
+        );
+        sort3_at_by(slice, [quarter, mid, three_quarters], &mut *comp);
+    } else {
+        sort3_at_by(slice, [0, mid, len - 1], &mut *comp);
+    }
+    mid
+}
+
+/// # Description
+/// Chooses two pivots of the slice for a dual-pivot partition and returns their indices `(low, high)`,
+/// with `low < high` and `slice[low] <= slice[high]` by comp.
+///
+/// Five samples are taken evenly around the middle of the slice and sorted by
+/// `odd_even_merge_sort::sort5_at_by`, then the second and the fourth become the pivots,
+/// so each pivot is near a tertile of the slice, and sorted or pipe organ input is split into three similar parts.
+/// For `len > NINTHER_THRESHOLD`, each sample is first replaced by the median of itself and its two neighbours,
+/// like the ninther of `choose_pivot_by`.
+/// A slice shorter than five returns its first and last indices.
+///
+/// # Type Parameters
+/// - `T`: The element type.
+/// - `F`: The comparator type. Must implement 'FnMut' trait.
+///
+/// # Parameters
+/// - `slice`: The mutable slice to choose pivots from.
+/// - `comp`: The callable object to compare two &T data.
+///
+/// # Panics
+/// Panics if the slice is shorter than two.
+/// Panics if the implementation of 'comp' panics.
+///
+/// # Examples
+/// ```
+/// use quick_sort::choose_two_pivots_by;
+/// let mut v: Vec<i32> = (0..10).collect();
+/// let (low, high) = choose_two_pivots_by(&mut v, &mut i32::cmp);
+/// assert_eq!((v[low], v[high]), (3, 7));
+/// ```
+pub fn choose_two_pivots_by<T, F>(slice: &mut [T], comp: &mut F) -> (usize, usize)
+where
+    F: FnMut(&T, &T) -> std::cmp::Ordering,
+{
+    let len = slice.len();
+    assert!(len >= 2, "choose_two_pivots_by: slice is shorter than two");
+    if len < 5 {
+        return (0, len - 1);
+    }
+
+    let (mid, step) = (len / 2, len / 5);
+    let samples = [mid - 2 * step, mid - step, mid, mid + step, mid + 2 * step];
+    if len > NINTHER_THRESHOLD {
+        for at in samples {
+            sort3_at_by(slice, [at - 1, at, at + 1], &mut *comp);
+        }
+    }
+    sort5_at_by(slice, samples, &mut *comp);
+    (samples[1], samples[3])
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_choose_pivot_by() {
+        // median of three below the threshold
+        let mut v: Vec<i32> = (0..NINTHER_THRESHOLD as i32).rev().collect();
+        let p = choose_pivot_by(&mut v, &mut i32::cmp);
+        assert_eq!(v[p], NINTHER_THRESHOLD as i32 / 2 - 1);
+
+        // the largest element in the middle does not become the pivot
+        let len = 1000;
+        let mut v: Vec<i32> = (0..len).map(|x| x.min(len - x)).collect();
+        let p = choose_pivot_by(&mut v, &mut i32::cmp);
+        assert!(len / 8 <= v[p] && v[p] < len / 2);
+
+        // the ninther of sorted input is exactly its median
+        let mut v: Vec<i32> = (0..len).collect();
+        let p = choose_pivot_by(&mut v, &mut i32::cmp);
+        assert_eq!(v[p], len / 2);
+        assert!(v.is_sorted());
+
+        for len in 0..3 {
+            let mut v: Vec<i32> = (0..len).collect();
+            assert_eq!(choose_pivot_by(&mut v, &mut i32::cmp), len as usize / 2);
+        }
+    }
+
+    #[test]
+    fn test_choose_two_pivots_by() {
+        // sorted and pipe organ input are split near the tertiles
+        let len = 1000;
+        let sorted: Vec<i32> = (0..len).collect();
+        let organ_pipe: Vec<i32> = (0..len).map(|x| x.min(len - x)).collect();
+        for mut v in [sorted, organ_pipe] {
+            let max = *v.iter().max().unwrap();
+            let (low, high) = choose_two_pivots_by(&mut v, &mut i32::cmp);
+            assert!(low < high && v[low] <= v[high]);
+            assert!(max / 8 <= v[low] && v[low] < max / 2);
+            assert!(max / 2 < v[high] && v[high] <= max - max / 8);
+        }
+
+        for len in 2..5 {
+            let mut v: Vec<i32> = (0..len).rev().collect();
+            assert_eq!(
+                choose_two_pivots_by(&mut v, &mut i32::cmp),
+                (0, len as usize - 1)
+            );
+        }
+    }
+}
@@ -1,3 +1,7 @@
+use std::ops::Range;
+
+use crate::pivot::choose_two_pivots_by;
+
 /// # Description
 /// Partition slice in 3 part and return it's delimeter.
 /// This function is based on Dijkstra's Dutch national flag algorithm.
@@ -111,24 +115,186 @@ where
     (i, j)
 }
 
-fn ternary_quick_by<T, F>(slice: &mut [T], comp: &mut F)
+/// # Description
+/// Partition slice in 3 regions by a single pivot and return each region explicitly.
+/// This function is based on Dijkstra's Dutch national flag algorithm.
+/// The pivot is the first element of the slice.
+///
+/// Returns `(less, equal, greater)` index ranges,
+/// which are disjoint, contiguous and cover the whole slice in that order.
+/// - `less` : elements smaller than the pivot.
+/// - `equal` : elements equal to the pivot, not empty unless the slice is empty.
+/// - `greater` : elements bigger than the pivot.
+///
+/// # Type Parameters
+/// - `T`: The element type. Must implement `Ord`.
+///
+/// # Parameters
+/// - `slice`: The mutable slice to partition.
+///
+/// # Panics
+/// Panics if the implementation of Ord panics.
+///
+/// # Examples
+/// ```
+/// use quick_sort::ternary_partition_regions;
+/// let mut v = vec![3, 1, 4, 3, 5, 2];
+/// let (less, equal, greater) = ternary_partition_regions(&mut v);
+/// assert_eq!((less, equal.clone(), greater), (0..2, 2..4, 4..6));
+/// assert_eq!(v[equal], [3, 3]);
+/// ```
+pub fn ternary_partition_regions<T: Ord>(
+    slice: &mut [T],
+) -> (Range<usize>, Range<usize>, Range<usize>) {
+    ternary_partition_regions_by(slice, &mut T::cmp)
+}
+
+/// # Description
+/// Partition slice in 3 regions by a single pivot and return each region explicitly.
+/// This function is based on Dijkstra's Dutch national flag algorithm.
+/// The pivot is the first element of the slice.
+/// Use comp to identify it's order.
+///
+/// Returns `(less, equal, greater)` index ranges,
+/// which are disjoint, contiguous and cover the whole slice in that order.
+///
+/// # Type Parameters
+/// - `T`: The element type.
+/// - 'F': The comparator type. Must implement 'FnMut'.
+///
+/// # Parameters
+/// - `slice`: The mutable slice to partition.
+/// - `comp`: The callable object to compare two &T data.
+///
+/// # Panics
+/// Panics if the implementation of comp panics.
+///
+/// # Examples
+/// ```
+/// use quick_sort::ternary_partition_regions_by;
+/// let mut v = vec![3, 1, 4, 3, 5, 2];
+/// let (less, equal, greater) = ternary_partition_regions_by(&mut v, &mut |a: &i32, b: &i32| b.cmp(a));
+/// assert_eq!((less, equal, greater), (0..2, 2..4, 4..6));
+/// ```
+pub fn ternary_partition_regions_by<T, F>(
+    slice: &mut [T],
+    comp: &mut F,
+) -> (Range<usize>, Range<usize>, Range<usize>)
 where
     F: FnMut(&T, &T) -> std::cmp::Ordering,
 {
-    if slice.len() <= 1 {
-        return;
+    use std::cmp::Ordering as O;
+    let len = slice.len();
+
+    // [0, lt) : smaller than pivot
+    // [lt, i) : equal to pivot, slice[lt] is always the pivot
+    // [i, gt) : not visited
+    // [gt, len) : bigger than pivot
+    let mut lt = 0usize;
+    let mut i = 1usize;
+    let mut gt = len;
+    while i < gt {
+        match comp(&slice[i], &slice[lt]) {
+            O::Less => {
+                slice.swap(lt, i);
+                lt += 1;
+                i += 1;
+            }
+            O::Greater => {
+                gt -= 1;
+                slice.swap(i, gt);
+            }
+            O::Equal => {
+                i += 1;
+            }
+        }
     }
+    let gt = gt.max(lt); // gt can be smaller than lt only if the slice is empty
+    (0..lt, lt..gt, gt..len)
+}
+
+/// # Description
+/// Split slice into 3 subslices around two excluded positions `a` and `b`.
+/// Returns `(&slice[..a], &slice[a + 1..b], &slice[b + 1..])`, without the elements at `a` and `b`.
+/// Each subslice can be empty, so the pivot at index 0 or `len - 1` is handled uniformly.
+///
+/// For the result `(i, j)` of [`ternary_partition_by`], the pivots are at `i - 1` and `j`,
+/// so `split_three(slice, i - 1, j)` returns the left, mid and right parts.
+///
+/// # Type Parameters
+/// - `T`: The element type.
+///
+/// # Parameters
+/// - `slice`: The mutable slice to split.
+/// - `a`: The position of the first excluded element.
+/// - `b`: The position of the second excluded element.
+///
+/// # Panics
+/// Panics unless `a < b < slice.len()`.
+///
+/// # Examples
+/// ```
+/// use quick_sort::split_three;
+/// let mut v = vec![0, 1, 2, 3, 4, 5];
+/// let (left, mid, right) = split_three(&mut v, 0, 3);
+/// assert_eq!((&*left, &*mid, &*right), (&[][..], &[1, 2][..], &[4, 5][..]));
+/// ```
+pub fn split_three<T>(slice: &mut [T], a: usize, b: usize) -> (&mut [T], &mut [T], &mut [T]) {
+    assert!(
+        a < b && b < slice.len(),
+        "split_three: excluded positions are out of range"
+    );
+    let (left, rest) = slice.split_at_mut(a);
+    let (mid, rest) = rest[1..].split_at_mut(b - a - 1);
+    (left, mid, &mut rest[1..])
+}
+
+/// Recurse into the smaller parts and loop on the largest one.
+/// Every part other than the largest is at most half of the slice,
+/// so the recursion depth is bounded by O(log n) even on adversarial inputs.
+fn ternary_quick_by<T, F>(mut slice: &mut [T], comp: &mut F)
+where
+    F: FnMut(&T, &T) -> std::cmp::Ordering,
+{
+    while slice.len() > 1 {
+        // move the chosen pivots to the front and the back, where the partitions take them
+        let (low, high) = choose_two_pivots_by(slice, comp);
+        let last = slice.len() - 1;
+        slice.swap(0, low);
+        slice.swap(last, high);
+
+        // Equal pivots leave every element equal to them in the left part,
+        // which degrades to O(n^2) on inputs with many duplicates.
+        // Partition by the single pivot instead, so that the equal region is excluded at once.
+        if comp(&slice[0], &slice[slice.len() - 1]).is_eq() {
+            let (less, _, greater) = ternary_partition_regions_by(slice, comp);
+            let (rest, greater) = slice.split_at_mut(greater.start);
+            let less = &mut rest[less];
+            let (smaller, larger) = match less.len() < greater.len() {
+                true => (less, greater),
+                false => (greater, less),
+            };
+            ternary_quick_by(smaller, comp);
+            slice = larger;
+            continue;
+        }
 
-    let (pivot1, pivot2) = ternary_partition_by(slice, comp);
+        let (pivot1, pivot2) = ternary_partition_by(slice, comp);
+        let (left, mid, right) = split_three(slice, pivot1 - 1, pivot2);
 
-    ternary_quick_by(&mut slice[0..pivot1 - 1], comp);
-    ternary_quick_by(&mut slice[pivot1..pivot2], comp);
-    ternary_quick_by(&mut slice[pivot2 + 1..], comp);
+        let mut parts = [left, mid, right];
+        parts.sort_unstable_by_key(|part| part.len());
+        let [smallest, middle, largest] = parts;
+        ternary_quick_by(smallest, comp);
+        ternary_quick_by(middle, comp);
+        slice = largest;
+    }
 }
 
 /// # Description
 /// Sorts the given slice in-place using a three-way partition quick‑sort algorithm
 /// whith comparator.
+/// The two pivots are chosen by `choose_two_pivots_by`, near the tertiles of five sorted samples.
 ///
 /// # Type Parameters
 /// - `T`: The element type.
@@ -141,6 +307,7 @@ where
 /// # Panics
 /// Panics if calculating partition indices overflows (only for very large slices).
 /// Panics if the implementation of 'comp' panics.///
+/// In debug builds, panics if `comp` is not a consistent total order.
 ///
 /// # Examples
 /// ```
@@ -154,6 +321,10 @@ where
     F: FnMut(&T, &T) -> std::cmp::Ordering,
 {
     ternary_quick_by(slice, &mut comp);
+    debug_assert!(
+        slice.is_sorted_by(|a, b| comp(a, b).is_le()),
+        "ternary_quick_sort_by: comparator is not a consistent total order"
+    );
 }
 
 #[cfg(test)]
@@ -161,6 +332,7 @@ mod tests {
 
     use crate::*;
     use std::cmp::Reverse;
+    use std::ops::Range;
 
     use rand::distr::StandardUniform;
     use rand::rngs::StdRng;
@@ -172,7 +344,7 @@ mod tests {
         let len = slice.len();
 
         for i in 0..pivot_pos.0 {
-            if !(slice[i] <= slice[pivot_pos.0 - 1]) {
+            if slice[i] > slice[pivot_pos.0 - 1] {
                 return false;
             }
         }
@@ -182,7 +354,7 @@ mod tests {
             }
         }
         for i in pivot_pos.1..len {
-            if !(slice[pivot_pos.1] <= slice[i]) {
+            if slice[pivot_pos.1] > slice[i] {
                 return false;
             }
         }
@@ -224,4 +396,176 @@ mod tests {
 
         assert!(vec.is_sorted_by(|&a, &b| { a > b }));
     }
+
+    #[test]
+    fn test_ternary_quick_sort_patterns() {
+        test_data::check_sort(ternary_quick_sort);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn test_ternary_quick_sort_by_inconsistent_comparator() {
+        test_data::check_inconsistent_comparator(ternary_quick_sort_by);
+    }
+
+    fn check_regions(slice: &[i32], regions: (Range<usize>, Range<usize>, Range<usize>)) {
+        let (less, equal, greater) = regions;
+
+        // disjoint and contiguous
+        assert_eq!(less.start, 0);
+        assert_eq!(less.end, equal.start);
+        assert_eq!(equal.end, greater.start);
+        assert_eq!(greater.end, slice.len());
+
+        // ordered relative to the pivot
+        if slice.is_empty() {
+            return;
+        }
+        assert!(!equal.is_empty());
+        let pivot = slice[equal.start];
+        assert!(slice[less].iter().all(|&x| x < pivot));
+        assert!(slice[equal].iter().all(|&x| x == pivot));
+        assert!(slice[greater].iter().all(|&x| x > pivot));
+    }
+
+    #[test]
+    fn test_partition_regions() {
+        let seed: u64 = 42;
+        let rng = StdRng::seed_from_u64(seed);
+
+        let mut vec: Vec<i32> = rng
+            .sample_iter(StandardUniform)
+            .take(100)
+            .map(|x: i32| x % 10)
+            .collect();
+        let pivot = vec[0];
+        let pivot_cnt = vec.iter().filter(|&&x| x == pivot).count();
+
+        let regions = ternary_partition_regions(&mut vec);
+        assert_eq!(regions.1.len(), pivot_cnt);
+        check_regions(&vec, regions);
+    }
+
+    #[test]
+    fn test_partition_regions_edge() {
+        let mut empty: Vec<i32> = vec![];
+        let regions = ternary_partition_regions(&mut empty);
+        assert_eq!(regions, (0..0, 0..0, 0..0));
+
+        let mut single = vec![1];
+        let regions = ternary_partition_regions(&mut single);
+        assert_eq!(regions, (0..0, 0..1, 1..1));
+
+        let mut all_equal = vec![7; 10];
+        let regions = ternary_partition_regions(&mut all_equal);
+        assert_eq!(regions, (0..0, 0..10, 10..10));
+
+        let mut min_pivot = vec![0, 5, 4, 3, 2, 1];
+        let regions = ternary_partition_regions(&mut min_pivot);
+        check_regions(&min_pivot, regions.clone());
+        assert_eq!(regions, (0..0, 0..1, 1..6));
+
+        let mut max_pivot = vec![5, 0, 1, 2, 3, 4];
+        let regions = ternary_partition_regions(&mut max_pivot);
+        check_regions(&max_pivot, regions.clone());
+        assert_eq!(regions, (0..5, 5..6, 6..6));
+    }
+
+    #[test]
+    fn test_ternary_quick_sort_duplicates() {
+        // all duplicates, the equal region is excluded at once
+        let mut all_equal = vec![3; 100_000];
+        let mut count = 0;
+        ternary_quick_sort_by(&mut all_equal, |a: &i32, b: &i32| {
+            count += 1;
+            a.cmp(b)
+        });
+        assert_eq!(all_equal, vec![3; 100_000]);
+        // one partition pass, and the consistency check in debug builds
+        assert!(count <= 3 * 100_000);
+
+        // few distinct values
+        let mut few: Vec<i32> = (0..100_000).map(|x| (x * 7) % 8).collect();
+        let mut expected = few.clone();
+        expected.sort();
+        ternary_quick_sort(&mut few);
+        assert_eq!(few, expected);
+    }
+
+    #[test]
+    fn test_split_three() {
+        let mut v: Vec<i32> = (0..6).collect();
+
+        let (left, mid, right) = split_three(&mut v, 0, 5);
+        assert_eq!(
+            (left.len(), mid, right.len()),
+            (0, &mut [1, 2, 3, 4][..], 0)
+        );
+
+        let (left, mid, right) = split_three(&mut v, 2, 3);
+        assert_eq!((&*left, mid.len(), &*right), (&[0, 1][..], 0, &[4, 5][..]));
+
+        let mut pair = vec![0, 1];
+        let (left, mid, right) = split_three(&mut pair, 0, 1);
+        assert!(left.is_empty() && mid.is_empty() && right.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "excluded positions are out of range")]
+    fn test_split_three_out_of_range() {
+        let mut v = vec![0, 1, 2];
+        split_three(&mut v, 1, 3);
+    }
+
+    #[test]
+    fn test_ternary_quick_sort_extreme_pivots() {
+        // both pivots are equal, every element goes to the left part
+        let mut all_equal = vec![7; 100];
+        ternary_quick_sort(&mut all_equal);
+        assert_eq!(all_equal, vec![7; 100]);
+
+        // pivot 1 stays at index 0
+        let mut unique_min_front: Vec<i32> =
+            (0..100).map(|x| if x == 0 { -1 } else { x % 10 }).collect();
+        let mut expected = unique_min_front.clone();
+        expected.sort();
+        ternary_quick_sort(&mut unique_min_front);
+        assert_eq!(unique_min_front, expected);
+
+        // pivot 2 stays at index len - 1
+        let mut unique_max_back: Vec<i32> = (0..100)
+            .map(|x| if x == 99 { 100 } else { x % 10 })
+            .collect();
+        let mut expected = unique_max_back.clone();
+        expected.sort();
+        ternary_quick_sort(&mut unique_max_back);
+        assert_eq!(unique_max_back, expected);
+
+        for len in 0..4 {
+            let mut small: Vec<i32> = (0..len).rev().collect();
+            ternary_quick_sort(&mut small);
+            assert!(small.is_sorted());
+        }
+    }
+
+    #[test]
+    fn test_ternary_quick_sort_bounded_stack() {
+        // with the first and last elements as pivots, sorted input would make them the min and max,
+        // so the middle part only loses two elements. the chosen pivots split it into three parts,
+        // and the loop on the largest part bounds the depth even when the pivots are bad.
+        let handle = std::thread::Builder::new()
+            .stack_size(128 * 1024)
+            .spawn(|| {
+                let len = 10_000;
+                let mut sorted: Vec<i32> = (0..len).collect();
+                ternary_quick_sort(&mut sorted);
+                assert_eq!(sorted, (0..len).collect::<Vec<i32>>());
+
+                let mut reversed: Vec<i32> = (0..len).rev().collect();
+                ternary_quick_sort(&mut reversed);
+                assert_eq!(reversed, (0..len).collect::<Vec<i32>>());
+            })
+            .unwrap();
+        handle.join().unwrap();
+    }
 }
@@ -6,6 +6,12 @@
 //! Radix Sort는 요소의 순차적 projection에 대한 Stable Counting Sort의 반복입니다.
 //! 따라서 한 타입 T에 대한 Radix Sort는 T의 projection을 나열함으로서 정의됩니다.
 //!
+//! # 안정성
+//! 본 모듈의 모든 Radix Sort는 stable합니다. 즉, 모든 projection의 값이 같은 두 요소는 정렬 전의 상대적 순서를 유지합니다.
+//! 이는 각 pass의 Counting Sort가 stable하다는 사실에 의존합니다.
+//! 뒤의 pass가 앞의 pass에서 만든 순서를 같은 키 안에서 보존해야 LSD 방식의 결과가 올바르므로,
+//! projection마다 stable한 pass를 사용하는 것은 정확성의 조건이기도 합니다.
+//!
 //! 본 모듈에서 projection은 `Fn(&T) -> usize` trait을 구현하는 함수로 정의됩니다.
 //! 해당 Trait을 구현하는 함수는 동일한 type을 가지지 못하므로, 서로다른 타입의 순차적 projection을 위해서는 일반적인 방법을 사용할 수 없습니다.
 //! 따라서, 본 모듈에서는 2가지 방법을 제공합니다.
@@ -88,6 +94,25 @@
 //! // ["", "app", "apple", "apricot", "banana", "bananaaa"]
 //! ```
 //!
+//! ## 3. 정수 키 정렬 (`radix_sort_by_key`)
+//!
+//! 단순한 정수 키의 경우 scheme을 직접 정의할 필요 없이 `radix_sort_by_key`를 사용할 수 있습니다.
+//! 키의 최대값으로부터 필요한 pass 수를 계산하여, `radix_bits` 비트씩 LSD부터 정렬합니다.
+//! 모든 키에서 0인 digit의 pass는 건너뛰므로, 키의 범위보다 넓은 `radix_bits`를 사용해도 낭비되는 pass가 없습니다.
+//!
+//! ```rust
+//! use radix_sort::radix_sort_by_key;
+//!
+//! let mut data = vec![(3, 'a'), (1000, 'b'), (0, 'c'), (3, 'd'), (42, 'e')];
+//! radix_sort_by_key(&mut data, 4, |x| x.0);
+//! assert_eq!(data, [(0, 'c'), (3, 'a'), (3, 'd'), (42, 'e'), (1000, 'b')]);
+//! ```
+//!
+//! ## 4. u128 키 정렬 (`u128_radix_scheme`)
+//!
+//! 128비트 키는 `usize` 하나로 projection할 수 없으므로, 여러 비트 구간의 projection을 LSD 순으로 나열해야 합니다.
+//! `u128_radix_scheme`은 이 projection 목록을 생성하며, `SerialScheme`과 함께 사용합니다.
+//!
 
 use counting_sort::CountingSortByKey;
 
@@ -215,9 +240,198 @@ impl<T> RadixSortExt<T> for &'_ mut [T] {
     }
 }
 
+/// scheme 없이 radix sort를 수행하기 위한 trait
+/// key의 폭이 정해진 `u8`, `u16` slice에 대해 필요한 pass 수를 자동으로 결정합니다.
+/// - `u8`: 1번의 counting sort pass
+/// - `u16`: 하위 byte, 상위 byte 순서로 2번의 counting sort pass
+///
+/// ```
+/// use radix_sort::RadixSortAutoExt;
+///
+/// let mut data: Vec<u16> = vec![0x1234, 0, 0xFFFF, 0x12, 0x1234];
+/// data.as_mut_slice().radix_sort_auto();
+/// assert_eq!(data, [0, 0x12, 0x1234, 0x1234, 0xFFFF]);
+/// ```
+pub trait RadixSortAutoExt {
+    fn radix_sort_auto(self);
+}
+
+impl RadixSortAutoExt for &'_ mut [u8] {
+    fn radix_sort_auto(self) {
+        self.counting_sort_by_key(|x| *x as usize);
+    }
+}
+
+impl RadixSortAutoExt for &'_ mut [u16] {
+    fn radix_sort_auto(self) {
+        self.radix_sort((|x: &u16| (*x & 0xFF) as usize, |x: &u16| (*x >> 8) as usize));
+    }
+}
+
+/// `key_fn`이 반환하는 usize 키를 기준으로 slice를 stable하게 정렬합니다.
+///
+/// 먼저 한 번의 순회로 모든 키의 bitwise OR `key_or`를 구하고, 필요한 pass 수를
+/// `key_or.ilog2() / radix_bits + 1`로 계산합니다. 이는 키의 최대값으로 계산한 pass 수와 같습니다.
+/// 각 pass에서는 키를 `radix_bits` 비트씩 잘라낸 digit에 대해 LSD부터 counting sort를 수행합니다.
+///
+/// `key_or`에서 해당 digit의 비트가 모두 0인 pass는 slice 전체에서 digit이 항상 0이므로,
+/// 순서를 바꾸지 않는 pass로서 수행하지 않습니다.
+/// 따라서 키가 작아 상위 digit이 비어있는 경우뿐 아니라, 키가 `2^k`의 배수여서 하위 digit이 비어있는 경우에도 pass가 줄어듭니다.
+/// 모든 키가 0인 경우 pass를 수행하지 않습니다.
+///
+/// `key_fn`은 pass마다 각 원소에 대해 다시 호출되므로, 같은 원소에 대해 항상 같은 값을 반환해야 합니다.
+///
+/// # Panics
+/// `radix_bits`가 0이거나 `usize::BITS` 이상인 경우.
+pub fn radix_sort_by_key<T, F>(slice: &mut [T], radix_bits: u32, key_fn: F)
+where
+    F: Fn(&T) -> usize,
+{
+    assert!(
+        0 < radix_bits && radix_bits < usize::BITS,
+        "radix_bits must be in 1..usize::BITS"
+    );
+
+    let key_or = slice.iter().map(&key_fn).fold(0, |acc, key| acc | key);
+    if key_or == 0 {
+        return;
+    }
+
+    let passes = key_or.ilog2() / radix_bits + 1;
+    let mask = (1usize << radix_bits) - 1;
+    for pass in 0..passes {
+        let shift = pass * radix_bits;
+        // 모든 원소의 digit이 0인 pass는 stable sort에서 아무것도 바꾸지 않음
+        if (key_or >> shift) & mask == 0 {
+            continue;
+        }
+        #[cfg(test)]
+        tests::PASS_CNT.with(|cnt| cnt.set(cnt.get() + 1));
+        slice.counting_sort_by_key(|x| (key_fn(x) >> shift) & mask);
+    }
+}
+
+/// `u128` 키를 `bits_per_pass` 비트씩 정렬하는 Serial Scheme의 projection 목록을 생성합니다.
+///
+/// projection은 LSD부터 MSD 순으로 나열되며, i번째 projection은 `(*x >> (i * bits_per_pass)) as usize & mask`입니다.
+/// 각 projection은 서로 겹치지 않는 비트 구간만을 보므로, pass 사이에 carry를 고려할 필요가 없습니다.
+/// `bits_per_pass`가 128을 나누지 않는 경우, 마지막 pass는 남은 상위 비트만을 보며 pass 수는 `128.div_ceil(bits_per_pass)`입니다.
+///
+/// 각 pass의 counting sort는 `2^bits_per_pass` 크기의 histogram을 사용하므로, 8 ~ 16 정도의 값이 적당합니다.
+///
+/// # Panics
+/// `bits_per_pass`가 0이거나 `usize::BITS` 이상인 경우.
+///
+/// # Examples
+/// ```
+/// use radix_sort::{u128_radix_scheme, RadixSortExt, SerialScheme};
+///
+/// let mut data: Vec<u128> = vec![u128::MAX, 1 << 100, 0, 42];
+/// let mut projections = u128_radix_scheme(12);
+/// data.as_mut_slice().radix_sort(SerialScheme::new(&mut projections));
+/// assert_eq!(data, [0, 42, 1 << 100, u128::MAX]);
+/// ```
+pub fn u128_radix_scheme(bits_per_pass: u32) -> Vec<Projection<u128>> {
+    assert!(
+        0 < bits_per_pass && bits_per_pass < usize::BITS,
+        "bits_per_pass must be in 1..usize::BITS"
+    );
+
+    let mask = (1usize << bits_per_pass) - 1;
+    (0..u128::BITS.div_ceil(bits_per_pass))
+        .map(|pass| {
+            let shift = pass * bits_per_pass;
+            // usize로 변환하며 잘리는 비트는 mask 바깥이므로 결과에 영향을 주지 않습니다.
+            Box::new(move |x: &u128| (*x >> shift) as usize & mask) as Projection<u128>
+        })
+        .collect()
+}
+
+/// 고정 폭 정수 타입에 대해 byte 단위(radix 256) LSD radix sort 함수를 정의하기 위한 매크로.
+/// closure를 통한 projection 없이, 256개 항목의 고정 크기 histogram을 사용하여 byte마다 stable counting sort를 수행합니다.
+/// 모든 byte의 histogram은 처음 한 번의 순회로 계산하고, 각 pass는 slice와 scratch buffer를 번갈아가며(ping-pong) 원본과 목적지로 사용합니다.
+/// 모든 원소의 해당 byte가 같은 pass는 순서를 바꾸지 않으므로 건너뜁니다.
+macro_rules! impl_radix256_sort {
+    ($name:ident, $t:ty) => {
+        #[doc = concat!("`", stringify!($t), "` slice를 byte 단위 LSD radix sort로 정렬합니다.")]
+        ///
+        #[doc = concat!("`", stringify!($t), "`의 byte 수만큼 stable counting sort pass를 수행하며, slice와 같은 크기의 scratch buffer를 할당합니다.")]
+        /// 원소마다 projection closure를 호출하는 Tuple Scheme보다 빠르게 동작합니다.
+        ///
+        /// # Examples
+        /// ```
+        #[doc = concat!("use radix_sort::", stringify!($name), ";")]
+        ///
+        #[doc = concat!("let mut data: Vec<", stringify!($t), "> = vec![42, ", stringify!($t), "::MAX, 0, 256, 1];")]
+        #[doc = concat!(stringify!($name), "(&mut data);")]
+        #[doc = concat!("assert_eq!(data, [0, 1, 42, 256, ", stringify!($t), "::MAX]);")]
+        /// ```
+        pub fn $name(slice: &mut [$t]) {
+            let len = slice.len();
+            if len <= 1 {
+                return;
+            }
+
+            const BYTES: usize = size_of::<$t>();
+            // 모든 byte의 histogram을 한 번의 순회로 계산합니다.
+            let mut counters = [[0usize; 256]; BYTES];
+            for &x in slice.iter() {
+                for (byte, counter) in counters.iter_mut().enumerate() {
+                    counter[((x >> (byte * 8)) & 0xFF) as usize] += 1;
+                }
+            }
+
+            let mut buffer: Vec<$t> = vec![0; len];
+            // true인 경우 정렬 중인 데이터가 buffer에 있습니다.
+            let mut in_buffer = false;
+            for (byte, counter) in counters.iter_mut().enumerate() {
+                let shift = byte * 8;
+                let digit = |x: $t| ((x >> shift) & 0xFF) as usize;
+                if counter[digit(slice[0])] == len {
+                    // 모든 원소의 digit이 같으므로 순서가 바뀌지 않습니다.
+                    continue;
+                }
+
+                // 각 digit의 시작 위치
+                let mut offset = 0;
+                for count in counter.iter_mut() {
+                    let next = offset + *count;
+                    *count = offset;
+                    offset = next;
+                }
+                let (src, dst) = match in_buffer {
+                    false => (&*slice, buffer.as_mut_slice()),
+                    true => (buffer.as_slice(), &mut *slice),
+                };
+                for &x in src.iter() {
+                    let d = digit(x);
+                    dst[counter[d]] = x;
+                    counter[d] += 1;
+                }
+                in_buffer = !in_buffer;
+            }
+
+            if in_buffer {
+                slice.copy_from_slice(&buffer);
+            }
+        }
+    };
+}
+
+impl_radix256_sort!(radix256_sort_u32, u32);
+impl_radix256_sort!(radix256_sort_u64, u64);
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use std::cell::Cell;
+
+    thread_local! {
+        /// number of counting sort passes run by radix_sort_by_key in the current thread
+        pub static PASS_CNT: Cell<usize> = const { Cell::new(0) };
+    }
 
     // helper to compare with standard sort for u32
     fn check_sorted_u32(data: &mut [u32]) {
@@ -378,4 +592,231 @@ mod tests {
         ];
         assert_eq!(v, expected);
     }
+
+    #[test]
+    fn radix_sort_by_key_random() {
+        let seed: u64 = 42;
+        let mut rng = StdRng::seed_from_u64(seed);
+
+        for radix_bits in [1, 3, 4, 8, 11, 16] {
+            let mut v: Vec<(usize, usize)> =
+                (0..1000).map(|i| (rng.random_range(0..5000), i)).collect();
+            let mut expected = v.clone();
+            expected.sort_by_key(|x| x.0); // stable
+
+            radix_sort_by_key(&mut v, radix_bits, |x| x.0);
+            assert_eq!(v, expected);
+        }
+
+        // 큰 키
+        let mut v: Vec<usize> = (0..500).map(|_| rng.random::<u64>() as usize).collect();
+        v.push(usize::MAX);
+        let mut expected = v.clone();
+        expected.sort();
+        radix_sort_by_key(&mut v, 8, |x| *x);
+        assert_eq!(v, expected);
+    }
+
+    #[test]
+    fn radix_sort_by_key_all_zero() {
+        let mut v: Vec<(usize, char)> = vec![(0, 'c'), (0, 'a'), (0, 'b')];
+        radix_sort_by_key(&mut v, 8, |x| x.0);
+        assert_eq!(v, [(0, 'c'), (0, 'a'), (0, 'b')]);
+
+        let mut empty: Vec<usize> = vec![];
+        radix_sort_by_key(&mut empty, 8, |x| *x);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn radix_sort_by_key_skips_zero_digits() {
+        let seed: u64 = 42;
+        let mut rng = StdRng::seed_from_u64(seed);
+
+        // 4비트씩 16 pass까지 가능한 scheme에 4비트 이하의 키
+        let mut v: Vec<(usize, usize)> = (0..1000).map(|i| (rng.random_range(0..16), i)).collect();
+        let mut expected = v.clone();
+        expected.sort_by_key(|x| x.0);
+
+        PASS_CNT.with(|cnt| cnt.set(0));
+        radix_sort_by_key(&mut v, 4, |x| x.0);
+        assert_eq!(PASS_CNT.with(|cnt| cnt.get()), 1);
+        assert_eq!(v, expected);
+
+        // 하위 16비트와 중간 digit이 비어있는 키: 0x00ff_0000 영역과 bit 40만 사용
+        let mut v: Vec<(usize, usize)> = (0..1000)
+            .map(|i| {
+                let k = rng.random::<u64>() as usize;
+                ((k & 0xff) << 16 | (k & 0x100) << 32, i)
+            })
+            .collect();
+        let mut expected = v.clone();
+        expected.sort_by_key(|x| x.0);
+
+        PASS_CNT.with(|cnt| cnt.set(0));
+        radix_sort_by_key(&mut v, 8, |x| x.0);
+        // 6개의 pass 중 digit 2와 digit 5만 수행
+        assert_eq!(PASS_CNT.with(|cnt| cnt.get()), 2);
+        assert_eq!(v, expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "radix_bits must be in 1..usize::BITS")]
+    fn radix_sort_by_key_zero_bits() {
+        let mut v = vec![3usize, 1, 2];
+        radix_sort_by_key(&mut v, 0, |x| *x);
+    }
+
+    #[test]
+    fn radix256_u32() {
+        let seed: u64 = 42;
+        let mut rng = StdRng::seed_from_u64(seed);
+
+        let mut v: Vec<u32> = (0..1000)
+            .map(|_| rng.random())
+            .chain([0, u32::MAX, 0, u32::MAX, 0x00FF_FF00])
+            .collect();
+        radix256_sort_u32(&mut v);
+        check_sorted_u32(&mut v);
+        assert_eq!((v[0], v[v.len() - 1]), (0, u32::MAX));
+
+        // 일부 byte가 모두 같아 pass를 건너뛰는 경우
+        let mut v: Vec<u32> = (0..300).rev().map(|x| x << 8).collect();
+        radix256_sort_u32(&mut v);
+        assert_eq!(v, (0..300).map(|x| x << 8).collect::<Vec<u32>>());
+
+        for mut v in [vec![], vec![7u32], vec![u32::MAX; 10]] {
+            let expected = v.clone();
+            radix256_sort_u32(&mut v);
+            assert_eq!(v, expected);
+        }
+    }
+
+    #[test]
+    fn radix256_u64() {
+        let seed: u64 = 42;
+        let mut rng = StdRng::seed_from_u64(seed);
+
+        let mut v: Vec<u64> = (0..1000)
+            .map(|_| rng.random())
+            .chain([0, u64::MAX, 1, u64::MAX - 1])
+            .collect();
+        let mut expected = v.clone();
+        expected.sort();
+        radix256_sort_u64(&mut v);
+        assert_eq!(v, expected);
+    }
+
+    #[test]
+    fn radix_sort_auto_u8() {
+        let seed: u64 = 42;
+        let mut rng = StdRng::seed_from_u64(seed);
+
+        for len in [0, 1, 1000] {
+            // u8 범위보다 길이가 길어 중복이 존재합니다.
+            let mut v: Vec<u8> = (0..len).map(|_| rng.random()).collect();
+            let mut expected = v.clone();
+            expected.sort();
+            v.as_mut_slice().radix_sort_auto();
+            assert_eq!(v, expected);
+        }
+    }
+
+    #[test]
+    fn radix_sort_auto_u16() {
+        let seed: u64 = 42;
+        let mut rng = StdRng::seed_from_u64(seed);
+
+        let mut v: Vec<u16> = (0..1000)
+            .map(|_| rng.random_range(0..300) * 211)
+            .chain([0, u16::MAX, u16::MAX, 0])
+            .collect();
+        let mut expected = v.clone();
+        expected.sort();
+        v.as_mut_slice().radix_sort_auto();
+        assert_eq!(v, expected);
+    }
+
+    #[test]
+    fn stability_through_all_passes() {
+        // 두 번째 필드는 원래 index이며, 첫 번째 필드는 중복이 많습니다.
+        let seed: u64 = 42;
+        let mut rng = StdRng::seed_from_u64(seed);
+        let data: Vec<(u8, usize)> = (0..2000).map(|i| (rng.random(), i)).collect();
+        let is_stable = |v: &[(u8, usize)]| {
+            v.windows(2)
+                .all(|w| w[0].0 < w[1].0 || (w[0].0 == w[1].0 && w[0].1 < w[1].1))
+        };
+
+        // tuple scheme, 4비트씩 두 pass
+        let mut v = data.clone();
+        v.as_mut_slice().radix_sort((
+            |x: &(u8, usize)| (x.0 & 0xF) as usize,
+            |x: &(u8, usize)| (x.0 >> 4) as usize,
+        ));
+        assert!(is_stable(&v));
+
+        // serial scheme, 1비트씩 여덟 pass
+        let mut projections: Vec<Projection<(u8, usize)>> = (0..8)
+            .map(|bit| Box::new(move |x: &(u8, usize)| ((x.0 >> bit) & 1) as usize) as _)
+            .collect();
+        let mut v = data.clone();
+        v.as_mut_slice()
+            .radix_sort(SerialScheme::new(&mut projections));
+        assert!(is_stable(&v));
+
+        // radix_sort_by_key, 2비트씩 네 pass
+        let mut v = data;
+        radix_sort_by_key(&mut v, 2, |x| x.0 as usize);
+        assert!(is_stable(&v));
+    }
+
+    #[test]
+    fn u128_scheme_random() {
+        let seed: u64 = 42;
+        let mut rng = StdRng::seed_from_u64(seed);
+        let data: Vec<u128> = (0..1000)
+            .map(|_| rng.random())
+            .chain([
+                0,
+                u128::MAX,
+                0,
+                u128::MAX - 1,
+                1,
+                1 << 127,
+                u64::MAX as u128,
+            ])
+            .collect();
+        let mut expected = data.clone();
+        expected.sort();
+
+        // 128을 나누는 경우와 나누지 않는 경우
+        for bits_per_pass in [1, 7, 8, 11, 16] {
+            let mut projections = u128_radix_scheme(bits_per_pass);
+            assert_eq!(projections.len() as u32, 128u32.div_ceil(bits_per_pass));
+            let mut v = data.clone();
+            v.as_mut_slice()
+                .radix_sort(SerialScheme::new(&mut projections));
+            assert_eq!(v, expected);
+        }
+    }
+
+    #[test]
+    fn u128_scheme_high_bits_only() {
+        // 마지막 pass의 상위 비트에서만 차이가 나는 키
+        let mut v: Vec<u128> = (0..50u128).rev().map(|x| (x << 121) | 5).collect();
+        let mut projections = u128_radix_scheme(11);
+        v.as_mut_slice()
+            .radix_sort(SerialScheme::new(&mut projections));
+        assert_eq!(
+            v,
+            (0..50u128).map(|x| (x << 121) | 5).collect::<Vec<u128>>()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "bits_per_pass must be in 1..usize::BITS")]
+    fn u128_scheme_zero_bits() {
+        let _ = u128_radix_scheme(0);
+    }
 }
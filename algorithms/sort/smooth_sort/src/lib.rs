@@ -0,0 +1,283 @@
+//! # Description
+//! Implementation of Dijkstra's smooth sort.
+//!
+//! Smooth sort is a variant of heap sort using a forest of Leonardo heaps instead of a binary heap.
+//! - In-place : only O(1) extra space is used, no allocation.
+//! - Adaptive : O(n) for already sorted input, O(n log n) in the worst case.
+//! - Unstable : order of equal elements is not preserved.
+//!
+//! # Leonardo heap
+//! Leonardo numbers are defined as L(0) = L(1) = 1, L(k) = L(k - 1) + L(k - 2) + 1.
+//! A Leonardo tree of order k has L(k) nodes, its root is at the end of the range,
+//! and its children are Leonardo trees of order k - 1(left) and k - 2(right).
+//! The slice is decomposed into Leonardo trees of strictly decreasing order from left to right,
+//! and roots of the trees are kept in ascending order.
+//! So the root of the rightmost tree is the maximum of the forest.
+
+/// Leonardo numbers which fit in usize.
+/// Order of the trees is always smaller than the length of this table.
+const LEONARDO: [usize; 92] = leonardo_numbers();
+
+const fn leonardo_numbers() -> [usize; 92] {
+    let mut table = [1usize; 92];
+    let mut k = 2;
+    while k < 92 {
+        table[k] = table[k - 1].saturating_add(table[k - 2]).saturating_add(1);
+        k += 1;
+    }
+    table
+}
+
+/// # Description
+/// Sorts the given slice in-place using Dijkstra's smooth sort algorithm.
+///
+/// # Type Parameters
+/// - `T`: The element type. Must implement `Ord`.
+///
+/// # Parameters
+/// - `slice`: The mutable slice to sort.
+///
+/// # Panics
+/// Panics if the implementation of Ord panics.
+///
+/// # Examples
+/// ```
+/// use smooth_sort::smooth_sort;
+/// let mut v = vec![3, 1, 4, 1, 5];
+/// smooth_sort(&mut v);
+/// assert_eq!(v, vec![1, 1, 3, 4, 5]);
+/// ```
+pub fn smooth_sort<T: Ord>(slice: &mut [T]) {
+    smooth_sort_by(slice, T::cmp)
+}
+
+/// # Description
+/// Sorts the given slice in-place using Dijkstra's smooth sort algorithm
+/// with comparator.
+///
+/// # Type Parameters
+/// - `T`: The element type.
+/// - `F`: The comparator type. Must implement 'FnMut' trait.
+///
+/// # Parameters
+/// - `slice`: The mutable slice to sort.
+/// - `comp`: The callable object to compare two &T data.
+///
+/// # Panics
+/// Panics if the implementation of 'comp' panics.
+/// In debug builds, panics if `comp` is not a consistent total order.
+///
+/// # Examples
+/// ```
+/// use smooth_sort::smooth_sort_by;
+/// let mut v = vec![3, 1, 4, 1, 5];
+/// smooth_sort_by(&mut v, |a : &i32, b : &i32|{ b.cmp(a) });
+/// assert_eq!(v, vec![5, 4, 3, 1, 1]);
+/// ```
+pub fn smooth_sort_by<T, F>(slice: &mut [T], mut comp: F)
+where
+    F: FnMut(&T, &T) -> std::cmp::Ordering,
+{
+    let len = slice.len();
+    if len <= 1 {
+        return;
+    }
+
+    // bit k is set if there is a tree of order k in the forest
+    let mut orders: u128 = 0;
+
+    // build forest of Leonardo heaps, [0, i] is the forest
+    for i in 0..len {
+        let smallest = orders.trailing_zeros();
+        if orders != 0 && (orders >> (smallest + 1)) & 1 == 1 {
+            // merge two trees of consecutive order with the new root
+            orders &= !(0b11 << smallest);
+            orders |= 1 << (smallest + 2);
+        } else if orders != 0 && smallest == 1 {
+            orders |= 1;
+        } else {
+            orders |= 1 << 1;
+        }
+        rectify(slice, &mut comp, orders, i, orders.trailing_zeros());
+    }
+
+    // dequeue maximum, which is the root of the rightmost tree
+    for i in (1..len).rev() {
+        let order = orders.trailing_zeros();
+        orders &= !(1 << order);
+        if order >= 2 {
+            // expose two children as new trees
+            let right = i - 1;
+            let left = right - LEONARDO[order as usize - 2];
+            orders |= (1 << (order - 1)) | (1 << (order - 2));
+            rectify(slice, &mut comp, orders, left, order - 1);
+            rectify(slice, &mut comp, orders, right, order - 2);
+        }
+    }
+
+    debug_assert!(
+        slice.is_sorted_by(|a, b| comp(a, b).is_le()),
+        "smooth_sort_by: comparator is not a consistent total order"
+    );
+}
+
+/// # Description
+/// Restore ascending order of the roots, and heap invariant of the tree.
+/// The tree of the given order at root is the only one which can break the invariant.
+///
+/// The root is swapped with the root of the left tree while the left root is the biggest,
+/// then sifted down in the tree where it stopped.
+fn rectify<T, F>(slice: &mut [T], comp: &mut F, orders: u128, mut root: usize, mut order: u32)
+where
+    F: FnMut(&T, &T) -> std::cmp::Ordering,
+{
+    loop {
+        // trees in the left side have higher order
+        let higher = orders & !((1 << (order + 1)) - 1);
+        if higher == 0 {
+            break;
+        }
+        let prev = root - LEONARDO[order as usize];
+        if comp(&slice[prev], &slice[root]).is_le() {
+            break;
+        }
+        if order >= 2 {
+            let right = root - 1;
+            let left = right - LEONARDO[order as usize - 2];
+            if comp(&slice[prev], &slice[left]).is_le() || comp(&slice[prev], &slice[right]).is_le()
+            {
+                // one of the children will be the root by sift down
+                break;
+            }
+        }
+        slice.swap(prev, root);
+        root = prev;
+        order = higher.trailing_zeros();
+    }
+    sift_down(slice, comp, root, order);
+}
+
+/// # Description
+/// Keep heap invariant of a Leonardo tree.
+/// Invariant : root must be bigger than or equal to it's children.
+///
+/// # Performance
+/// Time complexity(worst) : O(log n)
+fn sift_down<T, F>(slice: &mut [T], comp: &mut F, mut root: usize, mut order: u32)
+where
+    F: FnMut(&T, &T) -> std::cmp::Ordering,
+{
+    while order >= 2 {
+        let right = root - 1;
+        let left = right - LEONARDO[order as usize - 2];
+        let (child, child_order) = if comp(&slice[left], &slice[right]).is_ge() {
+            (left, order - 1)
+        } else {
+            (right, order - 2)
+        };
+        if comp(&slice[root], &slice[child]).is_ge() {
+            break;
+        }
+        slice.swap(root, child);
+        root = child;
+        order = child_order;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::*;
+    use std::cmp::Reverse;
+
+    use rand::distr::StandardUniform;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    const TEST_SIZE: usize = 10_000;
+
+    #[test]
+    fn test_leonardo_numbers() {
+        assert_eq!(LEONARDO[..10], [1, 1, 3, 5, 9, 15, 25, 41, 67, 109]);
+        assert!(LEONARDO[91] > usize::MAX / 2);
+    }
+
+    #[test]
+    fn test_smooth_sort_small() {
+        for len in 0..100 {
+            let seed: u64 = len as u64;
+            let rng = StdRng::seed_from_u64(seed);
+
+            let mut vec: Vec<i32> = rng.sample_iter(StandardUniform).take(len).collect();
+            let mut expected = vec.clone();
+            expected.sort();
+
+            smooth_sort(&mut vec);
+            assert_eq!(vec, expected);
+        }
+    }
+
+    #[test]
+    fn test_smooth_sort() {
+        let seed: u64 = 42;
+        let rng = StdRng::seed_from_u64(seed);
+
+        let mut vec: Vec<i32> = rng.sample_iter(StandardUniform).take(TEST_SIZE).collect();
+
+        smooth_sort(&mut vec);
+
+        assert!(vec.is_sorted());
+    }
+
+    #[test]
+    fn test_smooth_sort_by() {
+        let seed: u64 = 42;
+        let rng = StdRng::seed_from_u64(seed);
+
+        let mut vec: Vec<i32> = rng.sample_iter(StandardUniform).take(TEST_SIZE).collect();
+
+        smooth_sort_by(&mut vec, |a: &i32, b: &i32| Reverse(a).cmp(&Reverse(b)));
+
+        assert!(vec.is_sorted_by(|&a, &b| { a > b }));
+    }
+
+    #[test]
+    fn test_smooth_sort_duplicates() {
+        let seed: u64 = 42;
+        let rng = StdRng::seed_from_u64(seed);
+
+        let mut vec: Vec<i32> = rng
+            .sample_iter(StandardUniform)
+            .take(TEST_SIZE)
+            .map(|x: i32| x % 8)
+            .collect();
+
+        smooth_sort(&mut vec);
+
+        assert!(vec.is_sorted());
+    }
+
+    #[test]
+    fn test_smooth_sort_sorted() {
+        let mut vec: Vec<i32> = (0..TEST_SIZE as i32).collect();
+
+        let mut comp_cnt = 0usize;
+        smooth_sort_by(&mut vec, |a: &i32, b: &i32| {
+            comp_cnt += 1;
+            a.cmp(b)
+        });
+
+        assert!(vec.is_sorted());
+        // adaptive, linear number of comparisons for sorted input
+        assert!(comp_cnt < 8 * TEST_SIZE);
+    }
+
+    #[test]
+    fn test_smooth_sort_reverse() {
+        let mut vec: Vec<i32> = (0..TEST_SIZE as i32).rev().collect();
+
+        smooth_sort(&mut vec);
+
+        assert!(vec.is_sorted());
+    }
+}
@@ -0,0 +1,337 @@
+//! # Description
+//! Canonical edge-case inputs and fixtures shared by the tests of the sort and heap crates.
+//!
+//! Every pattern is generated deterministically, so a failing case can be
+//! reproduced from its name and length alone.
+//! Other crates use this crate only as a dev-dependency.
+use std::cell::Cell;
+use std::cmp::{Ordering, Reverse};
+use std::panic::{AssertUnwindSafe, catch_unwind};
+
+/// # Description
+/// Lengths checked by [`check_sort`] and [`check_stable_sort`].
+///
+/// Tiny lengths and lengths around the thresholds used by the sort crates
+/// (insertion sort cut-offs, tim sort minimum run, ninther pivot selection)
+/// are listed one below, at, and one above each threshold.
+pub const LENGTHS: &[usize] = &[
+    0, 1, 2, 3, 4, 7, 8, 9, 15, 16, 17, 31, 32, 33, 49, 50, 51, 63, 64, 65, 127, 128, 129, 1000,
+    10_000,
+];
+
+/// # Description
+/// Names of the patterns returned by [`i32_patterns`], in the same order.
+pub const PATTERNS: &[&str] = &[
+    "sorted",
+    "reverse",
+    "all_equal",
+    "sawtooth",
+    "organ_pipe",
+    "near_sorted",
+    "reversed_tail",
+    "many_duplicates",
+    "random",
+];
+
+/// # Description
+/// Generates every pattern of [`PATTERNS`] with the given length.
+///
+/// # Parameters
+/// - `len`: The length of each generated vector.
+///
+/// # Returns
+/// - `Vec<(&'static str, Vec<i32>)>`: Pairs of a pattern name and its data.
+///
+/// # Examples
+/// ```
+/// let patterns = test_data::i32_patterns(5);
+/// assert_eq!(patterns[1], ("reverse", vec![4, 3, 2, 1, 0]));
+/// assert_eq!(patterns[4], ("organ_pipe", vec![0, 1, 2, 2, 1]));
+/// ```
+pub fn i32_patterns(len: usize) -> Vec<(&'static str, Vec<i32>)> {
+    let n = len as i32;
+    let mut state = 0x9e37_79b9_7f4a_7c15 ^ len as u64;
+    let mut next = move || {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (state >> 32) as u32
+    };
+
+    let mut near_sorted: Vec<i32> = (0..n).collect();
+    for i in (0..len.saturating_sub(1)).step_by(16) {
+        near_sorted.swap(i, i + 1);
+    }
+
+    vec![
+        ("sorted", (0..n).collect()),
+        ("reverse", (0..n).rev().collect()),
+        ("all_equal", vec![7; len]),
+        ("sawtooth", (0..n).map(|x| x % 17).collect()),
+        ("organ_pipe", (0..n).map(|x| x.min(n - x)).collect()),
+        ("near_sorted", near_sorted),
+        (
+            "reversed_tail",
+            (0..n).map(|x| if x < n - 10 { x } else { -x }).collect(),
+        ),
+        (
+            "many_duplicates",
+            (0..len).map(|_| (next() % 4) as i32).collect(),
+        ),
+        ("random", (0..len).map(|_| next() as i32).collect()),
+    ]
+}
+
+/// # Description
+/// Checks that `sort` sorts every pattern of every length in [`LENGTHS`]
+/// the same way as `slice::sort`.
+///
+/// # Parameters
+/// - `sort`: The sort under test.
+///
+/// # Panics
+/// Panics with the pattern name and the length of the first case that is sorted incorrectly.
+///
+/// # Examples
+/// ```
+/// test_data::check_sort(|slice| slice.sort_unstable());
+/// ```
+pub fn check_sort<F>(mut sort: F)
+where
+    F: FnMut(&mut [i32]),
+{
+    for &len in LENGTHS {
+        for (name, mut vec) in i32_patterns(len) {
+            let mut expected = vec.clone();
+            expected.sort();
+            sort(&mut vec);
+            assert_eq!(vec, expected, "pattern {name} of length {len}");
+        }
+    }
+}
+
+/// # Description
+/// Checks that `sort` stable sorts every pattern of every length in [`LENGTHS`].
+///
+/// Each element is paired with its original index, and `sort` must order the pairs by the first field only.
+/// The result is compared with `slice::sort_by_key`, so equal keys must keep their original order.
+///
+/// # Parameters
+/// - `sort`: The stable sort under test, comparing by `.0`.
+///
+/// # Panics
+/// Panics with the pattern name and the length of the first case that is sorted incorrectly or unstably.
+///
+/// # Examples
+/// ```
+/// test_data::check_stable_sort(|slice| slice.sort_by_key(|&(key, _)| key));
+/// ```
+pub fn check_stable_sort<F>(mut sort: F)
+where
+    F: FnMut(&mut [(i32, usize)]),
+{
+    for &len in LENGTHS {
+        for (name, vec) in i32_patterns(len) {
+            let mut vec: Vec<(i32, usize)> = vec.into_iter().zip(0..).collect();
+            let mut expected = vec.clone();
+            expected.sort_by_key(|&(key, _)| key);
+            sort(&mut vec);
+            assert_eq!(vec, expected, "pattern {name} of length {len}");
+        }
+    }
+}
+
+/// # Description
+/// An element ordered only by its key, with the original index as a payload.
+///
+/// `Eq` and `Ord` ignore the index, so a sort which takes `T: Ord` can be checked for stability
+/// by looking at the indices of equal keys.
+#[derive(Debug, Clone, Copy)]
+pub struct Keyed(pub i32, pub usize);
+
+impl PartialEq for Keyed {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl Eq for Keyed {}
+
+impl PartialOrd for Keyed {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Keyed {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+/// # Description
+/// Checks that `sort` stable sorts every pattern of every length in [`LENGTHS`] in descending order.
+///
+/// Each element is a [`Keyed`] paired with its original index.
+/// The result is compared with `slice::sort_by_key` on the reversed key,
+/// so equal keys must keep their original order.
+///
+/// # Parameters
+/// - `sort`: The stable descending sort under test.
+///
+/// # Panics
+/// Panics with the pattern name and the length of the first case that is sorted incorrectly or unstably.
+///
+/// # Examples
+/// ```
+/// test_data::check_stable_sort_desc(|slice| slice.sort_by(|a, b| b.cmp(a)));
+/// ```
+pub fn check_stable_sort_desc<F>(mut sort: F)
+where
+    F: FnMut(&mut [Keyed]),
+{
+    for &len in LENGTHS {
+        for (name, vec) in i32_patterns(len) {
+            let mut vec: Vec<Keyed> = vec.into_iter().zip(0..).map(|(k, i)| Keyed(k, i)).collect();
+            let mut expected = vec.clone();
+            expected.sort_by_key(|k| Reverse(k.0));
+            sort(&mut vec);
+            // Keyed compares only the key, so compare the fields
+            let fields = |v: &[Keyed]| v.iter().map(|k| (k.0, k.1)).collect::<Vec<_>>();
+            assert_eq!(
+                fields(&vec),
+                fields(&expected),
+                "pattern {name} of length {len}"
+            );
+        }
+    }
+}
+
+/// # Description
+/// An element which counts its drops in a shared counter, for panic-safety tests.
+///
+/// After a panic is caught, the counter tells whether an element was dropped by the unwinding,
+/// and dropping the slice afterwards tells whether an element was lost or duplicated.
+///
+/// # Examples
+/// ```
+/// use std::cell::Cell;
+/// use test_data::DropCounter;
+/// let drops = Cell::new(0);
+/// let vec: Vec<DropCounter> = (0..3).map(|value| DropCounter { value, drops: &drops }).collect();
+/// assert_eq!(drops.get(), 0);
+/// drop(vec);
+/// assert_eq!(drops.get(), 3);
+/// ```
+pub struct DropCounter<'a> {
+    /// The key to sort by.
+    pub value: i32,
+    /// The counter increased by each drop.
+    pub drops: &'a Cell<usize>,
+}
+
+impl Drop for DropCounter<'_> {
+    fn drop(&mut self) {
+        self.drops.set(self.drops.get() + 1);
+    }
+}
+
+/// # Description
+/// Checks that `sort_by` panics in debug builds when the comparator is not a consistent total order.
+///
+/// The comparator returns `Greater` for every pair of distinct elements,
+/// so `a > b` and `b > a` at the same time, and the result can never be sorted by it.
+///
+/// # Parameters
+/// - `sort_by`: The sort under test, called with the slice and the comparator.
+///
+/// # Panics
+/// Panics if `sort_by` returns normally, or panics with a message which does not contain
+/// "comparator is not a consistent total order".
+///
+/// # Examples
+/// ```
+/// // a sort which only checks its postcondition
+/// test_data::check_inconsistent_comparator(|slice, comp| {
+///     assert!(
+///         slice.is_sorted_by(|a, b| comp(a, b).is_le()),
+///         "comparator is not a consistent total order"
+///     );
+/// });
+/// ```
+pub fn check_inconsistent_comparator<F>(sort_by: F)
+where
+    F: FnOnce(&mut [i32], fn(&i32, &i32) -> Ordering),
+{
+    let mut vec: Vec<i32> = (0..100).collect();
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        sort_by(&mut vec, |a, b| match a == b {
+            true => Ordering::Equal,
+            false => Ordering::Greater,
+        })
+    }));
+
+    let payload = result.expect_err("sort_by accepted an inconsistent comparator");
+    let message = payload
+        .downcast_ref::<String>()
+        .map(String::as_str)
+        .or_else(|| payload.downcast_ref::<&str>().copied())
+        .unwrap_or_default();
+    assert!(
+        message.contains("comparator is not a consistent total order"),
+        "unexpected panic message: {message}"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_patterns() {
+        for &len in LENGTHS {
+            let patterns = i32_patterns(len);
+            assert_eq!(patterns.len(), PATTERNS.len());
+            for ((name, vec), expected_name) in patterns.iter().zip(PATTERNS) {
+                assert_eq!(name, expected_name);
+                assert_eq!(vec.len(), len);
+            }
+        }
+        // same length, same data
+        assert_eq!(i32_patterns(100), i32_patterns(100));
+    }
+
+    #[test]
+    #[should_panic(expected = "pattern reverse of length 2")]
+    fn test_check_sort_reports_case() {
+        check_sort(|_| {});
+    }
+
+    #[test]
+    #[should_panic(expected = "pattern all_equal of length 2")]
+    fn test_check_stable_sort_rejects_unstable() {
+        check_stable_sort(|slice| {
+            slice.sort_by_key(|&(key, _)| key);
+            for run in slice.chunk_by_mut(|a, b| a.0 == b.0) {
+                run.reverse();
+            }
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "pattern all_equal of length 2")]
+    fn test_check_stable_sort_desc_rejects_unstable() {
+        check_stable_sort_desc(|slice| {
+            slice.sort_by(|a, b| b.cmp(a));
+            for run in slice.chunk_by_mut(|a, b| a == b) {
+                run.reverse();
+            }
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "sort_by accepted an inconsistent comparator")]
+    fn test_check_inconsistent_comparator_rejects_silent_sort() {
+        check_inconsistent_comparator(|_, _| {});
+    }
+}
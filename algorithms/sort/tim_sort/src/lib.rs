@@ -6,6 +6,27 @@ use std::ptr::copy_nonoverlapping;
 /// Run describe a range of [first, second).
 type Run = (usize, usize);
 
+/// # Description
+/// Tuning parameters of tim sort.
+///
+/// # Fields
+/// - `min_gallop`: Initial threshold of galloping mode in each merge.
+///   Galloping mode starts when one run wins `min_gallop` times in a row,
+///   and the threshold adapts by the success of galloping during the merge.
+///   A lower value gallops earlier, which pays off on data with long natural runs.
+///   A higher value stays in one-pair-at-a-time mode, which is cheaper on random data.
+///   Must be at least 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimConfig {
+    pub min_gallop: u32,
+}
+
+impl Default for TimConfig {
+    fn default() -> Self {
+        TimConfig { min_gallop: 3 }
+    }
+}
+
 /// # Description
 /// Sorts the given slice stable using a Tim‑sort algorithm.
 ///
@@ -30,6 +51,97 @@ pub fn tim_sort<T: Ord>(slice: &mut [T]) {
     tim_sort_by(slice, T::cmp)
 }
 
+/// # Description
+/// Sorts the given slice stable using a Tim‑sort algorithm in descending order.
+/// Same as `tim_sort_by` with `|a, b| b.cmp(a)`, without spelling out `Reverse`.
+/// Equal elements keep their original order, as in ascending order.
+///
+/// # Type Parameters
+/// - `T`: The element type. Must implement `Ord`.
+///
+/// # Parameters
+/// - `slice`: The mutable slice to sort.
+///
+/// # Panics
+/// Panics if calculating partition indices overflows (only for very large slices).
+/// Panics if the implementation of Ord panics.
+///
+/// # Examples
+/// ```
+/// use tim_sort::tim_sort_desc;
+/// let mut v = vec![3, 1, 4, 1, 5];
+/// tim_sort_desc(&mut v);
+/// assert_eq!(v, vec![5, 4, 3, 1, 1]);
+/// ```
+pub fn tim_sort_desc<T: Ord>(slice: &mut [T]) {
+    tim_sort_by(slice, |a, b| b.cmp(a));
+}
+
+/// # Description
+/// Sorts the given slice stable using a Tim‑sort algorithm in descending order of the key.
+/// Same as `tim_sort_by` with `|a, b| key_fn(b).cmp(&key_fn(a))`.
+/// Elements with equal keys keep their original order, unlike sorting ascending and reversing the result.
+///
+/// # Type Parameters
+/// - `T`: The element type.
+/// - `K`: The key type. Must implement `Ord`.
+/// - `F`: The key extraction function type. Must implement 'FnMut' trait.
+///
+/// # Parameters
+/// - `slice`: The mutable slice to sort.
+/// - `key_fn`: The callable object to extract the key of &T data. It is called twice per comparison.
+///
+/// # Panics
+/// Panics if calculating partition indices overflows (only for very large slices).
+/// Panics if the implementation of `key_fn` or Ord of K panics.
+///
+/// # Examples
+/// ```
+/// use tim_sort::tim_sort_by_key_desc;
+/// let mut v = vec![(1, 'a'), (3, 'b'), (1, 'c'), (3, 'd')];
+/// tim_sort_by_key_desc(&mut v, |x| x.0);
+/// assert_eq!(v, vec![(3, 'b'), (3, 'd'), (1, 'a'), (1, 'c')]);
+/// ```
+pub fn tim_sort_by_key_desc<T, K, F>(slice: &mut [T], mut key_fn: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    tim_sort_by(slice, |a, b| key_fn(b).cmp(&key_fn(a)));
+}
+
+/// # Description
+/// Sorts the given slice stable using a Tim‑sort algorithm
+/// with the given tuning parameters.
+/// `tim_sort` is the same as this function with `TimConfig::default()`.
+///
+/// # Type Parameters
+/// - `T`: The element type. Must implement `Ord`.
+///
+/// # Parameters
+/// - `slice`: The mutable slice to sort.
+/// - `config`: The tuning parameters, see `TimConfig`.
+///
+/// # Panics
+/// Panics if `config.min_gallop` is 0.
+/// Panics if calculating partition indices overflows (only for very large slices).
+/// Panics if the implementation of Ord panics.
+///
+/// # Examples
+/// ```
+/// use tim_sort::{TimConfig, tim_sort_with_config};
+/// let mut v: Vec<i32> = (0..100).chain(0..100).collect();
+/// tim_sort_with_config(&mut v, TimConfig { min_gallop: 1 });
+/// assert!(v.is_sorted());
+/// ```
+pub fn tim_sort_with_config<T: Ord>(slice: &mut [T], config: TimConfig) {
+    assert!(
+        config.min_gallop >= 1,
+        "tim_sort_with_config: min_gallop must be at least 1"
+    );
+    tim_sort_by_comp(slice, T::cmp, config.min_gallop);
+}
+
 /// # Description
 /// Sorts the given slice stable using Tim‑sort algorithm
 /// with comparator.
@@ -45,6 +157,7 @@ pub fn tim_sort<T: Ord>(slice: &mut [T]) {
 /// # Panics
 /// Panics if calculating partition indices overflows (only for very large slices).
 /// Panics if the implementation of 'comp' panics.
+/// In debug builds, panics if `comp` is not a consistent total order.
 ///
 /// # Examples
 /// ```
@@ -54,17 +167,45 @@ pub fn tim_sort<T: Ord>(slice: &mut [T]) {
 /// assert_eq!(v, vec![1, 1, 3, 4, 5]);
 /// ```
 pub fn tim_sort_by<T, F>(slice: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> std::cmp::Ordering,
+{
+    tim_sort_by_comp(slice, &mut compare, TimConfig::default().min_gallop);
+
+    debug_assert!(
+        slice.is_sorted_by(|a, b| compare(a, b).is_le()),
+        "tim_sort_by: comparator is not a consistent total order"
+    );
+}
+
+/// # Description
+/// Actual implementation of tim_sort_by.
+/// Split the slice into runs and merge them by keeping invariant of run stack.
+/// Each merge starts with `min_gallop` as the threshold of galloping mode.
+fn tim_sort_by_comp<T, F>(slice: &mut [T], mut compare: F, min_gallop: u32)
 where
     F: FnMut(&T, &T) -> std::cmp::Ordering,
 {
     // calculate min run size
     let size = slice.len();
+    if size == 0 {
+        return;
+    }
     let (min_run_size, max_run_cnt) = get_min_run_size(size);
+
+    // the first run covers the whole slice, e.g. already sorted or reversed
+    // no need to merge, so neither the runs nor the merge buffer is allocated
+    let first_run_end_pos = get_sorted_run_from_slice(slice, &mut compare, 0, min_run_size);
+    if first_run_end_pos == size {
+        return;
+    }
+
     let mut runs: Vec<Run> = Vec::with_capacity(max_run_cnt);
+    runs.push((0, first_run_end_pos));
 
-    // split slice into runs
+    // split rest of the slice into runs
     // half open range
-    let mut run_start_pos = 0;
+    let mut run_start_pos = first_run_end_pos;
     while run_start_pos < size {
         let run_end_pos =
             get_sorted_run_from_slice(slice, &mut compare, run_start_pos, min_run_size);
@@ -76,13 +217,10 @@ where
         run_start_pos = run_end_pos;
     }
 
-    // no need to merge
-    if runs.len() == 1 {
-        return;
-    }
-
     // buffer allocation
     // allocate additional space for merge
+    #[cfg(test)]
+    tests::MERGE_BUFFER_ALLOC_CNT.with(|cnt| cnt.set(cnt.get() + 1));
     let mut merge_buffer: Vec<T> = Vec::with_capacity(size);
 
     // merge runs using stacks
@@ -95,6 +233,7 @@ where
             &mut compare,
             merge_buffer.as_mut_ptr(),
             &mut run_stack,
+            min_gallop,
         );
     }
 
@@ -108,6 +247,7 @@ where
             merge_buffer.as_mut_ptr(),
             *run_stack.last().unwrap(),
             cur_run,
+            min_gallop,
         );
         run_stack.last_mut().unwrap().1 = cur_run.1;
     }
@@ -225,6 +365,7 @@ fn keep_run_stack_invariant<T, F>(
     mut compare: F,
     merge_buffer: *mut T,
     run_stack: &mut Vec<Run>,
+    min_gallop: u32,
 ) where
     F: FnMut(&T, &T) -> std::cmp::Ordering,
 {
@@ -240,6 +381,7 @@ fn keep_run_stack_invariant<T, F>(
                 merge_buffer,
                 second_from_top,
                 first_from_top,
+                min_gallop,
             );
             run_stack.push(third_from_top);
             run_stack.push((second_from_top.0, first_from_top.1));
@@ -250,6 +392,7 @@ fn keep_run_stack_invariant<T, F>(
                 merge_buffer,
                 third_from_top,
                 second_from_top,
+                min_gallop,
             );
             run_stack.push((third_from_top.0, second_from_top.1));
             run_stack.push(first_from_top);
@@ -296,12 +439,14 @@ fn update_min_gallop(min_gallop: &mut u32, gallop_cnt: usize) {
 /// # Description
 /// Merge two adjacent run.
 /// Memory optimization is not applied because of panic safety.
+/// `min_gallop` is the initial threshold of galloping mode, which adapts during the merge.
 fn merge_two_run<T, F>(
     slice: &mut [T],
     mut comp: F,
     merge_buffer: *mut T,
     mut run1: Run,
     mut run2: Run,
+    mut min_gallop: u32,
 ) where
     F: FnMut(&T, &T) -> std::cmp::Ordering,
 {
@@ -318,7 +463,6 @@ fn merge_two_run<T, F>(
     let mut i = run1.0;
     let mut j = run2.0;
     let mut k = run1.0;
-    let mut min_gallop = 3u32;
     while k < run2.1 {
         let mut copy_cnt = 1usize;
         if j == run2.1 || comp(&slice[i], &slice[j]).is_le() {
@@ -413,6 +557,7 @@ where
 mod tests {
 
     use crate::*;
+    use std::cell::Cell;
     use std::cmp::Reverse;
 
     use rand::distr::StandardUniform;
@@ -421,6 +566,11 @@ mod tests {
 
     const TEST_SIZE: usize = 10_000;
 
+    thread_local! {
+        /// number of merge buffer allocation in the current thread
+        pub static MERGE_BUFFER_ALLOC_CNT: Cell<usize> = const { Cell::new(0) };
+    }
+
     #[test]
     fn test_min_run_size() {
         let n = 1088;
@@ -492,6 +642,7 @@ mod tests {
             merge_buffer.as_mut_ptr(),
             (0, 5),
             (5, 9),
+            3,
         );
         assert_eq!(slice, [-9, 0, 1, 2, 3, 4, 5, 8, 44]);
     }
@@ -506,6 +657,7 @@ mod tests {
             merge_buffer.as_mut_ptr(),
             (0, 1),
             (1, 9),
+            3,
         );
         assert_eq!(slice, [0, 1, 2, 3, 4, 5, 6, 55, 99]);
 
@@ -516,6 +668,7 @@ mod tests {
             merge_buffer.as_mut_ptr(),
             (0, 8),
             (8, 9),
+            3,
         );
         assert_eq!(slice, [-33, 0, 1, 2, 3, 4, 5, 6, 99]);
     }
@@ -531,6 +684,7 @@ mod tests {
             merge_buffer.as_mut_ptr(),
             (0, 4),
             (4, 8),
+            3,
         );
 
         assert_eq!(
@@ -579,6 +733,14 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_tim_sort_patterns() {
+        test_data::check_sort(tim_sort);
+        test_data::check_sort(|slice| tim_sort_by(slice, |a, b| a.cmp(b)));
+        test_data::check_sort(|slice| tim_sort_with_config(slice, TimConfig { min_gallop: 1 }));
+        test_data::check_stable_sort(|slice| tim_sort_by(slice, |a, b| a.0.cmp(&b.0)));
+    }
+
     #[test]
     fn test_tim_sort_by_string() {
         let seed: u64 = 42;
@@ -594,4 +756,89 @@ mod tests {
 
         assert!(vec.is_sorted());
     }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn test_tim_sort_by_inconsistent_comparator() {
+        test_data::check_inconsistent_comparator(tim_sort_by);
+    }
+
+    #[test]
+    fn test_tim_sort_with_config() {
+        let seed: u64 = 42;
+        let rng = StdRng::seed_from_u64(seed);
+        let random: Vec<i32> = rng.sample_iter(StandardUniform).take(TEST_SIZE).collect();
+        // interleaved long ascending and descending runs
+        let long_runs: Vec<i32> = (0..TEST_SIZE as i32)
+            .map(|x| match (x / 1000) % 2 {
+                0 => x % 1000 * 3,
+                _ => 3000 - x % 1000 * 2,
+            })
+            .collect();
+
+        for input in [random, long_runs] {
+            let mut expected = input.clone();
+            expected.sort();
+            for min_gallop in [1, 3, 7, 64] {
+                let mut vec = input.clone();
+                tim_sort_with_config(&mut vec, TimConfig { min_gallop });
+                assert_eq!(vec, expected);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "min_gallop must be at least 1")]
+    fn test_tim_sort_with_config_zero_min_gallop() {
+        let mut vec = vec![3, 1, 2];
+        tim_sort_with_config(&mut vec, TimConfig { min_gallop: 0 });
+    }
+
+    #[test]
+    fn test_tim_sort_desc_stable() {
+        test_data::check_stable_sort_desc(tim_sort_desc);
+    }
+
+    #[test]
+    fn test_tim_sort_single_run() {
+        // sorted and strictly decreasing inputs are a single run
+        let sorted: Vec<i32> = (0..100_000).collect();
+        let reversed: Vec<i32> = (0..100_000).rev().collect();
+        for mut vec in [sorted.clone(), reversed] {
+            MERGE_BUFFER_ALLOC_CNT.with(|cnt| cnt.set(0));
+            tim_sort(&mut vec);
+            assert_eq!(MERGE_BUFFER_ALLOC_CNT.with(|cnt| cnt.get()), 0);
+            assert_eq!(vec, sorted);
+        }
+
+        // one element out of place splits the run
+        let mut vec = sorted.clone();
+        vec.swap(50_000, 50_001);
+        MERGE_BUFFER_ALLOC_CNT.with(|cnt| cnt.set(0));
+        tim_sort(&mut vec);
+        assert_eq!(MERGE_BUFFER_ALLOC_CNT.with(|cnt| cnt.get()), 1);
+        assert_eq!(vec, sorted);
+    }
+
+    #[test]
+    fn test_tim_sort_by_key_desc_stable() {
+        // many duplicate keys, the index tells the original order
+        let mut vec: Vec<(u32, usize)> = (0..1000).map(|i| ((i as u32 * 7919) % 17, i)).collect();
+        tim_sort_by_key_desc(&mut vec, |x| x.0);
+
+        assert!(vec.is_sorted_by(|a, b| a.0 >= b.0));
+        // within a group of equal keys, the original order is kept
+        assert!(
+            vec.windows(2)
+                .filter(|w| w[0].0 == w[1].0)
+                .all(|w| w[0].1 < w[1].1)
+        );
+
+        // reversing an ascending sort puts the equal keys in reverse order
+        let mut reversed: Vec<(u32, usize)> =
+            (0..1000).map(|i| ((i as u32 * 7919) % 17, i)).collect();
+        reversed.sort_by_key(|x| x.0);
+        reversed.reverse();
+        assert_ne!(vec, reversed);
+    }
 }
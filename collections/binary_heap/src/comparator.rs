@@ -14,6 +14,18 @@
 //! ord 트레잇에 의해서 비교는 std::cmp로 이루어지며, Ordering enum을 반환함
 //! variant로는 Greater, Equal, Less의 3종이 존재
 //!
+//! # ReverseComparator
+//! DefaultComparator의 역순 비교, Ord 트레잇을 요구한다.
+//! MinHeap에 사용하면 가장 큰 원소가 root가 되는 max heap으로 동작한다.
+//!
+//! # ReverseOwned
+//! 임의의 Comparator를 소유하여 그 역순으로 비교하는 wrapper, Ord 트레잇을 요구하지 않는다.
+//! 참조가 아닌 값으로 comparator를 보관하므로, MinHeap에 넘기거나 as_fn_mut으로 sort에 넘길 때 lifetime을 신경쓰지 않아도 된다.
+//!
+//! # as_fn_mut
+//! sort crate들은 comparator로 `FnMut(&T, &T) -> Ordering`을 받으므로,
+//! Comparator 구현체를 closure로 다시 작성하지 않고 재사용할 수 있도록 변환한다.
+//!
 
 pub trait Comparator<T> {
     fn compare(&self, a: &T, b: &T) -> std::cmp::Ordering;
@@ -27,3 +39,70 @@ impl<T: Ord> Comparator<T> for DefaultComparator {
         a.cmp(b)
     }
 }
+
+#[derive(Default)]
+pub struct ReverseComparator;
+impl<T: Ord> Comparator<T> for ReverseComparator {
+    #[inline]
+    fn compare(&self, a: &T, b: &T) -> std::cmp::Ordering {
+        b.cmp(a)
+    }
+}
+
+/// # Description
+/// owned wrapper of a Comparator, which compares in the reversed order of the inner one
+///
+/// # Examples
+/// ```
+/// use binary_heap::{Comparator, MinHeap, ReverseOwned};
+///
+/// struct ByLength;
+/// impl Comparator<&str> for ByLength {
+///     fn compare(&self, a: &&str, b: &&str) -> std::cmp::Ordering {
+///         a.len().cmp(&b.len())
+///     }
+/// }
+///
+/// // the longest string comes first
+/// let mut pq = MinHeap::new(ReverseOwned(ByLength));
+/// pq.push("a");
+/// pq.push("abc");
+/// pq.push("ab");
+/// assert_eq!(pq.pop(), Some("abc"));
+/// ```
+#[derive(Default, Clone, Copy)]
+pub struct ReverseOwned<C>(pub C);
+impl<T, C: Comparator<T>> Comparator<T> for ReverseOwned<C> {
+    #[inline]
+    fn compare(&self, a: &T, b: &T) -> std::cmp::Ordering {
+        self.0.compare(b, a)
+    }
+}
+
+/// # Description
+/// borrow a Comparator as a FnMut closure, for the sort functions taking FnMut comparators
+///
+/// # Examples
+/// ```
+/// use binary_heap::{Comparator, as_fn_mut};
+/// use intro_sort::intro_sort_by;
+///
+/// // compare by the distance from the target
+/// struct AbstractDistance {
+///     target: i32,
+/// }
+/// impl Comparator<i32> for AbstractDistance {
+///     fn compare(&self, a: &i32, b: &i32) -> std::cmp::Ordering {
+///         a.abs_diff(self.target).cmp(&b.abs_diff(self.target))
+///     }
+/// }
+///
+/// let comp = AbstractDistance { target: 10 };
+/// let mut v = vec![1, 12, 30, 9, 10];
+/// intro_sort_by(&mut v, as_fn_mut(&comp));
+/// assert_eq!(v[0], 10);
+/// assert_eq!(&v[3..], [1, 30]);
+/// ```
+pub fn as_fn_mut<T, C: Comparator<T>>(comp: &C) -> impl FnMut(&T, &T) -> std::cmp::Ordering + '_ {
+    move |a, b| comp.compare(a, b)
+}
@@ -47,6 +47,51 @@ pub fn min_heapify<T>(data: &mut [T], comp: &impl Comparator<T>, i: usize) {
     }
 }
 
+/// # Description
+/// pull up the node at i until its parent is not bigger than it
+/// Invariant : every node except i must already keep the heap invariant
+///
+/// # Performance
+/// Time complexity(worst) : O(log n)
+pub fn sift_up<T>(data: &mut [T], comp: &impl Comparator<T>, i: usize) {
+    let mut cur_idx = i;
+    let mut parent_idx = get_parent(cur_idx);
+    while parent_idx < data.len()
+        && cur_idx < data.len()
+        && Ordering::Greater == comp.compare(&data[parent_idx], &data[cur_idx])
+    {
+        data.swap(parent_idx, cur_idx); // pull up
+        cur_idx = parent_idx;
+        parent_idx = get_parent(parent_idx);
+    }
+}
+
+/// # Description
+/// decide whether rebuilding whole heap is cheaper than sifting up new elements one by one
+/// build_heap costs about 2 * (len + added) comparisons,
+/// sift_up costs about log2(len + added) comparisons for each added element in the worst case,
+/// since the heap grows to len + added while the elements are pushed
+/// so the heap is rebuilt when 2 * (len + added) < added * log2(len + added),
+/// i.e. roughly when added is larger than 2 * len / (log2(len + added) - 2)
+/// e.g. (3, 10_000) and (1, 100) are rebuilt, (1_000_000, 1) and (1, 1) are sifted up
+pub fn better_to_rebuild(len: usize, added: usize) -> bool {
+    if len == 0 {
+        return true;
+    }
+    let total = len + added;
+    let log2_total = usize::ilog2(total) as usize;
+    2 * total < added * log2_total
+}
+
+/// # Description
+/// check whether the data keeps invariant of heap tree
+///
+/// # Performance
+/// Time complexity(worst) : O(n)
+pub fn is_min_heap<T>(data: &[T], comp: &impl Comparator<T>) -> bool {
+    (1..data.len()).all(|i| Ordering::Greater != comp.compare(&data[get_parent(i)], &data[i]))
+}
+
 /// # Description
 /// reorder vector to make heap tree
 ///
@@ -71,7 +116,7 @@ mod tests {
     use crate::DefaultComparator;
     use crate::heap_logic::*;
 
-    fn is_min_heaped<T, C: Comparator<T>>(vec: &Vec<T>, comp: &C) -> bool {
+    fn is_min_heaped<T, C: Comparator<T>>(vec: &[T], comp: &C) -> bool {
         for i in (1..vec.len()).rev() {
             let current = &vec[i];
             let parent = &vec[super::get_parent(i)];
@@ -123,7 +168,7 @@ mod tests {
         let seed: u64 = 42;
         let rng = StdRng::seed_from_u64(seed);
 
-        let mut vec1: Vec<i32> = rng.sample_iter(StandardUniform).take(1000_000).collect();
+        let mut vec1: Vec<i32> = rng.sample_iter(StandardUniform).take(1_000_000).collect();
 
         build_heap(&mut vec1, &dcomp);
         assert!(is_min_heaped(&vec1, &dcomp));
@@ -157,4 +202,30 @@ mod tests {
         min_heapify(&mut vec3, &dcomp, 3);
         assert!(is_min_heaped(&vec3, &dcomp));
     }
+
+    #[test]
+    fn test_sift_up() {
+        let dcomp = DefaultComparator;
+
+        let mut vec1: Vec<u32> = vec![1, 2, 3, 4, 5, 6, 0];
+        sift_up(&mut vec1, &dcomp, 6);
+        assert!(is_min_heaped(&vec1, &dcomp));
+        assert_eq!(vec1[0], 0);
+    }
+
+    #[test]
+    fn test_better_to_rebuild() {
+        assert!(better_to_rebuild(0, 1));
+        assert!(better_to_rebuild(16, 1_000));
+        assert!(!better_to_rebuild(1_000_000, 1));
+
+        // small heap with large batch
+        assert!(better_to_rebuild(1, 100));
+        assert!(better_to_rebuild(3, 10_000));
+        assert!(!better_to_rebuild(1, 1));
+        assert!(!better_to_rebuild(1, 4));
+        // crossover at len = 1000 : 2 * (1000 + k) < k * log2(1000 + k)
+        assert!(!better_to_rebuild(1_000, 250));
+        assert!(better_to_rebuild(1_000, 300));
+    }
 }
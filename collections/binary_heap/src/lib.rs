@@ -10,6 +10,8 @@
 //! heapify, build_heap등 실제 이진 힙 트리를 구현 및 유지하는 핵심 logic을 구현.
 //! ### min_heap
 //! 실제 사용할 MinHeap과 그 method를 정의.
+//! ### top_k
+//! MinHeap을 크기 n으로 유지하여, 반복자에서 가장 작은/큰 n개의 원소를 구하는 nsmallest, nlargest를 정의.
 //!
 //! ## Difference between Rust and C++ in priority_queue
 //! ### C++
@@ -31,6 +33,8 @@
 mod comparator;
 mod heap_logic;
 mod min_heap;
+mod top_k;
 
 pub use crate::comparator::*;
 pub use crate::min_heap::*;
+pub use crate::top_k::*;
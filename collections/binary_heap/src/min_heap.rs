@@ -16,11 +16,11 @@
 //! Comparator trait의 구현체.
 //!
 
-use std::cmp::Ordering;
+use std::collections::TryReserveError;
 use std::ops::{Deref, DerefMut};
 
 use crate::comparator::Comparator;
-use crate::heap_logic::{build_heap, get_parent, min_heapify};
+use crate::heap_logic::{better_to_rebuild, build_heap, is_min_heap, min_heapify, sift_up};
 
 pub struct MinHeap<T, C: Comparator<T>> {
     data: Vec<T>,
@@ -39,6 +39,15 @@ where
         }
     }
 
+    /// # Description
+    /// create empty min heap which can hold at least capacity elements without reallocation
+    pub fn with_capacity(capacity: usize, comp: C) -> MinHeap<T, C> {
+        MinHeap {
+            data: Vec::with_capacity(capacity),
+            comparator: comp,
+        }
+    }
+
     /// create min heap with vector
     pub fn from_vec(mut source: Vec<T>, comp: C) -> MinHeap<T, C> {
         let comparator = comp;
@@ -49,19 +58,91 @@ where
         }
     }
 
+    /// # Description
+    /// create min heap with vector which is already in valid heap order
+    /// build_heap is skipped, so it takes O(1)
+    /// e.g. a vector heapified by heap_on_slice with the same order
+    ///
+    /// # Panics
+    /// In debug builds, panics if the source is not in valid heap order.
+    pub fn from_heapified_vec(source: Vec<T>, comp: C) -> MinHeap<T, C> {
+        debug_assert!(
+            is_min_heap(&source, &comp),
+            "from_heapified_vec: source is not in valid heap order"
+        );
+        MinHeap {
+            data: source,
+            comparator: comp,
+        }
+    }
+
+    /// # Description
+    /// create min heap with vector which is usually already in valid heap order
+    /// e.g. a heap persisted by as_heap_slice and deserialized later
+    /// heap order is checked first with n - 1 comparisons, and build_heap runs only if it is broken
+    /// so it never does less work than from_heapified_vec, and never fails on invalid input
+    /// O(n)
+    pub fn from_vec_checked(mut source: Vec<T>, comp: C) -> MinHeap<T, C> {
+        if !is_min_heap(&source, &comp) {
+            build_heap(&mut source, &comp);
+        }
+        MinHeap {
+            data: source,
+            comparator: comp,
+        }
+    }
+
+    /// # Description
+    /// get backing data of the heap, which is in valid heap order
+    /// root is at index 0, and children of i are at 2i + 1 and 2i + 2
+    /// so read functions of heap_on_slice(e.g. is_heap_by) can operate on it
+    pub fn as_heap_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    /// # Description
+    /// iterate the backing data in index order, annotated with the position in the heap tree
+    /// each item is (depth, index, element), where depth of index i is floor(log2(i + 1))
+    /// so the root has depth 0, its two children depth 1, the next four depth 2, and so on
+    /// read-only introspection for debugging and rendering the tree, not an ordered traversal
+    /// O(n)
+    pub fn tree_iter(&self) -> impl Iterator<Item = (usize, usize, &T)> {
+        self.data
+            .iter()
+            .enumerate()
+            .map(|(i, elem)| ((i + 1).ilog2() as usize, i, elem))
+    }
+
     /// push new element to the heap
     pub fn push(&mut self, elem: T) {
         let data = &mut self.data;
         data.push(elem);
-        let mut cur_idx = data.len() - 1;
-        let mut parent_idx = get_parent(cur_idx);
-        while parent_idx < data.len()
-            && cur_idx < data.len()
-            && Ordering::Greater == self.comparator.compare(&data[parent_idx], &data[cur_idx])
-        {
-            data.swap(parent_idx, cur_idx); // pull up
-            cur_idx = parent_idx;
-            parent_idx = get_parent(parent_idx);
+        let cur_idx = data.len() - 1;
+        sift_up(data, &self.comparator, cur_idx);
+    }
+
+    /// # Description
+    /// push multiple elements to the heap, choosing the cheaper strategy
+    ///
+    /// # Crossover
+    /// Let n be the current length and k be the number of new elements.
+    /// - sift up each element : about k * log2(n + k) comparisons in the worst case
+    /// - rebuild whole heap : about 2 * (n + k) comparisons
+    ///
+    /// If 2 * (n + k) < k * log2(n + k), the heap is rebuilt with build_heap at once.
+    /// Otherwise, each new element is sifted up individually.
+    /// Small batch on a big heap is sifted up, big batch on a small heap is rebuilt.
+    pub fn push_batch<I: IntoIterator<Item = T>>(&mut self, items: I) {
+        let data = &mut self.data;
+        let old_len = data.len();
+        data.extend(items);
+        let added = data.len() - old_len;
+        if better_to_rebuild(old_len, added) {
+            build_heap(data, &self.comparator); // O(n + k)
+        } else {
+            for i in old_len..data.len() {
+                sift_up(data, &self.comparator, i); // O(k log n)
+            }
         }
     }
 
@@ -86,6 +167,120 @@ where
         result
     }
 
+    /// # Description
+    /// consume the heap and return its elements in ascending order of the comparator
+    /// the root is moved to the end repeatedly in place, then the result is reversed
+    /// O(n log n)
+    pub fn into_sorted_vec(mut self) -> Vec<T> {
+        let data = &mut self.data;
+        for end in (1..data.len()).rev() {
+            data.swap(0, end);
+            min_heapify(&mut data[..end], &self.comparator, 0); // O(log n)
+        }
+        data.reverse();
+        self.data
+    }
+
+    /// # Description
+    /// consume the heap and iterate its elements in unspecified order
+    /// the iterator of the backing vector is returned as is, so it takes O(1) to set up
+    /// use it to drop or re-collect the elements, when the sorted order of into_sorted_vec is not needed
+    /// the current order is the heap order, but it may change and must not be relied on
+    pub fn into_iter_unsorted(self) -> std::vec::IntoIter<T> {
+        self.data.into_iter()
+    }
+
+    /// # Description
+    /// iterate the elements in ascending order of the comparator, without modifying the heap
+    /// T: Clone is not required, a secondary heap of indices is used instead of a sorted copy
+    /// the secondary heap starts from the root, and when an index is yielded, its children are pushed
+    /// since a child is never less than its parent, the next smallest element is always in the secondary heap
+    ///
+    /// # Cost
+    /// taking k elements costs O(k log k) time and O(k) extra space for the indices
+    /// so iterating all elements costs O(n log n), but taking only a few of them is cheap
+    pub fn iter_sorted(&self) -> impl Iterator<Item = &T> {
+        let data = &self.data;
+        let by_value = IndexComparator {
+            data,
+            comparator: &self.comparator,
+        };
+        let mut frontier = MinHeap::new(by_value);
+        if !data.is_empty() {
+            frontier.push(0);
+        }
+        std::iter::from_fn(move || {
+            let idx = frontier.pop()?;
+            for child in [2 * idx + 1, 2 * idx + 2] {
+                if child < data.len() {
+                    frontier.push(child);
+                }
+            }
+            Some(&data[idx])
+        })
+    }
+
+    /// # Description
+    /// remove all elements matching the predicate, and return them in unspecified order
+    /// retained elements are rebuilt into a valid heap with build_heap
+    /// O(n)
+    pub fn extract_if<P: FnMut(&T) -> bool>(&mut self, mut pred: P) -> Vec<T> {
+        let data = &mut self.data;
+        let mut extracted = Vec::new();
+        let mut i = 0;
+        while i < data.len() {
+            if pred(&data[i]) {
+                extracted.push(data.swap_remove(i)); // the last one is moved to i, check it again
+            } else {
+                i += 1;
+            }
+        }
+        if !extracted.is_empty() {
+            build_heap(data, &self.comparator); // O(n)
+        }
+        extracted
+    }
+
+    /// # Description
+    /// push down the node at idx until its children are not smaller than it
+    /// use it after the element at idx became bigger by external mutation(e.g. interior mutability)
+    /// every node except idx must already keep the heap invariant
+    /// O(log n)
+    ///
+    /// # Panics
+    /// Panics if idx is out of bounds.
+    pub fn sift_down_from(&mut self, idx: usize) {
+        assert!(idx < self.len(), "sift_down_from: index out of bounds");
+        min_heapify(&mut self.data, &self.comparator, idx);
+    }
+
+    /// # Description
+    /// pull up the node at idx until its parent is not bigger than it
+    /// use it after the element at idx became smaller by external mutation(e.g. interior mutability)
+    /// every node except idx must already keep the heap invariant
+    /// O(log n)
+    ///
+    /// # Panics
+    /// Panics if idx is out of bounds.
+    pub fn sift_up_from(&mut self, idx: usize) {
+        assert!(idx < self.len(), "sift_up_from: index out of bounds");
+        sift_up(&mut self.data, &self.comparator, idx);
+    }
+
+    /// # Description
+    /// check the heap invariant of the whole heap, only in debug builds
+    /// in release builds, it does nothing
+    /// O(n) in debug builds
+    ///
+    /// # Panics
+    /// In debug builds, panics if the heap invariant is broken.
+    pub fn assert_valid(&self) {
+        debug_assert!(
+            is_min_heap(&self.data, &self.comparator),
+            "assert_valid: heap invariant is broken"
+        );
+    }
+
     /// # Description
     /// get mutable reference of root of binary heap
     /// it's source will be heaped when the PeekMut drops
@@ -93,6 +288,22 @@ where
         (!self.is_empty()).then_some(PeekMut { source: self })
     }
 
+    /// number of elements the heap can hold without reallocation
+    pub fn capacity(&self) -> usize {
+        self.data.capacity()
+    }
+
+    /// # Description
+    /// reserve capacity for at least additional more elements
+    /// unlike Vec::reserve, it returns an error instead of aborting when the allocation fails
+    /// so memory-constrained services can handle OOM gracefully
+    ///
+    /// # Errors
+    /// Returns TryReserveError if the capacity overflows, or the allocator reports a failure.
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.data.try_reserve(additional)
+    }
+
     pub fn len(&self) -> usize {
         self.data.len()
     }
@@ -102,7 +313,7 @@ where
     }
 
     pub fn top(&self) -> Option<&T> {
-        self.data.get(0)
+        self.data.first()
     }
 }
 
@@ -118,6 +329,22 @@ where
     }
 }
 
+/// compare indices of the heap data by the values at them, used by iter_sorted
+struct IndexComparator<'a, T, C> {
+    data: &'a [T],
+    comparator: &'a C,
+}
+
+impl<T, C> Comparator<usize> for IndexComparator<'_, T, C>
+where
+    C: Comparator<T>,
+{
+    #[inline]
+    fn compare(&self, a: &usize, b: &usize) -> std::cmp::Ordering {
+        self.comparator.compare(&self.data[*a], &self.data[*b])
+    }
+}
+
 ///
 /// # Description
 /// BinaryHeap의 PeekMut의 replica, MinHeap의 top에 대한 smart pointer.
@@ -130,9 +357,9 @@ where
 ///
 /// # try-error
 /// 1. PeekMut의 원소로 &mut T와 &mut MinHeap을 주고, 각각 &mut self.data[0]와 self로 초기화
-/// 	-> MinHeap에 대한 &mut의 중복으로 실패.
+///    -> MinHeap에 대한 &mut의 중복으로 실패.
 /// 2. PeekMut의 원소로 T와 &mut MinHeap을 주고, self.pop()과 self로 초기화
-/// 	-> drop 시 T를 self에 push하려고 하였으나, 소유권 이동에 실패.
+///    -> drop 시 T를 self에 push하려고 하였으나, 소유권 이동에 실패.
 ///
 pub struct PeekMut<'a, T, C: Comparator<T>> {
     source: &'a mut MinHeap<T, C>,
@@ -159,6 +386,33 @@ where
     }
 }
 
+impl<'a, T, C> PeekMut<'a, T, C>
+where
+    C: Comparator<T>,
+{
+    /// # Description
+    /// PeekMut을 소비하며 MinHeap의 root를 제거하고 반환한다.
+    /// root는 이미 제거되었으므로, drop 시의 min_heapify는 수행하지 않는다.
+    /// T의 method와 이름이 겹치지 않도록 `PeekMut::pop(peek)` 형태의 associated function으로 제공한다.
+    /// O(log n)
+    pub fn pop(this: PeekMut<'a, T, C>) -> T {
+        // peek_mut은 비어있지 않은 heap에 대해서만 PeekMut을 생성하므로 unwrap은 실패하지 않는다.
+        let value = this.source.pop().unwrap();
+        std::mem::forget(this);
+        value
+    }
+
+    /// # Description
+    /// PeekMut을 소비하며 MinHeap의 root를 `new`로 교체하고, 이전 root를 반환한다.
+    /// pop 후 push하는 것과 달리, drop 시의 min_heapify 한 번으로 invariant를 복원한다.
+    /// `PeekMut::pop`과 같이 `PeekMut::set(peek, new)` 형태의 associated function으로 제공한다.
+    /// O(log n)
+    pub fn set(mut this: PeekMut<'a, T, C>, new: T) -> T {
+        // drop 시 root부터 min_heapify가 수행된다.
+        std::mem::replace(&mut *this, new)
+    }
+}
+
 /// drop trait for PeekMut
 /// recover invariant of it's MinHeap
 impl<'a, T, C> Drop for PeekMut<'a, T, C>
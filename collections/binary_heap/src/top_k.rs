@@ -0,0 +1,78 @@
+//! # top_k
+//! Python의 heapq.nsmallest, heapq.nlargest에 대응하는 함수.
+//!
+//! 반복자를 한 번 순회하며 크기 n으로 제한된 MinHeap을 유지한다.
+//! 원소 수를 m이라 하면 O(m log n) 시간과 O(n) 공간을 사용한다.
+//!
+//! ## nsmallest
+//! 지금까지의 n개 중 가장 큰 원소를 root로 두기 위해, ReverseComparator를 사용한 max heap을 유지한다.
+//! 새 원소가 root보다 작으면 root를 교체한다.
+//!
+//! ## nlargest
+//! DefaultComparator를 사용한 min heap을 유지하며, 새 원소가 root보다 크면 root를 교체한다.
+//!
+
+use crate::comparator::{Comparator, DefaultComparator, ReverseComparator};
+use crate::min_heap::MinHeap;
+
+/// 크기가 n으로 제한된 heap에 iter의 원소를 넣는다.
+/// heap이 가득 찬 경우, root보다 뒤에 와야 하는(comparator 기준으로 큰) 원소만 root와 교체한다.
+/// 결과는 pop 순서의 역순, 즉 comparator 기준 내림차순으로 반환한다.
+fn bounded_heap<T, C, I>(n: usize, iter: I) -> Vec<T>
+where
+    C: Comparator<T> + Default,
+    I: IntoIterator<Item = T>,
+{
+    if n == 0 {
+        return Vec::new();
+    }
+
+    let comp = C::default();
+    let mut heap = MinHeap::new(C::default());
+    for item in iter {
+        if heap.len() < n {
+            heap.push(item);
+        } else if heap
+            .top()
+            .is_some_and(|top| comp.compare(top, &item).is_lt())
+        {
+            // heap is recovered when PeekMut drops
+            *heap.peek_mut().unwrap() = item;
+        }
+    }
+
+    let mut result = Vec::with_capacity(heap.len());
+    while let Some(item) = heap.pop() {
+        result.push(item);
+    }
+    result.reverse();
+    result
+}
+
+/// # Description
+/// iter에서 가장 작은 n개의 원소를 오름차순으로 반환한다.
+/// iter의 원소가 n개보다 적으면 모든 원소를 반환한다.
+///
+/// # Examples
+/// ```
+/// use binary_heap::nsmallest;
+/// assert_eq!(nsmallest(3, [5, 1, 4, 2, 3]), vec![1, 2, 3]);
+/// assert_eq!(nsmallest(10, [2, 1]), vec![1, 2]);
+/// ```
+pub fn nsmallest<T: Ord, I: IntoIterator<Item = T>>(n: usize, iter: I) -> Vec<T> {
+    bounded_heap::<T, ReverseComparator, I>(n, iter)
+}
+
+/// # Description
+/// iter에서 가장 큰 n개의 원소를 내림차순으로 반환한다.
+/// iter의 원소가 n개보다 적으면 모든 원소를 반환한다.
+///
+/// # Examples
+/// ```
+/// use binary_heap::nlargest;
+/// assert_eq!(nlargest(3, [5, 1, 4, 2, 3]), vec![5, 4, 3]);
+/// assert_eq!(nlargest(10, [1, 2]), vec![2, 1]);
+/// ```
+pub fn nlargest<T: Ord, I: IntoIterator<Item = T>>(n: usize, iter: I) -> Vec<T> {
+    bounded_heap::<T, DefaultComparator, I>(n, iter)
+}
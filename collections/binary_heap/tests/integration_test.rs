@@ -117,3 +117,438 @@ fn test_from_iter() {
     let mut pq2: MinHeap<u32, DefaultComparator> = iter2.collect();
     assert_eq!(pq2.pop().unwrap(), 0);
 }
+
+#[test]
+fn test_push_batch_large() {
+    let dcomp = DefaultComparator;
+
+    // large batch on small heap, rebuilt at once since better_to_rebuild(3, 10_000)
+    let mut pq = MinHeap::<u32, DefaultComparator>::from_vec(vec![500, 3, 1000], dcomp);
+    pq.push_batch((0..10_000u32).rev());
+    assert_eq!(pq.len(), 10_003);
+
+    let mut expected: Vec<u32> = (0..10_000u32).chain([500, 3, 1000]).collect();
+    expected.sort();
+    for e in expected {
+        assert_eq!(pq.pop().unwrap(), e);
+    }
+    assert!(pq.is_empty());
+}
+
+#[test]
+fn test_push_batch_small() {
+    let dcomp = DefaultComparator;
+
+    // small batch on big heap, sifted up one by one
+    let mut pq = MinHeap::<u32, DefaultComparator>::from_vec((0..10_000u32).collect(), dcomp);
+    pq.push_batch([7u32, 20_000, 0]);
+    assert_eq!(pq.len(), 10_003);
+
+    let mut expected: Vec<u32> = (0..10_000u32).chain([7, 20_000, 0]).collect();
+    expected.sort();
+    for e in expected {
+        assert_eq!(pq.pop().unwrap(), e);
+    }
+    assert!(pq.is_empty());
+}
+
+#[test]
+fn test_from_heapified_vec() {
+    use heap_on_slice::min_heap;
+
+    // slice heap -> MinHeap
+    let mut vec: Vec<u32> = vec![9, 4, 7, 1, 8, 2, 6, 3, 5, 0];
+    min_heap::heapify(&mut vec);
+    let mut pq = MinHeap::<u32, DefaultComparator>::from_heapified_vec(vec, DefaultComparator);
+    for e in 0..10u32 {
+        assert_eq!(pq.pop().unwrap(), e);
+    }
+    assert!(pq.is_empty());
+}
+
+#[test]
+fn test_as_heap_slice() {
+    use heap_on_slice::min_heap;
+
+    // MinHeap -> slice heap
+    let dcomp = DefaultComparator;
+    let mut pq = MinHeap::<u32, DefaultComparator>::from_vec(vec![5, 3, 8, 1, 9, 2], dcomp);
+    pq.push(0);
+    assert!(min_heap::is_heap(pq.as_heap_slice()));
+    assert_eq!(pq.as_heap_slice()[0], *pq.top().unwrap());
+
+    // round trip
+    let comp = |a: &u32, b: &u32| DefaultComparator.compare(a, b);
+    let vec = pq.as_heap_slice().to_vec();
+    assert!(min_heap::is_heap_by(&vec, comp));
+    let mut pq2 = MinHeap::<u32, DefaultComparator>::from_heapified_vec(vec, DefaultComparator);
+    while let Some(e) = pq.pop() {
+        assert_eq!(pq2.pop().unwrap(), e);
+    }
+    assert!(pq2.is_empty());
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "source is not in valid heap order")]
+fn test_from_heapified_vec_not_heap() {
+    let _ = MinHeap::<u32, DefaultComparator>::from_heapified_vec(vec![3, 2, 1], DefaultComparator);
+}
+
+#[test]
+fn test_peek_mut_pop() {
+    let dcomp = DefaultComparator;
+
+    let test_vec: Vec<u32> = vec![3, 2, 1, 5, 4];
+    let mut pq = MinHeap::<u32, DefaultComparator>::from_vec(test_vec, dcomp);
+
+    // pop the root only if it is odd
+    while let Some(pm) = pq.peek_mut() {
+        if *pm % 2 == 0 {
+            break;
+        }
+        let _ = PeekMut::pop(pm);
+    }
+    assert_eq!(*pq.top().unwrap(), 2);
+    assert_eq!(pq.len(), 4);
+
+    let pm = pq.peek_mut().unwrap();
+    assert_eq!(PeekMut::pop(pm), 2);
+    assert_eq!(*pq.top().unwrap(), 3);
+    assert_eq!(pq.pop().unwrap(), 3);
+    assert_eq!(pq.pop().unwrap(), 4);
+    assert_eq!(pq.pop().unwrap(), 5);
+    assert!(pq.is_empty());
+}
+
+#[test]
+fn test_peek_mut_set() {
+    let mut pq: MinHeap<u32, DefaultComparator> = [3u32, 2, 1, 5, 4].into_iter().collect();
+
+    // larger value sifts down
+    let pm = pq.peek_mut().unwrap();
+    assert_eq!(PeekMut::set(pm, 6), 1);
+    pq.assert_valid();
+    assert_eq!(pq.len(), 5);
+    assert_eq!(*pq.top().unwrap(), 2);
+
+    // smaller value stays at root
+    let pm = pq.peek_mut().unwrap();
+    assert_eq!(PeekMut::set(pm, 0), 2);
+    pq.assert_valid();
+    assert_eq!(*pq.top().unwrap(), 0);
+
+    assert_eq!(pq.into_sorted_vec(), vec![0, 3, 4, 5, 6]);
+}
+
+#[test]
+fn test_reverse_owned() {
+    // compare by the distance from the target, owned by value in ReverseOwned
+    struct AbstractDistance {
+        target: u32,
+    }
+    impl Comparator<u32> for AbstractDistance {
+        fn compare(&self, a: &u32, b: &u32) -> std::cmp::Ordering {
+            a.abs_diff(self.target).cmp(&b.abs_diff(self.target))
+        }
+    }
+
+    let comp = ReverseOwned(AbstractDistance { target: 10 });
+    let mut v: Vec<u32> = vec![1, 12, 30, 9, 10];
+    intro_sort::intro_sort_by(&mut v, as_fn_mut(&comp));
+    assert_eq!(v[0], 30);
+    assert_eq!(v[1], 1);
+    assert_eq!(v[4], 10);
+
+    // as the comparator of MinHeap, the farthest element is popped first
+    let mut pq = MinHeap::from_vec(vec![1u32, 12, 30, 9, 10], comp);
+    pq.assert_valid();
+    assert_eq!(pq.pop(), Some(30));
+    assert_eq!(pq.pop(), Some(1));
+
+    // reversing twice gives the inner order
+    let twice = ReverseOwned(ReverseOwned(DefaultComparator));
+    let mut v = vec![3u32, 1, 2];
+    intro_sort::intro_sort_by(&mut v, as_fn_mut(&twice));
+    assert_eq!(v, vec![1, 2, 3]);
+}
+
+#[test]
+fn test_extract_if() {
+    let mut pq: MinHeap<u32, DefaultComparator> = (1..=10u32).collect();
+
+    let mut extracted = pq.extract_if(|x| x % 2 == 0);
+    extracted.sort();
+    assert_eq!(extracted, vec![2, 4, 6, 8, 10]);
+    assert_eq!(pq.len(), 5);
+    for e in [1, 3, 5, 7, 9] {
+        assert_eq!(pq.pop().unwrap(), e);
+    }
+    assert!(pq.is_empty());
+
+    // nothing matches
+    let mut pq: MinHeap<u32, DefaultComparator> = (1..=10u32).collect();
+    assert!(pq.extract_if(|x| *x > 10).is_empty());
+    assert_eq!(pq.len(), 10);
+    assert_eq!(*pq.top().unwrap(), 1);
+}
+
+#[test]
+fn test_nsmallest_nlargest() {
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    let mut rng = StdRng::seed_from_u64(42);
+    for len in [0usize, 1, 5, 100, 1000] {
+        let stream: Vec<u32> = (0..len).map(|_| rng.random_range(0..500)).collect();
+        let mut sorted = stream.clone();
+        sorted.sort();
+
+        for n in [0usize, 1, 3, 10, 100, 2000] {
+            let mut expected_smallest = sorted.clone();
+            expected_smallest.truncate(n);
+            assert_eq!(nsmallest(n, stream.iter().copied()), expected_smallest);
+
+            let mut expected_largest: Vec<u32> = sorted.iter().rev().copied().collect();
+            expected_largest.truncate(n);
+            assert_eq!(nlargest(n, stream.iter().copied()), expected_largest);
+        }
+    }
+}
+
+#[test]
+fn test_minimal_bounds() {
+    // implements neither Clone nor Ord, ordering is given by the comparator only
+    struct NonClone {
+        key: u32,
+    }
+    struct KeyComparator;
+    impl Comparator<NonClone> for KeyComparator {
+        fn compare(&self, a: &NonClone, b: &NonClone) -> std::cmp::Ordering {
+            a.key.cmp(&b.key)
+        }
+    }
+
+    let source = [5u32, 3, 8, 1, 9, 2].map(|key| NonClone { key });
+    let mut pq = MinHeap::from_vec(Vec::from(source), KeyComparator);
+    pq.push(NonClone { key: 0 });
+    assert_eq!(pq.top().unwrap().key, 0);
+    assert_eq!(pq.pop().unwrap().key, 0);
+
+    let mut pm = pq.peek_mut().unwrap();
+    pm.key = 7;
+    drop(pm);
+    assert_eq!(pq.top().unwrap().key, 2);
+
+    let keys: Vec<u32> = pq.into_sorted_vec().iter().map(|e| e.key).collect();
+    assert_eq!(keys, vec![2, 3, 5, 7, 8, 9]);
+}
+
+#[test]
+fn test_into_sorted_vec() {
+    let pq: MinHeap<u32, DefaultComparator> = [4u32, 3, 5, 1, 2, 0, 3].into_iter().collect();
+    assert_eq!(pq.into_sorted_vec(), vec![0, 1, 2, 3, 3, 4, 5]);
+
+    let pq: MinHeap<u32, ReverseComparator> = [4u32, 3, 5, 1, 2, 0, 3].into_iter().collect();
+    assert_eq!(pq.into_sorted_vec(), vec![5, 4, 3, 3, 2, 1, 0]);
+
+    let pq = MinHeap::<u32, DefaultComparator>::new(DefaultComparator);
+    assert!(pq.into_sorted_vec().is_empty());
+}
+
+#[test]
+fn test_tree_iter() {
+    let pq =
+        MinHeap::<u32, DefaultComparator>::from_vec(vec![6, 5, 4, 3, 2, 1, 0], DefaultComparator);
+
+    let depths: Vec<usize> = pq.tree_iter().map(|(depth, _, _)| depth).collect();
+    assert_eq!(depths, vec![0, 1, 1, 2, 2, 2, 2]);
+
+    // index and element follow the backing data
+    for (depth, idx, elem) in pq.tree_iter() {
+        assert_eq!(elem, &pq.as_heap_slice()[idx]);
+        if idx > 0 {
+            let parent = (idx - 1) / 2;
+            assert!(pq.as_heap_slice()[parent] <= *elem);
+            assert_eq!(pq.tree_iter().nth(parent).unwrap().0, depth - 1);
+        }
+    }
+    assert_eq!(pq.tree_iter().next(), Some((0, 0, &0)));
+
+    let empty = MinHeap::<u32, DefaultComparator>::new(DefaultComparator);
+    assert_eq!(empty.tree_iter().count(), 0);
+}
+
+#[test]
+fn test_iter_sorted() {
+    let source: Vec<u32> = vec![9, 4, 7, 1, 8, 2, 6, 3, 5, 0, 4];
+    let mut expected = source.clone();
+    expected.sort();
+    let mut pq = MinHeap::<u32, DefaultComparator>::from_vec(source, DefaultComparator);
+
+    // non-consuming, so it can be iterated twice
+    assert_eq!(pq.iter_sorted().copied().collect::<Vec<u32>>(), expected);
+    assert_eq!(
+        pq.iter_sorted().take(3).copied().collect::<Vec<u32>>(),
+        vec![0, 1, 2]
+    );
+    assert_eq!(pq.len(), expected.len());
+
+    // the heap still pops correctly
+    for e in expected {
+        assert_eq!(pq.pop().unwrap(), e);
+    }
+    assert!(pq.is_empty());
+    assert_eq!(pq.iter_sorted().next(), None);
+}
+
+#[derive(Default)]
+struct CellComparator;
+
+impl Comparator<std::cell::Cell<u32>> for CellComparator {
+    fn compare(&self, a: &std::cell::Cell<u32>, b: &std::cell::Cell<u32>) -> std::cmp::Ordering {
+        a.get().cmp(&b.get())
+    }
+}
+
+#[test]
+fn test_sift_from_after_external_mutation() {
+    use std::cell::Cell;
+
+    let mut pq: MinHeap<Cell<u32>, CellComparator> = (0..31u32).map(Cell::new).collect();
+    pq.assert_valid();
+
+    // decrease a leaf below the root, then repair it upward
+    let leaf = pq.len() - 1;
+    pq.as_heap_slice()[leaf].set(0);
+    pq.as_heap_slice()[0].set(1);
+    pq.sift_down_from(0);
+    pq.sift_up_from(leaf);
+    pq.assert_valid();
+    assert!(binary_heap_is_valid(&pq));
+
+    // increase an inner node above every leaf, then repair it downward
+    pq.as_heap_slice()[1].set(100);
+    assert!(!binary_heap_is_valid(&pq));
+    pq.sift_down_from(1);
+    pq.assert_valid();
+    assert!(binary_heap_is_valid(&pq));
+
+    let mut popped = Vec::new();
+    while let Some(e) = pq.pop() {
+        popped.push(e.get());
+    }
+    assert!(popped.is_sorted());
+    assert_eq!(popped.last(), Some(&100));
+}
+
+fn binary_heap_is_valid(pq: &MinHeap<std::cell::Cell<u32>, CellComparator>) -> bool {
+    let data = pq.as_heap_slice();
+    (1..data.len()).all(|i| data[(i - 1) / 2].get() <= data[i].get())
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "assert_valid: heap invariant is broken")]
+fn test_assert_valid_broken() {
+    let pq: MinHeap<std::cell::Cell<u32>, CellComparator> =
+        (0..7u32).map(std::cell::Cell::new).collect();
+    pq.as_heap_slice()[2].set(100);
+    pq.assert_valid();
+}
+
+#[test]
+#[should_panic(expected = "sift_up_from: index out of bounds")]
+fn test_sift_up_from_out_of_bounds() {
+    let mut pq = MinHeap::<u32, DefaultComparator>::from_vec(vec![1, 2, 3], DefaultComparator);
+    pq.sift_up_from(3);
+}
+
+#[test]
+fn test_with_capacity() {
+    let n = 100;
+    let mut pq = MinHeap::<u32, DefaultComparator>::with_capacity(n, DefaultComparator);
+    assert!(pq.capacity() >= n);
+    assert_eq!(pq.len(), 0);
+
+    // no reallocation while pushing n elements
+    let capacity = pq.capacity();
+    pq.push(0);
+    let ptr = pq.as_heap_slice().as_ptr();
+    for e in (1..n as u32).rev() {
+        pq.push(e);
+    }
+    assert_eq!(pq.capacity(), capacity);
+    assert_eq!(pq.as_heap_slice().as_ptr(), ptr);
+    assert_eq!(pq.top(), Some(&0));
+}
+
+#[test]
+fn test_try_reserve() {
+    let mut pq = MinHeap::<u32, DefaultComparator>::new(DefaultComparator);
+    assert!(pq.try_reserve(10).is_ok());
+    assert!(pq.capacity() >= 10);
+    assert!(pq.is_empty());
+
+    // capacity overflow is reported, not aborted
+    assert!(pq.try_reserve(usize::MAX).is_err());
+    pq.push(1);
+    assert_eq!(pq.pop(), Some(1));
+}
+
+/// count every comparison, to observe whether build_heap ran
+struct CountingComparator<'a>(&'a std::cell::Cell<usize>);
+
+impl Comparator<u32> for CountingComparator<'_> {
+    fn compare(&self, a: &u32, b: &u32) -> std::cmp::Ordering {
+        self.0.set(self.0.get() + 1);
+        a.cmp(b)
+    }
+}
+
+#[test]
+fn test_from_vec_checked_skips_valid_heap() {
+    let count = std::cell::Cell::new(0);
+    let heapified: Vec<u32> = (0..100).collect();
+
+    let mut pq = MinHeap::from_vec_checked(heapified.clone(), CountingComparator(&count));
+    // only the heap order check, one comparison for each non-root node
+    assert_eq!(count.get(), heapified.len() - 1);
+    assert_eq!(pq.as_heap_slice(), heapified.as_slice());
+    assert_eq!(pq.pop(), Some(0));
+}
+
+#[test]
+fn test_from_vec_checked_rebuilds_invalid_heap() {
+    let count = std::cell::Cell::new(0);
+    let source: Vec<u32> = (0..100).rev().collect();
+
+    let pq = MinHeap::from_vec_checked(source.clone(), CountingComparator(&count));
+    // the check stops at the first violation, then build_heap runs
+    // build_heap compares at least once for each inner node
+    assert!(count.get() > 1 + source.len() / 2);
+    pq.assert_valid();
+
+    let data = pq.as_heap_slice();
+    assert!((1..data.len()).all(|i| data[(i - 1) / 2] <= data[i]));
+    assert_eq!(pq.into_sorted_vec(), (0..100).collect::<Vec<u32>>());
+}
+
+#[test]
+fn test_into_iter_unsorted() {
+    let pushed: Vec<u32> = (0..1000).map(|i| i * 7919 % 101).collect();
+    let mut pq = MinHeap::new(DefaultComparator);
+    for &x in pushed.iter() {
+        pq.push(x);
+    }
+
+    // same multiset, in any order
+    let mut drained: Vec<u32> = pq.into_iter_unsorted().collect();
+    let mut expected = pushed;
+    drained.sort();
+    expected.sort();
+    assert_eq!(drained, expected);
+
+    let empty = MinHeap::<u32, DefaultComparator>::new(DefaultComparator);
+    assert_eq!(empty.into_iter_unsorted().next(), None);
+}
@@ -0,0 +1,113 @@
+use std::collections::BinaryHeap;
+
+use criterion::{BatchSize, BenchmarkId, Criterion, criterion_group, criterion_main};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use yt42::algorithms::adapter::heap_on_slice::max_heap;
+
+const SIZES: [usize; 3] = [1_000, 10_000, 100_000];
+
+fn random(size: usize) -> Vec<u64> {
+    let mut rng = StdRng::seed_from_u64(42);
+    (0..size).map(|_| rng.random()).collect()
+}
+
+/// build a heap from the input, then pop every element
+fn bench_heap_build_pop(c: &mut Criterion) {
+    let mut group = c.benchmark_group("heap_build_pop");
+    for size in SIZES {
+        let input = random(size);
+        group.bench_with_input(
+            BenchmarkId::new("heap_on_slice", size),
+            &input,
+            |b, input| {
+                b.iter_batched_ref(
+                    || input.clone(),
+                    |v| {
+                        max_heap::heapify(v);
+                        let mut heap = v.as_mut_slice();
+                        while let Some(rest) = max_heap::heap_pop(heap) {
+                            heap = rest;
+                        }
+                    },
+                    BatchSize::LargeInput,
+                )
+            },
+        );
+        // same pops as above, plus cloning every popped element into the returned Vec
+        group.bench_with_input(BenchmarkId::new("heap_drain", size), &input, |b, input| {
+            b.iter_batched_ref(
+                || input.clone(),
+                |v| {
+                    max_heap::heapify(v);
+                    max_heap::heap_drain(v)
+                },
+                BatchSize::LargeInput,
+            )
+        });
+        group.bench_with_input(
+            BenchmarkId::new("std_binary_heap", size),
+            &input,
+            |b, input| {
+                b.iter_batched(
+                    || input.clone(),
+                    |v| {
+                        let mut heap = BinaryHeap::from(v);
+                        while heap.pop().is_some() {}
+                        heap
+                    },
+                    BatchSize::LargeInput,
+                )
+            },
+        );
+    }
+    group.finish();
+}
+
+/// Floyd's bottom-up heapify against pushing the elements one at a time
+fn bench_heapify(c: &mut Criterion) {
+    let mut group = c.benchmark_group("heapify");
+    for size in SIZES {
+        let input = random(size);
+        group.bench_with_input(BenchmarkId::new("floyd", size), &input, |b, input| {
+            b.iter_batched_ref(
+                || input.clone(),
+                |v| max_heap::heapify(v),
+                BatchSize::LargeInput,
+            )
+        });
+        group.bench_with_input(BenchmarkId::new("insertion", size), &input, |b, input| {
+            b.iter_batched_ref(
+                || input.clone(),
+                |v| max_heap::heapify_by_insertion(v),
+                BatchSize::LargeInput,
+            )
+        });
+    }
+    group.finish();
+}
+
+/// is_heap on a valid heap scans every parent, but fails fast on a violation at the root
+fn bench_is_heap(c: &mut Criterion) {
+    let mut group = c.benchmark_group("is_heap");
+    for size in SIZES {
+        let mut valid = random(size);
+        max_heap::heapify(&mut valid);
+        let mut broken_root = valid.clone();
+        broken_root[0] = 0;
+
+        group.bench_with_input(BenchmarkId::new("valid", size), &valid, |b, input| {
+            b.iter(|| max_heap::is_heap(input))
+        });
+        group.bench_with_input(
+            BenchmarkId::new("broken_root", size),
+            &broken_root,
+            |b, input| b.iter(|| max_heap::is_heap(input)),
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_heap_build_pop, bench_heapify, bench_is_heap);
+criterion_main!(benches);
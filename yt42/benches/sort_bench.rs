@@ -0,0 +1,635 @@
+use criterion::{BatchSize, BenchmarkId, Criterion, criterion_group, criterion_main};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use yt42::algorithms::adapter::heap_on_slice::max_heap::heap_sort;
+use yt42::algorithms::sort::counting_sort::{
+    CountingSort, CountingSortByKey, TryCountingSort, counting_sort_by_key_inplace,
+    counting_sort_by_key_u16, counting_sort_direct, counting_sort_u8_by_key, counting_sort_u16,
+};
+use yt42::algorithms::sort::insertion_sort::{insertion_sort, insertion_sort_sentinel};
+use yt42::algorithms::sort::intro_sort::{intro_sort, intro_sort_final_insertion};
+use yt42::algorithms::sort::merge_sort::merge_sort;
+use yt42::algorithms::sort::quick_sort::{binary_quick_sort, hoare_quick_sort, ternary_quick_sort};
+use yt42::algorithms::sort::radix_sort::{
+    Projection, RadixSortExt, SerialScheme, radix_sort_by_key, radix256_sort_u32, radix256_sort_u64,
+};
+use yt42::algorithms::sort::smooth_sort::smooth_sort;
+use yt42::algorithms::sort::tim_sort::{TimConfig, tim_sort, tim_sort_with_config};
+
+const SIZES: [usize; 3] = [1_000, 10_000, 100_000];
+
+/// generator of benchmark input for the given size
+type Generator = fn(usize) -> Vec<u64>;
+
+/// already sorted input
+fn sorted(size: usize) -> Vec<u64> {
+    (0..size as u64).collect()
+}
+
+/// sorted input with 1% of elements swapped randomly
+fn nearly_sorted(size: usize) -> Vec<u64> {
+    let mut rng = StdRng::seed_from_u64(42);
+    let mut vec: Vec<u64> = (0..size as u64).collect();
+    for _ in 0..size / 100 {
+        let i = rng.random_range(0..size);
+        let j = rng.random_range(0..size);
+        vec.swap(i, j);
+    }
+    vec
+}
+
+/// uniformly random values
+fn random(size: usize) -> Vec<u64> {
+    let mut rng = StdRng::seed_from_u64(42);
+    (0..size).map(|_| rng.random()).collect()
+}
+
+/// repeated ascending runs of length 64
+fn sawtooth(size: usize) -> Vec<u64> {
+    (0..size as u64).map(|x| x % 64).collect()
+}
+
+/// only 8 distinct values, many duplicates
+fn few_distinct(size: usize) -> Vec<u64> {
+    (0..size as u64).map(|x| (x * 7) % 8).collect()
+}
+
+/// ascending runs of length 4096 over overlapping ranges
+/// each run is made of blocks of 256, so merging two runs takes long streaks from one side
+fn long_runs(size: usize) -> Vec<u64> {
+    (0..size as u64)
+        .map(|x| (x % 4096) / 256 * 1024 + (x / 4096) % 4 * 256 + x % 256)
+        .collect()
+}
+
+/// ascending then descending
+fn pipe_organ(size: usize) -> Vec<u64> {
+    (0..size as u64).map(|x| x.min(size as u64 - x)).collect()
+}
+
+/// random strings of 8 to 32 lowercase letters, cheap to move but compared byte by byte
+fn random_strings(size: usize) -> Vec<String> {
+    let mut rng = StdRng::seed_from_u64(42);
+    (0..size)
+        .map(|_| {
+            let len = rng.random_range(8..=32);
+            (0..len)
+                .map(|_| rng.random_range(b'a'..=b'z') as char)
+                .collect()
+        })
+        .collect()
+}
+
+/// random 64 byte arrays, expensive to move but mostly decided by the first byte
+fn random_bytes64(size: usize) -> Vec<[u8; 64]> {
+    let mut rng = StdRng::seed_from_u64(42);
+    (0..size).map(|_| rng.random()).collect()
+}
+
+fn bench_nearly_sorted(c: &mut Criterion) {
+    let mut group = c.benchmark_group("nearly_sorted");
+    for size in SIZES {
+        let input = nearly_sorted(size);
+        group.bench_with_input(BenchmarkId::new("smooth_sort", size), &input, |b, input| {
+            b.iter_batched_ref(|| input.clone(), |v| smooth_sort(v), BatchSize::LargeInput)
+        });
+        group.bench_with_input(BenchmarkId::new("tim_sort", size), &input, |b, input| {
+            b.iter_batched_ref(|| input.clone(), |v| tim_sort(v), BatchSize::LargeInput)
+        });
+        group.bench_with_input(BenchmarkId::new("merge_sort", size), &input, |b, input| {
+            b.iter_batched_ref(|| input.clone(), |v| merge_sort(v), BatchSize::LargeInput)
+        });
+    }
+    group.finish();
+}
+
+fn bench_sorted(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sorted");
+    for size in SIZES {
+        let input = sorted(size);
+        group.bench_with_input(BenchmarkId::new("merge_sort", size), &input, |b, input| {
+            b.iter_batched_ref(|| input.clone(), |v| merge_sort(v), BatchSize::LargeInput)
+        });
+        group.bench_with_input(BenchmarkId::new("tim_sort", size), &input, |b, input| {
+            b.iter_batched_ref(|| input.clone(), |v| tim_sort(v), BatchSize::LargeInput)
+        });
+    }
+    group.finish();
+}
+
+/// sorts of different move/compare trade-offs on the given element type
+fn bench_element_type<T: Ord + Clone>(
+    c: &mut Criterion,
+    name: &str,
+    generator: fn(usize) -> Vec<T>,
+) {
+    let mut group = c.benchmark_group(name);
+    group.sample_size(20);
+    for size in [10_000, 100_000] {
+        let input = generator(size);
+        group.bench_with_input(BenchmarkId::new("merge_sort", size), &input, |b, input| {
+            b.iter_batched_ref(|| input.clone(), |v| merge_sort(v), BatchSize::LargeInput)
+        });
+        group.bench_with_input(BenchmarkId::new("intro_sort", size), &input, |b, input| {
+            b.iter_batched_ref(|| input.clone(), |v| intro_sort(v), BatchSize::LargeInput)
+        });
+        group.bench_with_input(BenchmarkId::new("heap_sort", size), &input, |b, input| {
+            b.iter_batched_ref(|| input.clone(), |v| heap_sort(v), BatchSize::LargeInput)
+        });
+        group.bench_with_input(BenchmarkId::new("std_stable", size), &input, |b, input| {
+            b.iter_batched_ref(|| input.clone(), |v| v.sort(), BatchSize::LargeInput)
+        });
+    }
+    group.finish();
+}
+
+fn bench_element_types(c: &mut Criterion) {
+    bench_element_type(c, "random_string", random_strings);
+    bench_element_type(c, "random_bytes64", random_bytes64);
+}
+
+fn bench_patterns(c: &mut Criterion) {
+    let patterns: [(&str, Generator); 2] = [("sawtooth", sawtooth), ("pipe_organ", pipe_organ)];
+    for (name, generator) in patterns {
+        let mut group = c.benchmark_group(name);
+        for size in SIZES {
+            let input = generator(size);
+            group.bench_with_input(BenchmarkId::new("intro_sort", size), &input, |b, input| {
+                b.iter_batched_ref(|| input.clone(), |v| intro_sort(v), BatchSize::LargeInput)
+            });
+            group.bench_with_input(
+                BenchmarkId::new("std_unstable", size),
+                &input,
+                |b, input| {
+                    b.iter_batched_ref(
+                        || input.clone(),
+                        |v| v.sort_unstable(),
+                        BatchSize::LargeInput,
+                    )
+                },
+            );
+        }
+        group.finish();
+    }
+}
+
+fn bench_pivot_selection(c: &mut Criterion) {
+    // inputs which defeat a pivot at a fixed position
+    let patterns: [(&str, Generator); 3] = [
+        ("sorted", sorted),
+        ("pipe_organ", pipe_organ),
+        ("sawtooth", sawtooth),
+    ];
+    let mut group = c.benchmark_group("quick_sort_pivot");
+    // binary quick sort recurses on both parts, keep the size small enough for its stack
+    for size in [1_000, 10_000] {
+        for (name, generator) in patterns {
+            let input = generator(size);
+            group.bench_with_input(
+                BenchmarkId::new(format!("binary_quick_sort_{name}"), size),
+                &input,
+                |b, input| {
+                    b.iter_batched_ref(
+                        || input.clone(),
+                        |v| binary_quick_sort(v),
+                        BatchSize::LargeInput,
+                    )
+                },
+            );
+            group.bench_with_input(
+                BenchmarkId::new(format!("hoare_quick_sort_{name}"), size),
+                &input,
+                |b, input| {
+                    b.iter_batched_ref(
+                        || input.clone(),
+                        |v| hoare_quick_sort(v),
+                        BatchSize::LargeInput,
+                    )
+                },
+            );
+        }
+    }
+    group.finish();
+}
+
+fn bench_radix256(c: &mut Criterion) {
+    let mut group = c.benchmark_group("random_u64");
+    for size in SIZES {
+        let input = random(size);
+        group.bench_with_input(
+            BenchmarkId::new("radix256_sort", size),
+            &input,
+            |b, input| {
+                b.iter_batched_ref(
+                    || input.clone(),
+                    |v| radix256_sort_u64(v),
+                    BatchSize::LargeInput,
+                )
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("std_unstable", size),
+            &input,
+            |b, input| {
+                b.iter_batched_ref(
+                    || input.clone(),
+                    |v| v.sort_unstable(),
+                    BatchSize::LargeInput,
+                )
+            },
+        );
+    }
+    group.finish();
+}
+
+/// radix sorts against comparison sorts on uniformly random keys of the given width
+/// the scheme is made of byte projections, so it takes as many passes as radix256
+///
+/// when radix beats comparison sorts (measured with 1k, 10k and 100k keys):
+/// - radix256_sort beats both intro_sort and std unstable sort on u32 at every size,
+///   since it takes 4 passes against about log2(n) comparisons per element
+/// - on u64 it takes 8 passes, and is about even with std unstable sort from 10k elements
+/// - the closure-based scheme and radix_sort_by_key pay a projection call and a fresh counting buffer per pass,
+///   so they only beat intro_sort on u32 keys from 10k elements, and never std unstable sort
+fn bench_radix_width<T: Ord + Copy + Into<u64> + 'static>(
+    c: &mut Criterion,
+    name: &str,
+    generate: fn(usize) -> Vec<T>,
+    radix256: fn(&mut [T]),
+) {
+    let mut group = c.benchmark_group(name);
+    for size in SIZES {
+        let input = generate(size);
+        group.bench_with_input(
+            BenchmarkId::new("radix_scheme", size),
+            &input,
+            |b, input| {
+                b.iter_batched_ref(
+                    || input.clone(),
+                    |v| {
+                        let mut projections: Vec<Projection<T>> = (0..size_of::<T>() as u32)
+                            .map(|byte| {
+                                let shift = byte * 8;
+                                Box::new(move |x: &T| ((*x).into() >> shift) as usize & 0xFF)
+                                    as Projection<T>
+                            })
+                            .collect();
+                        v.as_mut_slice()
+                            .radix_sort(SerialScheme::new(&mut projections))
+                    },
+                    BatchSize::LargeInput,
+                )
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("radix_sort_by_key", size),
+            &input,
+            |b, input| {
+                b.iter_batched_ref(
+                    || input.clone(),
+                    |v| radix_sort_by_key(v, 8, |x| (*x).into() as usize),
+                    BatchSize::LargeInput,
+                )
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("radix256_sort", size),
+            &input,
+            |b, input| b.iter_batched_ref(|| input.clone(), |v| radix256(v), BatchSize::LargeInput),
+        );
+        group.bench_with_input(BenchmarkId::new("intro_sort", size), &input, |b, input| {
+            b.iter_batched_ref(|| input.clone(), |v| intro_sort(v), BatchSize::LargeInput)
+        });
+        group.bench_with_input(
+            BenchmarkId::new("std_unstable", size),
+            &input,
+            |b, input| {
+                b.iter_batched_ref(
+                    || input.clone(),
+                    |v| v.sort_unstable(),
+                    BatchSize::LargeInput,
+                )
+            },
+        );
+    }
+    group.finish();
+}
+
+fn bench_radix_sort(c: &mut Criterion) {
+    bench_radix_width(
+        c,
+        "radix_u32",
+        |size| random(size).into_iter().map(|x| x as u32).collect(),
+        radix256_sort_u32,
+    );
+    bench_radix_width(c, "radix_u64", random, radix256_sort_u64);
+}
+
+fn bench_counting_sort(c: &mut Criterion) {
+    let mut group = c.benchmark_group("small_range_u64");
+    for size in SIZES {
+        // keys in 0..1024
+        let input: Vec<u64> = random(size).into_iter().map(|x| x % 1024).collect();
+        group.bench_with_input(
+            BenchmarkId::new("counting_sort_by_key", size),
+            &input,
+            |b, input| {
+                b.iter_batched_ref(
+                    || input.clone(),
+                    |v| v.as_mut_slice().counting_sort_by_key(|x| *x as usize),
+                    BatchSize::LargeInput,
+                )
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("counting_sort_direct", size),
+            &input,
+            |b, input| {
+                b.iter_batched_ref(
+                    || input.clone(),
+                    |v| counting_sort_direct(v, |x| *x as usize),
+                    BatchSize::LargeInput,
+                )
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("try_counting_sort", size),
+            &input,
+            |b, input| {
+                b.iter_batched_ref(
+                    || input.clone(),
+                    |v| v.as_mut_slice().try_counting_sort().unwrap(),
+                    BatchSize::LargeInput,
+                )
+            },
+        );
+    }
+    group.finish();
+}
+
+fn bench_counting_sort_u16(c: &mut Criterion) {
+    let mut group = c.benchmark_group("random_u16");
+    for size in SIZES {
+        let input: Vec<u16> = random(size).into_iter().map(|x| x as u16).collect();
+        group.bench_with_input(
+            BenchmarkId::new("counting_sort", size),
+            &input,
+            |b, input| {
+                b.iter_batched_ref(
+                    || input.clone(),
+                    |v| v.as_mut_slice().counting_sort(),
+                    BatchSize::LargeInput,
+                )
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("counting_sort_u16", size),
+            &input,
+            |b, input| {
+                b.iter_batched_ref(
+                    || input.clone(),
+                    |v| counting_sort_u16(v),
+                    BatchSize::LargeInput,
+                )
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("counting_sort_by_key", size),
+            &input,
+            |b, input| {
+                b.iter_batched_ref(
+                    || input.clone(),
+                    |v| v.as_mut_slice().counting_sort_by_key(|x| *x as usize),
+                    BatchSize::LargeInput,
+                )
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("counting_sort_by_key_u16", size),
+            &input,
+            |b, input| {
+                b.iter_batched_ref(
+                    || input.clone(),
+                    |v| counting_sort_by_key_u16(v, |x| *x),
+                    BatchSize::LargeInput,
+                )
+            },
+        );
+    }
+    group.finish();
+}
+
+fn bench_counting_sort_u8(c: &mut Criterion) {
+    let mut group = c.benchmark_group("u8_keys");
+    for size in SIZES {
+        // the lowest byte is the key
+        let input = random(size);
+        group.bench_with_input(
+            BenchmarkId::new("counting_sort_by_key", size),
+            &input,
+            |b, input| {
+                b.iter_batched_ref(
+                    || input.clone(),
+                    |v| {
+                        v.as_mut_slice()
+                            .counting_sort_by_key(|x| (*x & 0xFF) as usize)
+                    },
+                    BatchSize::LargeInput,
+                )
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("counting_sort_u8_by_key", size),
+            &input,
+            |b, input| {
+                b.iter_batched_ref(
+                    || input.clone(),
+                    |v| counting_sort_u8_by_key(v, |x| *x as u8),
+                    BatchSize::LargeInput,
+                )
+            },
+        );
+    }
+    group.finish();
+}
+
+fn bench_counting_sort_inplace(c: &mut Criterion) {
+    // output buffer of n elements against permutation of n usize
+    let mut group = c.benchmark_group("counting_sort_copy_buffer");
+    for size in [10_000, 100_000] {
+        let small: Vec<u64> = random(size).into_iter().map(|x| x % 1024).collect();
+        let large: Vec<[u64; 8]> = small.iter().map(|&x| [x; 8]).collect();
+        group.bench_with_input(BenchmarkId::new("direct_u64", size), &small, |b, input| {
+            b.iter_batched_ref(
+                || input.clone(),
+                |v| counting_sort_direct(v, |x| *x as usize),
+                BatchSize::LargeInput,
+            )
+        });
+        group.bench_with_input(BenchmarkId::new("inplace_u64", size), &small, |b, input| {
+            b.iter_batched_ref(
+                || input.clone(),
+                |v| counting_sort_by_key_inplace(v, |x| *x as usize),
+                BatchSize::LargeInput,
+            )
+        });
+        group.bench_with_input(
+            BenchmarkId::new("direct_64bytes", size),
+            &large,
+            |b, input| {
+                b.iter_batched_ref(
+                    || input.clone(),
+                    |v| counting_sort_direct(v, |x| x[0] as usize),
+                    BatchSize::LargeInput,
+                )
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("inplace_64bytes", size),
+            &large,
+            |b, input| {
+                b.iter_batched_ref(
+                    || input.clone(),
+                    |v| counting_sort_by_key_inplace(v, |x| x[0] as usize),
+                    BatchSize::LargeInput,
+                )
+            },
+        );
+    }
+    group.finish();
+}
+
+fn bench_few_distinct(c: &mut Criterion) {
+    let mut group = c.benchmark_group("few_distinct");
+    // binary quick sort is quadratic on duplicates, keep the sample count small
+    group.sample_size(10);
+    for size in [10_000, 100_000] {
+        let input = few_distinct(size);
+        group.bench_with_input(
+            BenchmarkId::new("binary_quick_sort", size),
+            &input,
+            |b, input| {
+                b.iter_batched_ref(
+                    || input.clone(),
+                    |v| binary_quick_sort(v),
+                    BatchSize::LargeInput,
+                )
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("ternary_quick_sort", size),
+            &input,
+            |b, input| {
+                b.iter_batched_ref(
+                    || input.clone(),
+                    |v| ternary_quick_sort(v),
+                    BatchSize::LargeInput,
+                )
+            },
+        );
+        group.bench_with_input(BenchmarkId::new("intro_sort", size), &input, |b, input| {
+            b.iter_batched_ref(|| input.clone(), |v| intro_sort(v), BatchSize::LargeInput)
+        });
+    }
+    group.finish();
+}
+
+fn bench_final_insertion(c: &mut Criterion) {
+    let mut group = c.benchmark_group("intro_sort_small_subarrays");
+    for size in SIZES {
+        let input = random(size);
+        group.bench_with_input(
+            BenchmarkId::new("per_subarray_insertion", size),
+            &input,
+            |b, input| {
+                b.iter_batched_ref(|| input.clone(), |v| intro_sort(v), BatchSize::LargeInput)
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("final_insertion", size),
+            &input,
+            |b, input| {
+                b.iter_batched_ref(
+                    || input.clone(),
+                    |v| intro_sort_final_insertion(v),
+                    BatchSize::LargeInput,
+                )
+            },
+        );
+    }
+    group.finish();
+}
+
+fn bench_insertion_sentinel(c: &mut Criterion) {
+    let mut group = c.benchmark_group("insertion_sort_sentinel");
+    // insertion sort is quadratic, only small sizes are meaningful
+    for size in [16, 64, 256, 1024] {
+        let input = random(size);
+        group.bench_with_input(
+            BenchmarkId::new("insertion_sort", size),
+            &input,
+            |b, input| {
+                b.iter_batched_ref(
+                    || input.clone(),
+                    |v| insertion_sort(v),
+                    BatchSize::SmallInput,
+                )
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("insertion_sort_sentinel", size),
+            &input,
+            |b, input| {
+                b.iter_batched_ref(
+                    || input.clone(),
+                    |v| insertion_sort_sentinel(v),
+                    BatchSize::SmallInput,
+                )
+            },
+        );
+    }
+    group.finish();
+}
+
+fn bench_min_gallop(c: &mut Criterion) {
+    let mut group = c.benchmark_group("long_runs");
+    for size in [100_000, 1_000_000] {
+        let input = long_runs(size);
+        for min_gallop in [1, 3, 7, 32] {
+            let config = TimConfig { min_gallop };
+            group.bench_with_input(
+                BenchmarkId::new(format!("tim_sort_min_gallop_{min_gallop}"), size),
+                &input,
+                |b, input| {
+                    b.iter_batched_ref(
+                        || input.clone(),
+                        |v| tim_sort_with_config(v, config),
+                        BatchSize::LargeInput,
+                    )
+                },
+            );
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_nearly_sorted,
+    bench_sorted,
+    bench_element_types,
+    bench_patterns,
+    bench_pivot_selection,
+    bench_radix256,
+    bench_radix_sort,
+    bench_counting_sort,
+    bench_counting_sort_u16,
+    bench_counting_sort_u8,
+    bench_counting_sort_inplace,
+    bench_few_distinct,
+    bench_min_gallop,
+    bench_final_insertion,
+    bench_insertion_sentinel
+);
+criterion_main!(benches);
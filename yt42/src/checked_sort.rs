@@ -0,0 +1,100 @@
+//! # Description
+//! Sorting with a postcondition check, for comparators that may not be a total order.
+//! The sort itself is intro sort, and the check is one extra pass over adjacent pairs.
+use std::cmp::Ordering;
+
+pub use intro_sort::UnsortedError;
+use intro_sort::intro_sort_checked_by;
+
+/// # Description
+/// Sorts the given slice in-place with comparator, and checks that the result is sorted by it.
+/// A comparator which is not a consistent total order(e.g. not transitive) is reported
+/// as an error instead of silently leaving the slice unsorted.
+///
+/// # Type Parameters
+/// - `T`: The element type.
+/// - `F`: The comparator type. Must implement 'FnMut' trait.
+///
+/// # Parameters
+/// - `slice`: The mutable slice to sort.
+/// - `comp`: The callable object to compare two &T data.
+///
+/// # Errors
+/// Returns [`UnsortedError`] with the first index `i` where `comp(&slice[i], &slice[i + 1])` is `Greater`.
+/// The slice is still a permutation of the input.
+///
+/// # Panics
+/// Panics if the implementation of 'comp' panics.
+///
+/// # Examples
+/// ```
+/// use yt42::algorithms::sort::checked_sort_by;
+/// let mut v = vec![3, 1, 4, 1, 5];
+/// assert_eq!(checked_sort_by(&mut v, |a, b| b.cmp(a)), Ok(()));
+/// assert_eq!(v, vec![5, 4, 3, 1, 1]);
+/// ```
+pub fn checked_sort_by<T, F>(slice: &mut [T], comp: F) -> Result<(), UnsortedError>
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    intro_sort_checked_by(slice, comp)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::is_sorted::is_sorted_by;
+
+    #[test]
+    fn test_checked_sort_by() {
+        let mut v: Vec<i32> = (0..1000).map(|x| (x * 7919) % 1009 - 500).collect();
+        assert_eq!(checked_sort_by(&mut v, i32::cmp), Ok(()));
+        assert!(is_sorted_by(&v, i32::cmp));
+
+        let mut empty: Vec<i32> = vec![];
+        assert_eq!(checked_sort_by(&mut empty, i32::cmp), Ok(()));
+    }
+
+    #[test]
+    fn test_checked_sort_by_non_transitive() {
+        // rock-paper-scissors : x < x + 1 (mod 3), which is not transitive
+        let rps = |a: &u32, b: &u32| match (a % 3, b % 3) {
+            (x, y) if x == y => Ordering::Equal,
+            (x, y) if (x + 1) % 3 == y => Ordering::Less,
+            _ => Ordering::Greater,
+        };
+        for len in [2, 3, 10, 100, 1000] {
+            let source: Vec<u32> = (0..len).map(|x| (x * 7919) % 1009).collect();
+            let mut v = source.clone();
+            let result = checked_sort_by(&mut v, rps);
+
+            // the error points at the first adjacent pair out of order
+            let first_break = v
+                .windows(2)
+                .position(|pair| rps(&pair[0], &pair[1]).is_gt());
+            assert_eq!(
+                result,
+                first_break.map_or(Ok(()), |index| Err(UnsortedError { index }))
+            );
+            assert_eq!(result.is_ok(), is_sorted_by(&v, rps));
+
+            // still a permutation of the input
+            let mut expected = source;
+            expected.sort();
+            v.sort();
+            assert_eq!(v, expected);
+        }
+
+        // every distinct pair is greater in both directions, never sorted
+        let mut v: Vec<u32> = (0..100).rev().collect();
+        let result = checked_sort_by(&mut v, |a, b| match a == b {
+            true => Ordering::Equal,
+            false => Ordering::Greater,
+        });
+        assert_eq!(result, Err(UnsortedError { index: 0 }));
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "slice is not sorted at index 0"
+        );
+    }
+}
@@ -0,0 +1,114 @@
+//! # Description
+//! Postcondition checks for the sort functions.
+//! These only use `windows`, so they work without `slice::is_sorted` and without `PartialOrd`.
+use std::cmp::Ordering;
+
+/// # Description
+/// Checks whether the given slice is sorted in ascending order.
+///
+/// # Type Parameters
+/// - `T`: The element type. Must implement `Ord`.
+///
+/// # Parameters
+/// - `slice`: The slice to check.
+///
+/// # Examples
+/// ```
+/// use yt42::algorithms::sort::is_sorted;
+/// assert!(is_sorted(&[1, 1, 3, 4, 5]));
+/// assert!(!is_sorted(&[1, 3, 2]));
+/// ```
+pub fn is_sorted<T: Ord>(slice: &[T]) -> bool {
+    is_sorted_by(slice, T::cmp)
+}
+
+/// # Description
+/// Checks whether the given slice is sorted by the comparator,
+/// that is, no element compares `Greater` than the next one.
+/// Empty and single element slices are always sorted.
+///
+/// # Type Parameters
+/// - `T`: The element type.
+/// - `F`: The comparator type. Must implement 'FnMut' trait.
+///
+/// # Parameters
+/// - `slice`: The slice to check.
+/// - `comp`: The callable object to compare two &T data.
+///
+/// # Panics
+/// Panics if the implementation of 'comp' panics.
+///
+/// # Examples
+/// ```
+/// use yt42::algorithms::sort::is_sorted_by;
+/// assert!(is_sorted_by(&[5, 4, 4, 1], |a, b| b.cmp(a)));
+/// assert!(!is_sorted_by(&[1, 2], |a, b| b.cmp(a)));
+/// ```
+pub fn is_sorted_by<T, F>(slice: &[T], mut comp: F) -> bool
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    slice
+        .windows(2)
+        .all(|w| comp(&w[0], &w[1]) != Ordering::Greater)
+}
+
+/// # Description
+/// Checks whether the given slice is sorted by the key extracted with `key_fn`.
+///
+/// # Type Parameters
+/// - `T`: The element type.
+/// - `K`: The key type. Must implement `Ord`.
+/// - `F`: The key extractor type. Must implement 'FnMut' trait.
+///
+/// # Parameters
+/// - `slice`: The slice to check.
+/// - `key_fn`: The callable object to extract the key from &T data.
+///
+/// # Panics
+/// Panics if the implementation of 'key_fn' or Ord of `K` panics.
+///
+/// # Examples
+/// ```
+/// use yt42::algorithms::sort::is_sorted_by_key;
+/// assert!(is_sorted_by_key(&["a", "bb", "cc", "ddd"], |s| s.len()));
+/// ```
+pub fn is_sorted_by_key<T, K, F>(slice: &[T], mut key_fn: F) -> bool
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    is_sorted_by(slice, |a, b| key_fn(a).cmp(&key_fn(b)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_sorted() {
+        assert!(is_sorted::<u32>(&[]));
+        assert!(is_sorted(&[7]));
+        assert!(is_sorted(&[1, 2, 2, 3, 9]));
+        // one break
+        assert!(!is_sorted(&[1, 2, 4, 3, 9]));
+        assert!(!is_sorted(&[2, 1]));
+
+        let v: Vec<u32> = (0..1000).collect();
+        assert_eq!(is_sorted(&v), v.is_sorted());
+    }
+
+    #[test]
+    fn test_is_sorted_by_and_by_key() {
+        let desc = |a: &i32, b: &i32| b.cmp(a);
+        assert!(is_sorted_by::<i32, _>(&[], desc));
+        assert!(is_sorted_by(&[3], desc));
+        assert!(is_sorted_by(&[9, 3, 3, -1], desc));
+        assert!(!is_sorted_by(&[9, 3, 4, -1], desc));
+
+        let pairs = [(1, 'z'), (2, 'a'), (2, 'b'), (5, 'c')];
+        assert!(is_sorted_by_key(&pairs, |p| p.0));
+        assert!(!is_sorted_by_key(&pairs, |p| p.1));
+        assert!(is_sorted_by_key::<(i32, char), i32, _>(&[], |p| p.0));
+    }
+}
@@ -1,3 +1,9 @@
+mod checked_sort;
+mod is_sorted;
+mod smart_sort;
+mod sort_assume_init;
+mod sorted;
+
 pub mod collections {
     pub use binary_heap;
 }
@@ -10,7 +16,18 @@ pub mod algorithms {
         pub use insertion_sort;
         pub use intro_sort;
         pub use merge_sort;
+        pub use odd_even_merge_sort;
         pub use quick_sort;
         pub use radix_sort;
+        pub use smooth_sort;
+        pub use tim_sort;
+
+        pub use crate::checked_sort::{UnsortedError, checked_sort_by};
+        pub use crate::is_sorted::{is_sorted, is_sorted_by, is_sorted_by_key};
+        pub use crate::smart_sort::smart_sort_u32;
+        pub use crate::sort_assume_init::{sort_assume_init, sort_assume_init_by};
+        pub use crate::sorted::{
+            sorted, sorted_by, sorted_by_key, sorted_vec, sorted_vec_by, sorted_vec_by_key,
+        };
     }
 }
@@ -0,0 +1,79 @@
+//! # Description
+//! Sorting of integer slices which picks the algorithm from the input.
+
+use counting_sort::CountingSortByKey;
+use intro_sort::intro_sort;
+use radix_sort::radix256_sort_u32;
+
+/// Below this length, a comparison sort has less overhead than a histogram.
+const SMALL_LEN: usize = 64;
+
+/// # Description
+/// Sorts the given slice of `u32`, choosing the algorithm by the size and the value range.
+///
+/// # Heuristic
+/// - `len < 64` : intro sort. Building a histogram does not pay off for tiny slices.
+/// - `max - min < len` : counting sort on `x - min`. The histogram is not larger than the slice,
+///   so a single counting pass is cheaper than four radix passes.
+/// - otherwise : radix sort with 8 bit digits, which takes at most four passes regardless of the range.
+///
+/// The value range is found by one linear scan before dispatching.
+///
+/// # Parameters
+/// - `slice`: The mutable slice to sort.
+///
+/// # Examples
+/// ```
+/// use yt42::algorithms::sort::smart_sort_u32;
+/// let mut v = vec![3, 1, 4, 1, 5];
+/// smart_sort_u32(&mut v);
+/// assert_eq!(v, vec![1, 1, 3, 4, 5]);
+/// ```
+pub fn smart_sort_u32(slice: &mut [u32]) {
+    let len = slice.len();
+    if len < SMALL_LEN {
+        return intro_sort(slice);
+    }
+
+    let (min, max) = slice.iter().fold((u32::MAX, u32::MIN), |(min, max), &x| {
+        (min.min(x), max.max(x))
+    });
+    if ((max - min) as usize) < len {
+        slice.counting_sort_by_key(|x| (x - min) as usize);
+    } else {
+        radix256_sort_u32(slice);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn test_smart_sort_u32() {
+        let seed: u64 = 42;
+        let mut rng = StdRng::seed_from_u64(seed);
+
+        // (len, range, offset) covering every path
+        let cases = [
+            (0, 1, 0),
+            (10, u32::MAX, 0),                 // intro sort
+            (1000, 100, 3_000_000_000),        // counting sort, shifted range
+            (1000, 1, 7),                      // counting sort, all equal
+            (1000, u32::MAX, 0),               // radix sort
+            (100_000, 50_000, 0),              // counting sort, large
+            (100_000, 1 << 20, u32::MAX >> 1), // radix sort, wide range
+        ];
+        for (len, range, offset) in cases {
+            let mut v: Vec<u32> = (0..len)
+                .map(|_| offset + rng.random_range(0..range))
+                .collect();
+            let mut expected = v.clone();
+            expected.sort();
+            smart_sort_u32(&mut v);
+            assert_eq!(v, expected, "len {len}, range {range}");
+        }
+    }
+}
@@ -0,0 +1,108 @@
+//! # Description
+//! Sorting of buffers that were filled through `MaybeUninit`,
+//! e.g. records deserialized directly into an uninitialized buffer.
+//! The buffer is sorted in place, without copying it into a `Vec<T>` first.
+use std::cmp::Ordering;
+use std::mem::MaybeUninit;
+
+use intro_sort::intro_sort_by;
+
+/// # Description
+/// Sorts the given buffer of initialized `MaybeUninit<T>` in-place with intro sort.
+///
+/// # Safety
+/// Every element of `buf` must be initialized.
+/// `MaybeUninit<T>` has the same layout as `T`, so the buffer is reinterpreted as `&mut [T]` and sorted.
+///
+/// The sort only swaps elements, so every element is still initialized after the call,
+/// even if `Ord` panics. The elements are never dropped by this function,
+/// dropping them (e.g. with `assume_init_drop`) is still the caller's responsibility.
+///
+/// # Type Parameters
+/// - `T`: The element type. Must implement `Ord`.
+///
+/// # Parameters
+/// - `buf`: The mutable buffer to sort.
+///
+/// # Panics
+/// Panics if the implementation of Ord panics.
+///
+/// # Examples
+/// ```
+/// use std::mem::MaybeUninit;
+/// use yt42::algorithms::sort::sort_assume_init;
+///
+/// let mut buf = [const { MaybeUninit::<u32>::uninit() }; 4];
+/// for (slot, x) in buf.iter_mut().zip([3, 1, 4, 1]) {
+///     slot.write(x);
+/// }
+/// // SAFETY: every element was written above
+/// unsafe { sort_assume_init(&mut buf) };
+/// let v: Vec<u32> = buf.iter().map(|x| unsafe { x.assume_init() }).collect();
+/// assert_eq!(v, vec![1, 1, 3, 4]);
+/// ```
+pub unsafe fn sort_assume_init<T: Ord>(buf: &mut [MaybeUninit<T>]) {
+    // SAFETY: forwarded to the caller
+    unsafe { sort_assume_init_by(buf, T::cmp) }
+}
+
+/// # Description
+/// Sorts the given buffer of initialized `MaybeUninit<T>` in-place with intro sort
+/// whith comparator.
+///
+/// # Safety
+/// Every element of `buf` must be initialized. See [`sort_assume_init`].
+///
+/// # Type Parameters
+/// - `T`: The element type.
+/// - `F`: The comparator type. Must implement 'FnMut' trait.
+///
+/// # Parameters
+/// - `buf`: The mutable buffer to sort.
+/// - `comp`: The callable object to compare two &T data.
+///
+/// # Panics
+/// Panics if the implementation of 'comp' panics.
+/// In debug builds, panics if `comp` is not a consistent total order.
+pub unsafe fn sort_assume_init_by<T, F>(buf: &mut [MaybeUninit<T>], comp: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    // SAFETY: MaybeUninit<T> has the same layout as T, and the caller guarantees
+    // that every element is initialized. The borrow of buf is kept by the returned slice.
+    let slice = unsafe { &mut *(buf as *mut [MaybeUninit<T>] as *mut [T]) };
+    intro_sort_by(slice, comp);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sort_assume_init() {
+        let source: Vec<String> = (0..1000).map(|i| ((i * 7919) % 1009).to_string()).collect();
+        let mut expected = source.clone();
+        expected.sort();
+
+        let mut buf: Box<[MaybeUninit<String>]> = Box::new_uninit_slice(source.len());
+        for (slot, x) in buf.iter_mut().zip(source) {
+            slot.write(x);
+        }
+        // SAFETY: every element was written above
+        unsafe { sort_assume_init(&mut buf) };
+        // SAFETY: sorting keeps every element initialized
+        let buf = unsafe { buf.assume_init() };
+        assert_eq!(buf.into_vec(), expected);
+
+        let mut empty: [MaybeUninit<u32>; 0] = [];
+        unsafe { sort_assume_init(&mut empty) };
+
+        let mut buf = [const { MaybeUninit::<i32>::uninit() }; 5];
+        for (slot, x) in buf.iter_mut().zip([2, -1, 5, 0, 2]) {
+            slot.write(x);
+        }
+        unsafe { sort_assume_init_by(&mut buf, |a: &i32, b: &i32| b.cmp(a)) };
+        let v: Vec<i32> = buf.iter().map(|x| unsafe { x.assume_init() }).collect();
+        assert_eq!(v, vec![5, 2, 2, 0, -1]);
+    }
+}
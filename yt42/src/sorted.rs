@@ -0,0 +1,233 @@
+//! # Description
+//! One-shot sorting of iterator output, and of an owned `Vec`.
+//! Collects the items into a `Vec` and sorts it stable with tim sort.
+//! The `sorted_vec` functions take the `Vec` by value and sort it in place, without collecting.
+use std::cmp::Ordering;
+
+use tim_sort::tim_sort_by;
+
+/// # Description
+/// Collects the given iterator into a `Vec` and sorts it stable.
+/// Equal elements keep the order in which the iterator yielded them.
+///
+/// # Type Parameters
+/// - `T`: The element type. Must implement `Ord`.
+/// - `I`: The source type. Must implement `IntoIterator<Item = T>`.
+///
+/// # Parameters
+/// - `iter`: The items to sort.
+///
+/// # Panics
+/// Panics if the implementation of Ord panics.
+///
+/// # Examples
+/// ```
+/// use yt42::algorithms::sort::sorted;
+/// let v = sorted([3, 1, 4, 1, 5]);
+/// assert_eq!(v, vec![1, 1, 3, 4, 5]);
+/// ```
+pub fn sorted<T, I>(iter: I) -> Vec<T>
+where
+    T: Ord,
+    I: IntoIterator<Item = T>,
+{
+    sorted_by(iter, T::cmp)
+}
+
+/// # Description
+/// Collects the given iterator into a `Vec` and sorts it stable
+/// with comparator.
+///
+/// # Type Parameters
+/// - `T`: The element type.
+/// - `I`: The source type. Must implement `IntoIterator<Item = T>`.
+/// - `F`: The comparator type. Must implement 'FnMut' trait.
+///
+/// # Parameters
+/// - `iter`: The items to sort.
+/// - `comp`: The callable object to compare two &T data.
+///
+/// # Panics
+/// Panics if the implementation of 'comp' panics.
+/// In debug builds, panics if `comp` is not a consistent total order.
+///
+/// # Examples
+/// ```
+/// use yt42::algorithms::sort::sorted_by;
+/// let v = sorted_by("hello world".split(' '), |a, b| b.cmp(a));
+/// assert_eq!(v, vec!["world", "hello"]);
+/// ```
+pub fn sorted_by<T, I, F>(iter: I, comp: F) -> Vec<T>
+where
+    I: IntoIterator<Item = T>,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let mut vec: Vec<T> = iter.into_iter().collect();
+    tim_sort_by(&mut vec, comp);
+    vec
+}
+
+/// # Description
+/// Collects the given iterator into a `Vec` and sorts it stable
+/// by the key extracted with `key_fn`.
+///
+/// # Type Parameters
+/// - `T`: The element type.
+/// - `I`: The source type. Must implement `IntoIterator<Item = T>`.
+/// - `K`: The key type. Must implement `Ord`.
+/// - `F`: The key extractor type. Must implement 'FnMut' trait.
+///
+/// # Parameters
+/// - `iter`: The items to sort.
+/// - `key_fn`: The callable object to extract the key from &T data.
+///
+/// # Panics
+/// Panics if the implementation of 'key_fn' or Ord of `K` panics.
+///
+/// # Examples
+/// ```
+/// use yt42::algorithms::sort::sorted_by_key;
+/// let v = sorted_by_key([(2, 'a'), (1, 'b'), (2, 'c')], |p| p.0);
+/// assert_eq!(v, vec![(1, 'b'), (2, 'a'), (2, 'c')]);
+/// ```
+pub fn sorted_by_key<T, I, K, F>(iter: I, mut key_fn: F) -> Vec<T>
+where
+    I: IntoIterator<Item = T>,
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    sorted_by(iter, |a, b| key_fn(a).cmp(&key_fn(b)))
+}
+
+/// # Description
+/// Sorts the given `Vec` stable in place and returns it.
+/// The allocation of `v` is reused, so `let s = sorted_vec(data);` needs no mutable binding.
+///
+/// # Type Parameters
+/// - `T`: The element type. Must implement `Ord`.
+///
+/// # Parameters
+/// - `v`: The vector to sort.
+///
+/// # Panics
+/// Panics if the implementation of Ord panics.
+///
+/// # Examples
+/// ```
+/// use yt42::algorithms::sort::sorted_vec;
+/// let data = vec![3, 1, 4, 1, 5];
+/// let s = sorted_vec(data);
+/// assert_eq!(s, vec![1, 1, 3, 4, 5]);
+/// ```
+pub fn sorted_vec<T: Ord>(v: Vec<T>) -> Vec<T> {
+    sorted_vec_by(v, T::cmp)
+}
+
+/// # Description
+/// Sorts the given `Vec` stable in place with comparator and returns it.
+///
+/// # Type Parameters
+/// - `T`: The element type.
+/// - `F`: The comparator type. Must implement 'FnMut' trait.
+///
+/// # Parameters
+/// - `v`: The vector to sort.
+/// - `comp`: The callable object to compare two &T data.
+///
+/// # Panics
+/// Panics if the implementation of 'comp' panics.
+/// In debug builds, panics if `comp` is not a consistent total order.
+///
+/// # Examples
+/// ```
+/// use yt42::algorithms::sort::sorted_vec_by;
+/// let s = sorted_vec_by(vec!["hello", "world"], |a, b| b.cmp(a));
+/// assert_eq!(s, vec!["world", "hello"]);
+/// ```
+pub fn sorted_vec_by<T, F>(mut v: Vec<T>, comp: F) -> Vec<T>
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    tim_sort_by(&mut v, comp);
+    v
+}
+
+/// # Description
+/// Sorts the given `Vec` stable in place by the key extracted with `key_fn` and returns it.
+///
+/// # Type Parameters
+/// - `T`: The element type.
+/// - `K`: The key type. Must implement `Ord`.
+/// - `F`: The key extractor type. Must implement 'FnMut' trait.
+///
+/// # Parameters
+/// - `v`: The vector to sort.
+/// - `key_fn`: The callable object to extract the key from &T data.
+///
+/// # Panics
+/// Panics if the implementation of 'key_fn' or Ord of `K` panics.
+///
+/// # Examples
+/// ```
+/// use yt42::algorithms::sort::sorted_vec_by_key;
+/// let s = sorted_vec_by_key(vec![(2, 'a'), (1, 'b'), (2, 'c')], |p| p.0);
+/// assert_eq!(s, vec![(1, 'b'), (2, 'a'), (2, 'c')]);
+/// ```
+pub fn sorted_vec_by_key<T, K, F>(v: Vec<T>, mut key_fn: F) -> Vec<T>
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    sorted_vec_by(v, |a, b| key_fn(a).cmp(&key_fn(b)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sorted_matches_collect_then_sort() {
+        let iter = (0..1000u32).map(|i| (i * 7919) % 1000 / 3);
+        let mut expected: Vec<u32> = iter.clone().collect();
+        expected.sort();
+        assert_eq!(sorted(iter), expected);
+
+        let empty: Vec<u32> = sorted(std::iter::empty());
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_sorted_by_key_stable() {
+        let iter = (0..1000u32).map(|i| ((i * 7919) % 10, i));
+        let mut expected: Vec<(u32, u32)> = iter.clone().collect();
+        expected.sort_by_key(|p| p.0);
+        assert_eq!(sorted_by_key(iter.clone(), |p| p.0), expected);
+
+        expected.reverse();
+        let mut reversed: Vec<(u32, u32)> = iter.collect();
+        reversed.sort_by(|a, b| b.cmp(a));
+        assert_eq!(sorted_by(expected, |a, b| b.cmp(a)), reversed);
+    }
+
+    #[test]
+    fn test_sorted_vec() {
+        let data: Vec<(u32, u32)> = (0..1000u32).map(|i| ((i * 7919) % 10, i)).collect();
+        let ptr = data.as_ptr();
+        let mut expected = data.clone();
+        expected.sort();
+
+        // sorted in place, the allocation is reused
+        let s = sorted_vec(data);
+        assert_eq!(s.as_ptr(), ptr);
+        assert_eq!(s, expected);
+
+        expected.sort_by_key(|p| p.0);
+        let s = sorted_vec_by_key(s, |p| p.0);
+        assert_eq!(s, expected);
+
+        expected.reverse();
+        assert_eq!(sorted_vec_by(s, |a, b| b.cmp(a)), expected);
+
+        assert!(sorted_vec(Vec::<u32>::new()).is_empty());
+    }
+}